@@ -4,10 +4,14 @@ use std::{
     time::{Duration, Instant},
 };
 
-use dbrokerapi::broker_scheduler_rpc::{self, BrokerEvent, DiscordEventData, HelloData};
+use dbrokerapi::{
+    broker_scheduler_rpc::{self, BrokerEvent, DiscordEventData, HelloData},
+    models::{DiscordMessagePoll, DiscordPoll, MessagePollVote},
+};
 use futures_util::StreamExt;
+use serde::Deserialize;
 
-use stores::config::ConfigStore;
+use stores::config::{ConfigStore, PremiumSlotTier};
 use tokio::{
     net::TcpStream,
     sync::mpsc::{self, UnboundedSender},
@@ -15,21 +19,26 @@ use tokio::{
 use tracing::{error, info, warn};
 use twilight_cache_inmemory::InMemoryCache;
 use twilight_gateway::{
-    stream::{self, ShardEventStream},
-    Config, Event, Intents, MessageSender, Shard,
+    stream::{self, ShardMessageStream},
+    Config, Event, EventTypeFlags, Intents, Message as GatewayMessage, MessageSender, Shard,
 };
 use twilight_http::Client;
 // use twilight_gateway::{cluster::Events, Cluster, stream, Event, Intents};
 use twilight_model::{
-    gateway::{event::DispatchEvent, payload::outgoing::RequestGuildMembers},
-    guild::Member,
+    gateway::{
+        event::{DispatchEvent, GatewayEventDeserializer},
+        payload::outgoing::RequestGuildMembers,
+    },
+    guild::{Member, Role},
     id::{
-        marker::{GuildMarker, UserMarker},
+        marker::{GuildMarker, RoleMarker, UserMarker},
         Id,
     },
     voice::VoiceState,
 };
 
+use crate::message_cache::MessageCache;
+
 pub async fn run_broker(
     token: String,
     discord_state: Arc<InMemoryCache>,
@@ -41,10 +50,15 @@ pub async fn run_broker(
         | Intents::GUILDS
         | Intents::GUILD_MEMBERS
         | Intents::GUILD_MODERATION
+        | Intents::GUILD_EMOJIS_AND_STICKERS
         | Intents::GUILD_INVITES
         | Intents::GUILD_VOICE_STATES
         | Intents::GUILD_MESSAGES
-        | Intents::GUILD_MESSAGE_REACTIONS;
+        | Intents::GUILD_MESSAGE_REACTIONS
+        | Intents::GUILD_SCHEDULED_EVENTS
+        | Intents::AUTO_MODERATION_CONFIGURATION
+        | Intents::AUTO_MODERATION_EXECUTION
+        | guild_message_polls_intent();
     let config = Config::new(token.clone(), intents);
 
     // let (cluster, events) = Cluster::new(token, intents).await?;
@@ -58,6 +72,7 @@ pub async fn run_broker(
 
     let mut discord_manager = Broker {
         discord_state,
+        cmd_tx: cmd_tx.clone(),
         cmd_rx,
         stores,
         ready,
@@ -67,6 +82,7 @@ pub async fn run_broker(
         gateway_message_senders: shards.iter().map(|v| v.sender()).collect(),
         nonce_counter: 0,
         pending_guild_member_requests: Default::default(),
+        message_cache: Default::default(),
     };
 
     tokio::spawn(async move { discord_manager.run(shards).await });
@@ -78,6 +94,7 @@ pub type BrokerHandle = mpsc::UnboundedSender<BrokerCommand>;
 
 struct Broker {
     discord_state: Arc<InMemoryCache>,
+    cmd_tx: BrokerHandle,
     cmd_rx: mpsc::UnboundedReceiver<BrokerCommand>,
 
     connected_scheduler: Option<TcpStream>,
@@ -91,17 +108,24 @@ struct Broker {
 
     // map of pending guild member requests and their nonce
     pending_guild_member_requests: HashMap<String, PendingChunkState>,
+
+    message_cache: MessageCache,
 }
 
 impl Broker {
     pub async fn run(&mut self, mut shards: Vec<Shard>) {
-        let mut stream = ShardEventStream::new(shards.iter_mut());
+        let mut stream = ShardMessageStream::new(shards.iter_mut());
+
+        let mut premium_tier_refresh = tokio::time::interval(PREMIUM_TIER_REFRESH_INTERVAL);
+        // the tiers are fetched on guild create, so skip the immediate first tick
+        premium_tier_refresh.tick().await;
 
         loop {
             tokio::select! {
-                evt = stream.next() => match evt {
-                    Some((_shard_id, evt)) => match evt{
-                        Ok(evt) => self.handle_event(evt).await,
+                msg = stream.next() => match msg {
+                    Some((_shard_id, msg)) => match msg{
+                        Ok(GatewayMessage::Text(json)) => self.handle_gateway_message(json).await,
+                        Ok(GatewayMessage::Close(_)) => {}
                         Err(err) => {
                             error!(?err, "failed handling event");
                             if err.is_fatal(){
@@ -116,6 +140,7 @@ impl Broker {
                     Some(cmd) => self.handle_cmd(cmd).await,
                     None => todo!(),
                 },
+                _ = premium_tier_refresh.tick() => self.spawn_premium_tier_refresh(),
             }
         }
     }
@@ -130,10 +155,50 @@ impl Broker {
             BrokerCommand::RequestGuildMembers(req) => {
                 self.handle_request_guild_members(req).await;
             }
+            BrokerCommand::SetGuildPremiumTier(guild_id, tier) => {
+                // the guild may have been removed while the tier was being fetched
+                if self.message_cache.contains_guild(guild_id) {
+                    self.message_cache.set_guild_tier(guild_id, tier);
+                }
+            }
         }
     }
 
-    async fn handle_event(&mut self, evt: Event) {
+    // twilight doesn't know about polls yet, so we read the raw gateway messages to pick up the
+    // poll vote events and the polls on created messages, everything else is parsed by twilight
+    // the same way Shard::next_event would before going through handle_event
+    async fn handle_gateway_message(&mut self, json: String) {
+        let event_t = GatewayEventDeserializer::from_json(&json)
+            .and_then(|v| v.event_type().map(ToOwned::to_owned));
+
+        let poll = match event_t.as_deref() {
+            Some("MESSAGE_POLL_VOTE_ADD" | "MESSAGE_POLL_VOTE_REMOVE") => {
+                self.handle_poll_vote_event(&json).await;
+                return;
+            }
+            Some("MESSAGE_CREATE") => parse_message_poll(&json),
+            _ => None,
+        };
+
+        match twilight_gateway::parse(json, EventTypeFlags::all()) {
+            Ok(Some(evt)) => self.handle_event(evt.into(), poll).await,
+            Ok(None) => {}
+            Err(err) => error!(?err, "failed parsing gateway event"),
+        }
+    }
+
+    async fn handle_poll_vote_event(&mut self, json: &str) {
+        metrics::counter!("bl.broker.handled_events_total").increment(1);
+
+        if let Some(poll_vote) = parse_poll_vote_event(json) {
+            self.dispatch_or_queue_event(poll_vote.guild_id, BrokerEvent::DiscordEvent(poll_vote))
+                .await;
+
+            metrics::counter!("bl.broker.dispatched_events").increment(1);
+        }
+    }
+
+    async fn handle_event(&mut self, evt: Event, poll: Option<Box<DiscordPoll>>) {
         metrics::counter!("bl.broker.handled_events_total").increment(1);
 
         match &evt {
@@ -149,6 +214,8 @@ impl Broker {
                 if !g.unavailable {
                     let _ = self.stores.set_guild_left_status(g.id, true).await;
                 }
+
+                self.message_cache.remove_guild(g.id);
             }
             Event::GuildCreate(gc) => {
                 let _ = self
@@ -167,6 +234,14 @@ impl Broker {
                     .await;
 
                 metrics::gauge!("bl.broker.connected_guilds_total").increment(1.0);
+
+                let tier = fetch_guild_premium_tier(self.stores.as_ref(), gc.id).await;
+                self.message_cache.set_guild_tier(gc.id, tier.unwrap_or_default());
+            }
+            Event::ChannelDelete(c) => {
+                if let Some(guild_id) = c.guild_id {
+                    self.message_cache.remove_channel(guild_id, c.id);
+                }
             }
             Event::MemberChunk(chunk) => {
                 let nonce = chunk.nonce.clone().unwrap_or_default();
@@ -186,7 +261,7 @@ impl Broker {
         };
 
         if let Ok(dispatch) = DispatchEvent::try_from(evt.clone()) {
-            if let Some(broker_event) = self.prepare_dispatch_event(dispatch) {
+            if let Some(broker_event) = self.prepare_dispatch_event(dispatch, poll) {
                 self.dispatch_or_queue_event(
                     broker_event.guild_id,
                     BrokerEvent::DiscordEvent(broker_event),
@@ -293,6 +368,7 @@ impl Broker {
     fn prepare_dispatch_event(
         &mut self,
         evt: DispatchEvent,
+        poll: Option<Box<DiscordPoll>>,
     ) -> Option<broker_scheduler_rpc::DiscordEvent> {
         let event_t = evt.kind().name().unwrap().to_string();
 
@@ -300,16 +376,39 @@ impl Broker {
             DispatchEvent::GuildDelete(g) => (g.id, DiscordEventData::GuildDelete(g)),
             DispatchEvent::GuildCreate(gc) => (gc.id, DiscordEventData::GuildCreate(gc)),
 
+            DispatchEvent::BanAdd(b) => (b.guild_id, DiscordEventData::BanAdd(b)),
+            DispatchEvent::BanRemove(b) => (b.guild_id, DiscordEventData::BanRemove(b)),
+
             DispatchEvent::MemberAdd(m) => (m.guild_id, DiscordEventData::MemberAdd(m)),
             DispatchEvent::MemberRemove(m) => (m.guild_id, DiscordEventData::MemberRemove(m)),
             DispatchEvent::MemberUpdate(m) => (m.guild_id, DiscordEventData::MemberUpdate(m)),
 
-            DispatchEvent::MessageCreate(m) => (m.guild_id?, DiscordEventData::MessageCreate(m)),
-            DispatchEvent::MessageDelete(m) => (m.guild_id?, DiscordEventData::MessageDelete(m)),
+            DispatchEvent::MessageCreate(m) => {
+                let guild_id = m.guild_id?;
+                self.message_cache.insert(guild_id, m.0.clone());
+                (guild_id, DiscordEventData::MessageCreate { event: m, poll })
+            }
+            DispatchEvent::MessageDelete(m) => {
+                let guild_id = m.guild_id?;
+                let previous = self.message_cache.remove(guild_id, &m).map(Box::new);
+                (
+                    guild_id,
+                    DiscordEventData::MessageDelete { event: m, previous },
+                )
+            }
             DispatchEvent::MessageDeleteBulk(m) => {
-                (m.guild_id?, DiscordEventData::MessageDeleteBulk(m))
+                let guild_id = m.guild_id?;
+                self.message_cache.remove_bulk(guild_id, &m);
+                (guild_id, DiscordEventData::MessageDeleteBulk(m))
+            }
+            DispatchEvent::MessageUpdate(m) => {
+                let guild_id = m.guild_id?;
+                let previous = self.message_cache.update(guild_id, &m).map(Box::new);
+                (
+                    guild_id,
+                    DiscordEventData::MessageUpdate { event: m, previous },
+                )
             }
-            DispatchEvent::MessageUpdate(m) => (m.guild_id?, DiscordEventData::MessageUpdate(m)),
 
             DispatchEvent::ReactionAdd(r) => (r.guild_id?, DiscordEventData::ReactionAdd(r)),
             DispatchEvent::ReactionRemove(r) => (r.guild_id?, DiscordEventData::ReactionRemove(r)),
@@ -344,6 +443,68 @@ impl Broker {
             DispatchEvent::InviteDelete(invite) => {
                 (invite.guild_id, DiscordEventData::InviteDelete(invite))
             }
+            DispatchEvent::GuildEmojisUpdate(v) => {
+                (v.guild_id, DiscordEventData::GuildEmojisUpdate(v))
+            }
+            DispatchEvent::GuildStickersUpdate(v) => {
+                (v.guild_id, DiscordEventData::GuildStickersUpdate(v))
+            }
+            DispatchEvent::GuildAuditLogEntryCreate(v) => {
+                (v.guild_id?, DiscordEventData::GuildAuditLogEntryCreate(v))
+            }
+            DispatchEvent::GuildScheduledEventCreate(v) => {
+                (v.guild_id, DiscordEventData::GuildScheduledEventCreate(v))
+            }
+            DispatchEvent::GuildScheduledEventUpdate(v) => {
+                (v.guild_id, DiscordEventData::GuildScheduledEventUpdate(v))
+            }
+            DispatchEvent::GuildScheduledEventDelete(v) => {
+                (v.guild_id, DiscordEventData::GuildScheduledEventDelete(v))
+            }
+            DispatchEvent::GuildScheduledEventUserAdd(v) => {
+                (v.guild_id, DiscordEventData::GuildScheduledEventUserAdd(v))
+            }
+            DispatchEvent::GuildScheduledEventUserRemove(v) => (
+                v.guild_id,
+                DiscordEventData::GuildScheduledEventUserRemove(v),
+            ),
+            DispatchEvent::AutoModerationActionExecution(v) => (
+                v.guild_id,
+                DiscordEventData::AutoModerationActionExecution(v),
+            ),
+            DispatchEvent::GuildUpdate(v) => {
+                // the cached guild and the broker guild share the same serialized format,
+                // this is the same conversion the state api does
+                let old_guild = self
+                    .discord_state
+                    .guild(v.id)
+                    .and_then(|g| serde_json::to_value(g.value()).ok())
+                    .and_then(|g| serde_json::from_value(g).ok())
+                    .map(Box::new);
+
+                (
+                    v.id,
+                    DiscordEventData::GuildUpdate {
+                        event: v,
+                        old_guild,
+                    },
+                )
+            }
+            DispatchEvent::RoleCreate(v) => (v.guild_id, DiscordEventData::RoleCreate(v)),
+            DispatchEvent::RoleUpdate(v) => {
+                let old_role = self.cached_role(v.guild_id, v.role.id);
+                (
+                    v.guild_id,
+                    DiscordEventData::RoleUpdate { event: v, old_role },
+                )
+            }
+            DispatchEvent::RoleDelete(v) => {
+                let old_role = self.cached_role(v.guild_id, v.role_id);
+                (
+                    v.guild_id,
+                    DiscordEventData::RoleDelete { event: v, old_role },
+                )
+            }
             DispatchEvent::VoiceStateUpdate(update) => {
                 let guild_id = update.guild_id?;
 
@@ -386,6 +547,34 @@ impl Broker {
         })
     }
 
+    // premium slots are managed through the webapi which has no way of telling us about
+    // changes, so we periodically refetch the tiers of all the guilds we have cached messages for
+    fn spawn_premium_tier_refresh(&self) {
+        let guild_ids = self.message_cache.guild_ids();
+        let stores = self.stores.clone();
+        let cmd_tx = self.cmd_tx.clone();
+
+        tokio::spawn(async move {
+            for guild_id in guild_ids {
+                if let Some(tier) = fetch_guild_premium_tier(stores.as_ref(), guild_id).await {
+                    if cmd_tx
+                        .send(BrokerCommand::SetGuildPremiumTier(guild_id, tier))
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    fn cached_role(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Option<Box<Role>> {
+        self.discord_state
+            .role(role_id)
+            .filter(|r| r.guild_id() == guild_id)
+            .map(|r| Box::new(r.value().resource().clone()))
+    }
+
     fn queue_event(&mut self, guild_id: Id<GuildMarker>, evt: BrokerEvent) {
         if Instant::elapsed(&self.scheduler_disconnected_at) > Duration::from_secs(60) {
             warn!("event queue too old, expired, clearing");
@@ -423,6 +612,7 @@ impl Broker {
 pub enum BrokerCommand {
     SchedulerConnected(TcpStream),
     RequestGuildMembers(GuildMembersRequest),
+    SetGuildPremiumTier(Id<GuildMarker>, Option<PremiumSlotTier>),
 }
 
 pub struct GuildMembersRequest {
@@ -435,3 +625,71 @@ struct PendingChunkState {
     received_chunks: u64,
     response: UnboundedSender<Vec<Member>>,
 }
+
+const PREMIUM_TIER_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 10);
+
+// returns none if the premium slots couldn't be fetched
+async fn fetch_guild_premium_tier(
+    stores: &dyn ConfigStore,
+    guild_id: Id<GuildMarker>,
+) -> Option<Option<PremiumSlotTier>> {
+    match stores.get_guild_premium_slots(guild_id).await {
+        Ok(slots) => Some(PremiumSlotTier::highest(&slots)),
+        Err(err) => {
+            warn!(%guild_id, ?err, "failed fetching premium slots");
+            None
+        }
+    }
+}
+
+// GUILD_MESSAGE_POLLS, not known to twilight 0.15 and bitflags 1 has no safe constructor that keeps
+// unknown bits, this can be replaced with the named flag once twilight is upgraded
+fn guild_message_polls_intent() -> Intents {
+    // SAFETY: Intents has no invariants on its bits, they're only serialized into the identify payload
+    unsafe { Intents::from_bits_unchecked(1 << 24) }
+}
+
+fn parse_message_poll(json: &str) -> Option<Box<DiscordPoll>> {
+    #[derive(Deserialize)]
+    struct RawDispatch {
+        d: DiscordMessagePoll,
+    }
+
+    match serde_json::from_str::<RawDispatch>(json) {
+        Ok(v) => v.d.poll.map(Box::new),
+        Err(err) => {
+            error!(?err, "failed parsing message poll");
+            None
+        }
+    }
+}
+
+fn parse_poll_vote_event(json: &str) -> Option<broker_scheduler_rpc::DiscordEvent> {
+    #[derive(Deserialize)]
+    struct RawDispatch {
+        d: MessagePollVote,
+    }
+
+    let event_t = GatewayEventDeserializer::from_json(json)?.event_type()?;
+
+    let vote = match serde_json::from_str::<RawDispatch>(json) {
+        Ok(v) => v.d,
+        Err(err) => {
+            error!(?err, "failed parsing poll vote event");
+            return None;
+        }
+    };
+
+    let guild_id = vote.guild_id?;
+    let event = if event_t == "MESSAGE_POLL_VOTE_ADD" {
+        DiscordEventData::MessagePollVoteAdd(vote)
+    } else {
+        DiscordEventData::MessagePollVoteRemove(vote)
+    };
+
+    Some(broker_scheduler_rpc::DiscordEvent {
+        t: event_t.to_string(),
+        guild_id,
+        event,
+    })
+}
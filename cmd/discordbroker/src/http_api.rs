@@ -11,15 +11,19 @@ use axum::{
     Json, Router,
 };
 use axum_extra::extract::Query;
-use dbrokerapi::state_client::ConnectedGuildsResponse;
+use dbrokerapi::state_client::{ConnectedGuildsResponse, SearchGuildMembersResponse};
 use serde::Deserialize;
 use tokio::sync::mpsc::unbounded_channel;
 use tracing::info;
-use twilight_cache_inmemory::{model::CachedGuild, InMemoryCache};
+use twilight_cache_inmemory::{
+    model::{CachedGuild, CachedMember},
+    InMemoryCache,
+};
 use twilight_model::{
     channel::Channel,
     guild::{Member, Role},
     id::Id,
+    user::User,
     voice::VoiceState,
 };
 
@@ -86,6 +90,10 @@ pub async fn run_http_server(
             get(handle_get_channel),
         )
         .route("/guilds/:guild_id/members", get(handle_get_members))
+        .route(
+            "/guilds/:guild_id/members/search",
+            get(handle_search_members),
+        )
         .route("/guilds/:guild_id/roles", get(handle_get_roles))
         .route("/guilds/:guild_id/roles/:role_id", get(handle_get_role))
         .route("/connected_guilds", get(handle_get_connected_guilds))
@@ -302,3 +310,107 @@ async fn handle_get_members(
 
     Ok(Json(received_members))
 }
+
+#[derive(Debug, Deserialize)]
+struct SearchGuildMembersQuery {
+    query: String,
+    limit: Option<usize>,
+    #[serde(default)]
+    fuzzy: bool,
+}
+
+/// Searches the cached members of a guild by username, global name and nickname
+///
+/// Note that the cache may not contain all the members of the guild, the response
+/// tells whether it does
+async fn handle_search_members(
+    Path(guild_id_u): Path<u64>,
+    State(state): State<RouterState>,
+    Query(query): Query<SearchGuildMembersQuery>,
+) -> ApiResult<Json<SearchGuildMembersResponse>> {
+    let guild_id = Id::new_checked(guild_id_u).ok_or(ApiError::BadGuildId)?;
+    let limit = query.limit.unwrap_or(25).clamp(1, 1000);
+    let search = query.query.to_lowercase();
+
+    let Some(member_ids) = state.discord_state.guild_members(guild_id) else {
+        return Err(ApiError::GuildNotFound);
+    };
+
+    let complete = state
+        .discord_state
+        .guild(guild_id)
+        .and_then(|guild| guild.member_count())
+        .is_some_and(|count| member_ids.len() as u64 >= count);
+
+    let mut matches = member_ids
+        .value()
+        .iter()
+        .filter_map(|user_id| {
+            let member = state.discord_state.member(guild_id, *user_id)?;
+            let user = state.discord_state.user(*user_id)?;
+
+            let score = [
+                Some(user.name.as_str()),
+                user.global_name.as_deref(),
+                member.nick(),
+            ]
+            .into_iter()
+            .flatten()
+            .filter_map(|name| member_match_score(name, &search, query.fuzzy))
+            .min()?;
+
+            Some((score, cached_to_member(&member, user.value().clone())))
+        })
+        .collect::<Vec<_>>();
+
+    matches.sort_by(|a, b| {
+        a.0.cmp(&b.0)
+            .then_with(|| a.1.user.name.cmp(&b.1.user.name))
+    });
+    matches.truncate(limit);
+
+    Ok(Json(SearchGuildMembersResponse {
+        members: matches.into_iter().map(|(_, member)| member).collect(),
+        complete,
+    }))
+}
+
+// lower is better, none if it does not match at all
+fn member_match_score(name: &str, search: &str, fuzzy: bool) -> Option<u8> {
+    let name = name.to_lowercase();
+
+    if name == search {
+        Some(0)
+    } else if name.starts_with(search) {
+        Some(1)
+    } else if !fuzzy {
+        None
+    } else if name.contains(search) {
+        Some(2)
+    } else if is_subsequence(search, &name) {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|h| h == c))
+}
+
+fn cached_to_member(member: &CachedMember, user: User) -> Member {
+    Member {
+        avatar: member.avatar(),
+        communication_disabled_until: member.communication_disabled_until(),
+        deaf: member.deaf().unwrap_or_default(),
+        flags: member.flags(),
+        joined_at: member.joined_at(),
+        mute: member.mute().unwrap_or_default(),
+        nick: member.nick().map(ToOwned::to_owned),
+        pending: member.pending(),
+        premium_since: member.premium_since(),
+        roles: member.roles().to_vec(),
+        user,
+    }
+}
@@ -9,6 +9,7 @@ use crate::{broker::run_broker, http_api::run_http_server};
 mod broker;
 mod dispatch_server;
 mod http_api;
+mod message_cache;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
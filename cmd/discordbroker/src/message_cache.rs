@@ -0,0 +1,216 @@
+use std::collections::{HashMap, VecDeque};
+
+use stores::config::PremiumSlotTier;
+use twilight_model::{
+    channel::Message,
+    gateway::payload::incoming::{MessageDelete, MessageDeleteBulk, MessageUpdate},
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker},
+        Id,
+    },
+};
+
+/// Keeps the most recent messages of every channel around so that we can provide
+/// the previous state of a message on update and delete events
+#[derive(Default)]
+pub struct MessageCache {
+    guilds: HashMap<Id<GuildMarker>, GuildMessageCache>,
+}
+
+impl MessageCache {
+    pub fn set_guild_tier(&mut self, guild_id: Id<GuildMarker>, tier: Option<PremiumSlotTier>) {
+        let limits = CacheLimits::for_tier(tier);
+        let guild = self.guilds.entry(guild_id).or_default();
+        guild.limits = limits;
+        guild.enforce_limits();
+    }
+
+    pub fn contains_guild(&self, guild_id: Id<GuildMarker>) -> bool {
+        self.guilds.contains_key(&guild_id)
+    }
+
+    pub fn guild_ids(&self) -> Vec<Id<GuildMarker>> {
+        self.guilds.keys().copied().collect()
+    }
+
+    pub fn remove_guild(&mut self, guild_id: Id<GuildMarker>) {
+        self.guilds.remove(&guild_id);
+    }
+
+    pub fn insert(&mut self, guild_id: Id<GuildMarker>, message: Message) {
+        self.guilds.entry(guild_id).or_default().insert(message);
+    }
+
+    /// Applies the update to the cached message, returning the message as it was before the update
+    pub fn update(&mut self, guild_id: Id<GuildMarker>, update: &MessageUpdate) -> Option<Message> {
+        let message = self
+            .guilds
+            .get_mut(&guild_id)?
+            .get_mut(update.channel_id, update.id)?;
+
+        let previous = message.clone();
+        apply_message_update(message, update);
+
+        Some(previous)
+    }
+
+    pub fn remove(&mut self, guild_id: Id<GuildMarker>, delete: &MessageDelete) -> Option<Message> {
+        self.guilds
+            .get_mut(&guild_id)?
+            .remove(delete.channel_id, delete.id)
+    }
+
+    pub fn remove_bulk(&mut self, guild_id: Id<GuildMarker>, delete: &MessageDeleteBulk) {
+        if let Some(guild) = self.guilds.get_mut(&guild_id) {
+            for id in &delete.ids {
+                guild.remove(delete.channel_id, *id);
+            }
+        }
+    }
+
+    pub fn remove_channel(&mut self, guild_id: Id<GuildMarker>, channel_id: Id<ChannelMarker>) {
+        if let Some(guild) = self.guilds.get_mut(&guild_id) {
+            guild.remove_channel(channel_id);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CacheLimits {
+    max_channels: usize,
+    max_messages_per_channel: usize,
+}
+
+impl CacheLimits {
+    fn for_tier(tier: Option<PremiumSlotTier>) -> Self {
+        match tier {
+            None => Self {
+                max_channels: 25,
+                max_messages_per_channel: 50,
+            },
+            Some(PremiumSlotTier::Lite) => Self {
+                max_channels: 100,
+                max_messages_per_channel: 100,
+            },
+            Some(PremiumSlotTier::Premium) => Self {
+                max_channels: 250,
+                max_messages_per_channel: 250,
+            },
+        }
+    }
+}
+
+impl Default for CacheLimits {
+    fn default() -> Self {
+        Self::for_tier(None)
+    }
+}
+
+#[derive(Default)]
+struct GuildMessageCache {
+    limits: CacheLimits,
+    channels: HashMap<Id<ChannelMarker>, VecDeque<Message>>,
+
+    // channels ordered by the last time a message was sent in them, the front being the oldest
+    channel_activity: VecDeque<Id<ChannelMarker>>,
+}
+
+impl GuildMessageCache {
+    fn insert(&mut self, message: Message) {
+        let channel_id = message.channel_id;
+
+        let messages = self.channels.entry(channel_id).or_default();
+        messages.push_back(message);
+
+        self.channel_activity.retain(|v| *v != channel_id);
+        self.channel_activity.push_back(channel_id);
+
+        self.enforce_limits();
+    }
+
+    fn get_mut(
+        &mut self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Option<&mut Message> {
+        self.channels
+            .get_mut(&channel_id)?
+            .iter_mut()
+            .find(|v| v.id == message_id)
+    }
+
+    fn remove(
+        &mut self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Option<Message> {
+        let messages = self.channels.get_mut(&channel_id)?;
+        let index = messages.iter().position(|v| v.id == message_id)?;
+        messages.remove(index)
+    }
+
+    fn remove_channel(&mut self, channel_id: Id<ChannelMarker>) {
+        self.channels.remove(&channel_id);
+        self.channel_activity.retain(|v| *v != channel_id);
+    }
+
+    fn enforce_limits(&mut self) {
+        while self.channel_activity.len() > self.limits.max_channels {
+            if let Some(oldest) = self.channel_activity.pop_front() {
+                self.channels.remove(&oldest);
+            }
+        }
+
+        for messages in self.channels.values_mut() {
+            while messages.len() > self.limits.max_messages_per_channel {
+                messages.pop_front();
+            }
+        }
+    }
+}
+
+fn apply_message_update(message: &mut Message, update: &MessageUpdate) {
+    if let Some(attachments) = &update.attachments {
+        message.attachments = attachments.clone();
+    }
+
+    if let Some(author) = &update.author {
+        message.author = author.clone();
+    }
+
+    if let Some(content) = &update.content {
+        message.content = content.clone();
+    }
+
+    if let Some(edited_timestamp) = update.edited_timestamp {
+        message.edited_timestamp = Some(edited_timestamp);
+    }
+
+    if let Some(embeds) = &update.embeds {
+        message.embeds = embeds.clone();
+    }
+
+    if let Some(kind) = update.kind {
+        message.kind = kind;
+    }
+
+    if let Some(mention_everyone) = update.mention_everyone {
+        message.mention_everyone = mention_everyone;
+    }
+
+    if let Some(mention_roles) = &update.mention_roles {
+        message.mention_roles = mention_roles.clone();
+    }
+
+    if let Some(mentions) = &update.mentions {
+        message.mentions = mentions.clone();
+    }
+
+    if let Some(pinned) = update.pinned {
+        message.pinned = pinned;
+    }
+
+    if let Some(tts) = update.tts {
+        message.tts = tts;
+    }
+}
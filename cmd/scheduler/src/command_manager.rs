@@ -13,6 +13,7 @@ use twilight_model::application::command::{
 };
 
 use runtime_models::internal::script::{Command, CommandGroup, ScriptMeta};
+use twilight_model::guild::Permissions;
 use twilight_model::id::marker::GuildMarker;
 use twilight_model::id::Id;
 
@@ -261,10 +262,12 @@ pub fn to_twilight_commands(
             kind: cmd.kind.into(),
             version: Id::new(1),
             dm_permission: None,
-            default_member_permissions: None,
-            description_localizations: Default::default(),
-            name_localizations: Default::default(),
-            nsfw: None,
+            default_member_permissions: parse_permissions(
+                cmd.default_member_permissions.as_deref(),
+            ),
+            description_localizations: cmd.description_localizations.clone(),
+            name_localizations: cmd.name_localizations.clone(),
+            nsfw: cmd.nsfw,
         })
         .collect::<Vec<_>>();
 
@@ -400,14 +403,20 @@ pub fn group_to_twilight_command(
         name: group.name.clone(),
         options: opts,
         version: Id::new(1),
-        default_member_permissions: None,
+        default_member_permissions: parse_permissions(group.default_member_permissions.as_deref()),
         dm_permission: None,
         description_localizations: Default::default(),
         name_localizations: Default::default(),
-        nsfw: None,
+        nsfw: group.nsfw,
     }
 }
 
+// the raw value has already been validated at this point
+fn parse_permissions(raw: Option<&str>) -> Option<Permissions> {
+    raw.and_then(|v| v.parse().ok())
+        .map(Permissions::from_bits_truncate)
+}
+
 fn merge_script_commands(scripts: Vec<Script>) -> Vec<TwilightCommand> {
     let mut result = Vec::new();
 
@@ -433,6 +442,19 @@ fn merge_command(dst: &mut TwilightCommand, src: TwilightCommand) {
         dst.description = src.description;
     }
 
+    // when multiple scripts set permissions on the same group, members need all of them
+    dst.default_member_permissions = match (
+        dst.default_member_permissions,
+        src.default_member_permissions,
+    ) {
+        (Some(dst_perms), Some(src_perms)) => Some(dst_perms | src_perms),
+        (dst_perms, src_perms) => dst_perms.or(src_perms),
+    };
+
+    if src.nsfw == Some(true) {
+        dst.nsfw = Some(true);
+    }
+
     for opt in &dst.options {
         if !matches!(
             opt.kind,
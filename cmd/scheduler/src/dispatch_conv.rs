@@ -4,28 +4,67 @@ use twilight_model::id::{marker::GuildMarker, Id};
 
 pub fn discord_event_to_dispatch(evt: DiscordEvent) -> Option<DiscordDispatchEvent> {
     match evt.event {
-        DiscordEventData::MessageCreate(m) => Some(DiscordDispatchEvent {
+        DiscordEventData::MessageCreate { event, poll } => Some(DiscordDispatchEvent {
             name: "MESSAGE_CREATE",
             guild_id: evt.guild_id,
-            data: serde_json::to_value(&runtime_models::internal::messages::Message::from(m.0))
-                .unwrap(),
+            data: serde_json::to_value(&runtime_models::internal::messages::Message {
+                poll: poll.map(|v| (*v).into()),
+                ..runtime_models::internal::messages::Message::from(event.0)
+            })
+            .unwrap(),
         }),
-        DiscordEventData::MessageUpdate(m) => Some(DiscordDispatchEvent {
+        DiscordEventData::MessageUpdate { event, previous } => Some(DiscordDispatchEvent {
             name: "MESSAGE_UPDATE",
             guild_id: evt.guild_id,
-            data: serde_json::to_value(runtime_models::internal::events::EventMessageUpdate::from(
-                *m,
-            ))
+            data: serde_json::to_value(runtime_models::internal::events::EventMessageUpdate {
+                previous: previous.map(|v| runtime_models::internal::messages::Message::from(*v)),
+                ..runtime_models::internal::events::EventMessageUpdate::from(*event)
+            })
             .unwrap(),
         }),
-        DiscordEventData::MessageDelete(m) => Some(DiscordDispatchEvent {
+        DiscordEventData::MessageDelete { event, previous } => Some(DiscordDispatchEvent {
             name: "MESSAGE_DELETE",
             guild_id: evt.guild_id,
-            data: serde_json::to_value(runtime_models::discord::events::EventMessageDelete::from(
-                m,
-            ))
+            data: serde_json::to_value(runtime_models::discord::events::EventMessageDelete {
+                previous: previous.map(|v| runtime_models::internal::messages::Message::from(*v)),
+                ..runtime_models::discord::events::EventMessageDelete::from(event)
+            })
+            .unwrap(),
+        }),
+        DiscordEventData::MessageDeleteBulk(m) => Some(DiscordDispatchEvent {
+            name: "MESSAGE_DELETE_BULK",
+            guild_id: evt.guild_id,
+            data: serde_json::to_value(
+                runtime_models::discord::events::EventMessageDeleteBulk::from(m),
+            )
             .unwrap(),
         }),
+        DiscordEventData::MessagePollVoteAdd(v) => Some(DiscordDispatchEvent {
+            name: "MESSAGE_POLL_VOTE_ADD",
+            guild_id: evt.guild_id,
+            data: serde_json::to_value(
+                runtime_models::discord::events::EventMessagePollVote::from(v),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::MessagePollVoteRemove(v) => Some(DiscordDispatchEvent {
+            name: "MESSAGE_POLL_VOTE_REMOVE",
+            guild_id: evt.guild_id,
+            data: serde_json::to_value(
+                runtime_models::discord::events::EventMessagePollVote::from(v),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::BanAdd(b) => Some(DiscordDispatchEvent {
+            name: "BAN_ADD",
+            guild_id: evt.guild_id,
+            data: serde_json::to_value(runtime_models::discord::events::EventBan::from(b)).unwrap(),
+        }),
+        DiscordEventData::BanRemove(b) => Some(DiscordDispatchEvent {
+            name: "BAN_REMOVE",
+            guild_id: evt.guild_id,
+            data: serde_json::to_value(runtime_models::discord::events::EventBan::from(b)).unwrap(),
+        }),
         DiscordEventData::MemberAdd(m) => Some(DiscordDispatchEvent {
             name: "MEMBER_ADD",
             guild_id: m.guild_id,
@@ -157,6 +196,13 @@ pub fn discord_event_to_dispatch(evt: DiscordEvent) -> Option<DiscordDispatchEve
                         name: "BOTLOADER_COMMAND_INTERACTION_CREATE",
                         data: serde_json::to_value(&cmd_interaction).unwrap(),
                     }),
+                    runtime_models::internal::interaction::Interaction::Autocomplete(
+                        autocomplete_interaction,
+                    ) => Some(DiscordDispatchEvent {
+                        guild_id,
+                        name: "BOTLOADER_AUTOCOMPLETE_INTERACTION_CREATE",
+                        data: serde_json::to_value(&autocomplete_interaction).unwrap(),
+                    }),
                     runtime_models::internal::interaction::Interaction::MessageComponent(
                         component_interaction,
                     ) => Some(DiscordDispatchEvent {
@@ -210,9 +256,115 @@ pub fn discord_event_to_dispatch(evt: DiscordEvent) -> Option<DiscordDispatchEve
             })
             .unwrap(),
         }),
+        DiscordEventData::GuildEmojisUpdate(v) => Some(DiscordDispatchEvent {
+            guild_id: v.guild_id,
+            name: "GUILD_EMOJIS_UPDATE",
+            data: serde_json::to_value(
+                runtime_models::internal::events::EventGuildEmojisUpdate::from(v),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::GuildStickersUpdate(v) => Some(DiscordDispatchEvent {
+            guild_id: v.guild_id,
+            name: "GUILD_STICKERS_UPDATE",
+            data: serde_json::to_value(
+                runtime_models::internal::events::EventGuildStickersUpdate::from(v),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::GuildAuditLogEntryCreate(v) => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "GUILD_AUDIT_LOG_ENTRY_CREATE",
+            data: serde_json::to_value(runtime_models::discord::audit_log::AuditLogEntry::from(
+                v.0,
+            ))
+            .unwrap(),
+        }),
+        DiscordEventData::GuildScheduledEventCreate(v) => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "GUILD_SCHEDULED_EVENT_CREATE",
+            data: serde_json::to_value(
+                runtime_models::internal::scheduled_event::ScheduledEvent::from(v.0),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::GuildScheduledEventUpdate(v) => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "GUILD_SCHEDULED_EVENT_UPDATE",
+            data: serde_json::to_value(
+                runtime_models::internal::scheduled_event::ScheduledEvent::from(v.0),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::GuildScheduledEventDelete(v) => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "GUILD_SCHEDULED_EVENT_DELETE",
+            data: serde_json::to_value(
+                runtime_models::internal::scheduled_event::ScheduledEvent::from(v.0),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::GuildScheduledEventUserAdd(v) => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "GUILD_SCHEDULED_EVENT_USER_ADD",
+            data: serde_json::to_value(
+                runtime_models::discord::events::EventScheduledEventUser::from(v),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::GuildScheduledEventUserRemove(v) => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "GUILD_SCHEDULED_EVENT_USER_REMOVE",
+            data: serde_json::to_value(
+                runtime_models::discord::events::EventScheduledEventUser::from(v),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::AutoModerationActionExecution(v) => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "AUTO_MODERATION_ACTION_EXECUTION",
+            data: serde_json::to_value(
+                runtime_models::discord::events::EventAutoModerationActionExecution::from(v),
+            )
+            .unwrap(),
+        }),
+        DiscordEventData::GuildUpdate { event, old_guild } => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "GUILD_UPDATE",
+            data: serde_json::to_value(runtime_models::internal::events::EventGuildUpdate {
+                new: runtime_models::discord::guild::Guild::from_partial(
+                    event.0,
+                    old_guild.as_deref(),
+                ),
+                old: old_guild.map(|v| (*v).into()),
+            })
+            .unwrap(),
+        }),
+        DiscordEventData::RoleCreate(v) => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "ROLE_CREATE",
+            data: serde_json::to_value(runtime_models::discord::role::Role::from(v.role)).unwrap(),
+        }),
+        DiscordEventData::RoleUpdate { event, old_role } => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "ROLE_UPDATE",
+            data: serde_json::to_value(runtime_models::internal::events::EventRoleUpdate {
+                new: event.role.into(),
+                old: old_role.map(|v| (*v).into()),
+            })
+            .unwrap(),
+        }),
+        DiscordEventData::RoleDelete { event, old_role } => Some(DiscordDispatchEvent {
+            guild_id: evt.guild_id,
+            name: "ROLE_DELETE",
+            data: serde_json::to_value(runtime_models::internal::events::EventRoleDelete {
+                role_id: event.role_id.to_string(),
+                old: old_role.map(|v| (*v).into()),
+            })
+            .unwrap(),
+        }),
         DiscordEventData::GuildDelete(_) => None,
         DiscordEventData::GuildCreate(_) => None,
-        DiscordEventData::MessageDeleteBulk(_) => None,
     }
 }
 
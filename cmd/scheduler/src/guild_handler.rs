@@ -206,19 +206,8 @@ impl GuildHandler {
             return;
         };
 
-        let mut highest_tier = Option::<PremiumSlotTier>::None;
-        for slot in slots {
-            if let Some(current_highest) = highest_tier {
-                if slot.tier.is_higher_than(current_highest) {
-                    highest_tier = Some(slot.tier);
-                }
-            } else {
-                highest_tier = Some(slot.tier);
-            }
-        }
-
         let mut w = self.premium_tier.write().unwrap();
-        *w = PremiumTierState::Fetched(highest_tier);
+        *w = PremiumTierState::Fetched(PremiumSlotTier::highest(&slots));
     }
 
     async fn handle_broker_event(&mut self, evt: DiscordEvent) {
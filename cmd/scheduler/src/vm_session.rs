@@ -15,7 +15,10 @@ use crate::{
 use common::DiscordConfig;
 use dbrokerapi::broker_scheduler_rpc::DiscordEvent;
 use guild_logger::{entry::CreateLogEntry, GuildLogSender};
-use runtime_models::{internal::script::ScriptMeta, util::PluginId};
+use runtime_models::{
+    internal::{script::ScriptMeta, storage::StorageChangedEvent},
+    util::PluginId,
+};
 use scheduler_worker_rpc::{
     CreateScriptsVmReq, MetricEvent, SchedulerMessage, VmDispatchEvent, WorkerMessage,
 };
@@ -49,6 +52,9 @@ pub struct VmSession {
     last_claimed_worker_id: Option<u64>,
     last_claimed_worker_at: Instant,
     last_returned_worker_at: Instant,
+
+    // keyed by plugin id and bucket name
+    storage_changed_limiters: HashMap<(Option<u64>, String), StorageChangedLimiter>,
 }
 
 impl VmSession {
@@ -87,6 +93,8 @@ impl VmSession {
             last_claimed_worker_id: None,
             last_claimed_worker_at: Instant::now(),
             last_returned_worker_at: Instant::now(),
+
+            storage_changed_limiters: HashMap::new(),
         }
     }
 
@@ -381,6 +389,9 @@ impl VmSession {
                 // handled in caller
             }
             WorkerMessage::Metric(name, m, labels) => self.handle_metric(name, m, labels),
+            WorkerMessage::StorageChanged(evt) => {
+                self.dispatch_storage_changed(evt).await;
+            }
         }
     }
 
@@ -445,6 +456,34 @@ impl VmSession {
         .await;
     }
 
+    async fn dispatch_storage_changed(&mut self, evt: StorageChangedEvent) {
+        let limiter = self
+            .storage_changed_limiters
+            .entry((evt.plugin_id.map(|v| v.0), evt.bucket_name.clone()))
+            .or_insert_with(StorageChangedLimiter::new);
+
+        match limiter.check() {
+            StorageChangedCheck::Allowed => {}
+            StorageChangedCheck::Paused => return,
+            StorageChangedCheck::JustPaused => {
+                self.logger.log(CreateLogEntry::error(format!(
+                    "too many storage change events for bucket {}, pausing them for {} seconds",
+                    evt.bucket_name,
+                    STORAGE_CHANGED_COOLDOWN.as_secs()
+                )));
+                return;
+            }
+        }
+
+        let serialized = serde_json::to_value(&evt).unwrap();
+        self.dispatch_worker_evt(
+            "STORAGE_CHANGED".to_string(),
+            serialized,
+            PendingAck::Dispatch(None),
+        )
+        .await;
+    }
+
     pub async fn send_discord_guild_event(&mut self, evt: DiscordEvent) {
         if let Some(converted_evt) = crate::dispatch_conv::discord_event_to_dispatch(evt) {
             self.dispatch_worker_evt(
@@ -673,6 +712,64 @@ enum ClaimWorkerResult {
     Reloaded,
 }
 
+const STORAGE_CHANGED_WINDOW: Duration = Duration::from_secs(10);
+const STORAGE_CHANGED_MAX_PER_WINDOW: u32 = 100;
+const STORAGE_CHANGED_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Rate limit for the storage change events of a single bucket
+///
+/// Loops between handlers are caught by the runtime through the event depth, this keeps a
+/// single busy bucket from flooding the vm with events, once the limit is hit the events for
+/// that bucket are dropped for a while.
+struct StorageChangedLimiter {
+    window_start: Instant,
+    in_window: u32,
+    paused_until: Option<Instant>,
+}
+
+enum StorageChangedCheck {
+    Allowed,
+    Paused,
+    // the limit was hit by this event
+    JustPaused,
+}
+
+impl StorageChangedLimiter {
+    fn new() -> Self {
+        Self {
+            window_start: Instant::now(),
+            in_window: 0,
+            paused_until: None,
+        }
+    }
+
+    fn check(&mut self) -> StorageChangedCheck {
+        let now = Instant::now();
+
+        if let Some(paused_until) = self.paused_until {
+            if now < paused_until {
+                return StorageChangedCheck::Paused;
+            }
+            self.paused_until = None;
+        }
+
+        if now.duration_since(self.window_start) > STORAGE_CHANGED_WINDOW {
+            self.window_start = now;
+            self.in_window = 0;
+        }
+
+        self.in_window += 1;
+        if self.in_window > STORAGE_CHANGED_MAX_PER_WINDOW {
+            self.paused_until = Some(now + STORAGE_CHANGED_COOLDOWN);
+            self.window_start = now;
+            self.in_window = 0;
+            return StorageChangedCheck::JustPaused;
+        }
+
+        StorageChangedCheck::Allowed
+    }
+}
+
 pub struct VmSessionStatus {
     pub current_claimed_worker: Option<u64>,
     pub last_claimed_worker: Option<u64>,
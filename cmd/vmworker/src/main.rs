@@ -259,6 +259,9 @@ impl Worker {
                 ))
                 .await?;
             }
+            RuntimeEvent::StorageChanged(evt) => {
+                self.write_message(WorkerMessage::StorageChanged(evt)).await?;
+            }
         }
         Ok(ContinueState::Continue)
     }
@@ -131,7 +131,8 @@ async fn main() {
         .route("/reload_vm", post(routes::vm::reload_guild_vm))
         .route(
             "/settings",
-            get(routes::guilds::get_guild_settings::<CurrentSessionStore>),
+            get(routes::guilds::get_guild_settings::<CurrentSessionStore>)
+                .patch(routes::guilds::update_guild_settings::<CurrentSessionStore>),
         )
         .route(
             "/premium_slots",
@@ -14,7 +14,7 @@ use twilight_model::{
 
 use crate::{errors::ApiErrorResponse, middlewares::LoggedInSession, ApiResult};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tracing::error;
 
 #[derive(Serialize)]
@@ -80,6 +80,40 @@ pub async fn get_guild_settings<CT: ConfigStore + 'static>(
     Ok(Json(settings))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct UpdateGuildSettingsData {
+    #[serde(default)]
+    pub allow_direct_messages: Option<bool>,
+}
+
+pub async fn update_guild_settings<CT: ConfigStore + 'static>(
+    Extension(config_store): Extension<CT>,
+    Extension(current_guild): Extension<CurrentUserGuild>,
+    Json(payload): Json<UpdateGuildSettingsData>,
+) -> ApiResult<impl IntoResponse> {
+    let mut settings = config_store
+        .get_guild_meta_config_or_default(current_guild.id)
+        .await
+        .map_err(|err| {
+            error!(%err, "failed fetching guild config");
+            ApiErrorResponse::InternalError
+        })?;
+
+    if let Some(allow_direct_messages) = payload.allow_direct_messages {
+        settings.allow_direct_messages = allow_direct_messages;
+    }
+
+    let settings = config_store
+        .update_guild_meta_config(&settings)
+        .await
+        .map_err(|err| {
+            error!(%err, "failed updating guild config");
+            ApiErrorResponse::InternalError
+        })?;
+
+    Ok(Json(settings))
+}
+
 pub async fn get_guild_premium_slots<CT: ConfigStore + 'static>(
     Extension(config_store): Extension<CT>,
     Extension(current_guild): Extension<CurrentUserGuild>,
@@ -1,16 +1,24 @@
 use serde::{Deserialize, Serialize};
 use twilight_model::{
+    channel::Message,
     gateway::payload::incoming::{
-        ChannelCreate, ChannelDelete, ChannelUpdate, GuildCreate, GuildDelete, InteractionCreate,
-        InviteCreate, InviteDelete, MemberAdd, MemberRemove, MemberUpdate, MessageCreate,
-        MessageDelete, MessageDeleteBulk, MessageUpdate, ReactionAdd, ReactionRemove,
-        ReactionRemoveAll, ReactionRemoveEmoji, ThreadCreate, ThreadDelete, ThreadListSync,
-        ThreadMemberUpdate, ThreadMembersUpdate, ThreadUpdate, VoiceStateUpdate,
+        AutoModerationActionExecution, BanAdd, BanRemove, ChannelCreate, ChannelDelete,
+        ChannelUpdate, GuildAuditLogEntryCreate, GuildCreate, GuildDelete, GuildEmojisUpdate,
+        GuildScheduledEventCreate, GuildScheduledEventDelete, GuildScheduledEventUpdate,
+        GuildScheduledEventUserAdd, GuildScheduledEventUserRemove, GuildStickersUpdate,
+        GuildUpdate, InteractionCreate, InviteCreate, InviteDelete, MemberAdd, MemberRemove,
+        MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate, ReactionAdd,
+        ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji, RoleCreate, RoleDelete, RoleUpdate,
+        ThreadCreate, ThreadDelete, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate,
+        ThreadUpdate, VoiceStateUpdate,
     },
+    guild::Role,
     id::{marker::GuildMarker, Id},
     voice::VoiceState,
 };
 
+use crate::models::{BrokerGuild, DiscordPoll, MessagePollVote};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum BrokerEvent {
     Hello(HelloData),
@@ -33,15 +41,55 @@ pub struct DiscordEvent {
 pub enum DiscordEventData {
     GuildDelete(GuildDelete),
     GuildCreate(Box<GuildCreate>),
+    GuildEmojisUpdate(GuildEmojisUpdate),
+    GuildStickersUpdate(GuildStickersUpdate),
+    GuildAuditLogEntryCreate(Box<GuildAuditLogEntryCreate>),
+    GuildUpdate {
+        event: Box<GuildUpdate>,
+        old_guild: Option<Box<BrokerGuild>>,
+    },
+
+    RoleCreate(Box<RoleCreate>),
+    RoleUpdate {
+        event: Box<RoleUpdate>,
+        old_role: Option<Box<Role>>,
+    },
+    RoleDelete {
+        event: RoleDelete,
+        old_role: Option<Box<Role>>,
+    },
+
+    GuildScheduledEventCreate(Box<GuildScheduledEventCreate>),
+    GuildScheduledEventUpdate(Box<GuildScheduledEventUpdate>),
+    GuildScheduledEventDelete(Box<GuildScheduledEventDelete>),
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
+
+    AutoModerationActionExecution(AutoModerationActionExecution),
+
+    BanAdd(BanAdd),
+    BanRemove(BanRemove),
 
     MemberAdd(Box<MemberAdd>),
     MemberRemove(MemberRemove),
     MemberUpdate(Box<MemberUpdate>),
 
-    MessageCreate(Box<MessageCreate>),
-    MessageDelete(MessageDelete),
+    MessageCreate {
+        event: Box<MessageCreate>,
+        /// twilight drops the poll from messages, so the broker parses it out of the raw payload
+        poll: Option<Box<DiscordPoll>>,
+    },
+    MessageDelete {
+        event: MessageDelete,
+        previous: Option<Box<Message>>,
+    },
     MessageDeleteBulk(MessageDeleteBulk),
-    MessageUpdate(Box<MessageUpdate>),
+    MessageUpdate {
+        event: Box<MessageUpdate>,
+        previous: Option<Box<Message>>,
+    },
+    MessagePollVoteAdd(MessagePollVote),
+    MessagePollVoteRemove(MessagePollVote),
 
     ReactionAdd(Box<ReactionAdd>),
     ReactionRemove(Box<ReactionRemove>),
@@ -1,6 +1,8 @@
 /// The twilight cache models don't implement deserialize, so this just duplicates the structs for deserialization purposes
 mod guild;
 mod member;
+mod poll;
 
 pub use guild::*;
 pub use member::*;
+pub use poll::*;
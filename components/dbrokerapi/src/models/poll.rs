@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use twilight_model::{
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+        Id,
+    },
+    util::Timestamp,
+};
+
+/// Payload of the MESSAGE_POLL_VOTE_ADD and MESSAGE_POLL_VOTE_REMOVE gateway events,
+/// twilight doesn't have a model for these yet
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MessagePollVote {
+    pub user_id: Id<UserMarker>,
+    pub channel_id: Id<ChannelMarker>,
+    pub message_id: Id<MessageMarker>,
+    #[serde(default)]
+    pub guild_id: Option<Id<GuildMarker>>,
+    pub answer_id: u8,
+}
+
+// twilight doesn't have models for polls yet, so the types below mirror the discord api
+// and are used to parse them out of the raw message payloads
+
+/// The poll field of a raw message payload
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiscordMessagePoll {
+    #[serde(default)]
+    pub poll: Option<DiscordPoll>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiscordPoll {
+    pub question: DiscordPollMedia,
+    pub answers: Vec<DiscordPollAnswer>,
+    #[serde(default)]
+    pub expiry: Option<Timestamp>,
+    #[serde(default)]
+    pub allow_multiselect: bool,
+    #[serde(default)]
+    pub results: Option<DiscordPollResults>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiscordPollMedia {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<DiscordPollEmoji>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiscordPollEmoji {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub animated: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiscordPollAnswer {
+    pub answer_id: u8,
+    pub poll_media: DiscordPollMedia,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiscordPollResults {
+    pub is_finalized: bool,
+    pub answer_counts: Vec<DiscordPollAnswerCount>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiscordPollAnswerCount {
+    pub id: u8,
+    pub count: u32,
+    pub me_voted: bool,
+}
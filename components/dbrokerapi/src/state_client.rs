@@ -40,7 +40,21 @@ impl Client {
 
     pub async fn get_option<T: DeserializeOwned>(&self, url: String) -> ApiResult<Option<T>> {
         let resp = self.client.get(url).send().await?;
+        Self::handle_option_response(resp).await
+    }
+
+    pub async fn get_option_with_query<T: DeserializeOwned, Q: Serialize + ?Sized>(
+        &self,
+        url: String,
+        query: &Q,
+    ) -> ApiResult<Option<T>> {
+        let resp = self.client.get(url).query(query).send().await?;
+        Self::handle_option_response(resp).await
+    }
 
+    async fn handle_option_response<T: DeserializeOwned>(
+        resp: reqwest::Response,
+    ) -> ApiResult<Option<T>> {
         if resp.status() == StatusCode::NOT_FOUND {
             return Ok(None);
         } else if !resp.status().is_success() {
@@ -134,6 +148,43 @@ impl Client {
         .await
         .map(|inner| inner.unwrap())
     }
+
+    /// Searches the cached members of the guild by username, global name and nickname
+    ///
+    /// Matches by prefix, or also by substring and subsequence if fuzzy is set
+    pub async fn search_guild_members(
+        &self,
+        guild_id: Id<GuildMarker>,
+        query: &str,
+        limit: u32,
+        fuzzy: bool,
+    ) -> ApiResult<Option<SearchGuildMembersResponse>> {
+        self.get_option_with_query(
+            format!("{}/guilds/{}/members/search", self.server_addr, guild_id),
+            &SearchGuildMembersQuery {
+                query,
+                limit,
+                fuzzy,
+            },
+        )
+        .await
+    }
+}
+
+#[derive(Serialize)]
+struct SearchGuildMembersQuery<'a> {
+    query: &'a str,
+    limit: u32,
+    fuzzy: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchGuildMembersResponse {
+    pub members: Vec<Member>,
+
+    /// Whether the cache holds all the members of the guild, if not then
+    /// members missing from the results may still exist
+    pub complete: bool,
 }
 
 #[derive(Serialize, Deserialize)]
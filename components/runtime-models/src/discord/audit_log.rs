@@ -0,0 +1,290 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AuditLogEntry.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntry {
+    pub id: String,
+    pub action_type: AuditLogActionType,
+    pub changes: Vec<AuditLogChange>,
+    pub options: Option<AuditLogEntryInfo>,
+    pub reason: Option<String>,
+    pub target_id: Option<String>,
+    // the user or app that made the change
+    pub user_id: Option<String>,
+}
+
+impl From<twilight_model::guild::audit_log::AuditLogEntry> for AuditLogEntry {
+    fn from(v: twilight_model::guild::audit_log::AuditLogEntry) -> Self {
+        Self {
+            id: v.id.to_string(),
+            action_type: v.action_type.into(),
+            changes: v.changes.into_iter().map(Into::into).collect(),
+            options: v.options.map(Into::into),
+            reason: v.reason,
+            target_id: v.target_id.as_ref().map(ToString::to_string),
+            user_id: v.user_id.as_ref().map(ToString::to_string),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AuditLogChange.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogChange {
+    pub key: String,
+    #[ts(type = "any")]
+    pub old_value: serde_json::Value,
+    #[ts(type = "any")]
+    pub new_value: serde_json::Value,
+}
+
+impl From<twilight_model::guild::audit_log::AuditLogChange> for AuditLogChange {
+    fn from(v: twilight_model::guild::audit_log::AuditLogChange) -> Self {
+        // the values vary wildly depending on the key, so we pass them on as they come from discord
+        let mut raw = match serde_json::to_value(v) {
+            Ok(serde_json::Value::Object(raw)) => raw,
+            _ => Default::default(),
+        };
+
+        Self {
+            key: match raw.remove("key") {
+                Some(serde_json::Value::String(key)) => key,
+                _ => "unknown".to_string(),
+            },
+            old_value: raw.remove("old_value").unwrap_or_default(),
+            new_value: raw.remove("new_value").unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AuditLogEntryInfo.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntryInfo {
+    pub channel_id: Option<String>,
+    pub count: Option<String>,
+    pub delete_member_days: Option<String>,
+    pub id: Option<String>,
+    pub kind: Option<String>,
+    pub members_removed: Option<String>,
+    pub message_id: Option<String>,
+    pub role_name: Option<String>,
+}
+
+impl From<twilight_model::guild::audit_log::AuditLogOptionalEntryInfo> for AuditLogEntryInfo {
+    fn from(v: twilight_model::guild::audit_log::AuditLogOptionalEntryInfo) -> Self {
+        Self {
+            channel_id: v.channel_id.as_ref().map(ToString::to_string),
+            count: v.count,
+            delete_member_days: v.delete_member_days,
+            id: v.id.as_ref().map(ToString::to_string),
+            kind: v.kind,
+            members_removed: v.members_removed,
+            message_id: v.message_id.as_ref().map(ToString::to_string),
+            role_name: v.role_name,
+        }
+    }
+}
+
+// we map these from the raw values ourselves since not all of them are known to twilight
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TS, PartialEq, Eq)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AuditLogActionType.ts")]
+pub enum AuditLogActionType {
+    GuildUpdate,
+    ChannelCreate,
+    ChannelUpdate,
+    ChannelDelete,
+    ChannelOverwriteCreate,
+    ChannelOverwriteUpdate,
+    ChannelOverwriteDelete,
+    MemberKick,
+    MemberPrune,
+    MemberBanAdd,
+    MemberBanRemove,
+    MemberUpdate,
+    MemberRoleUpdate,
+    MemberMove,
+    MemberDisconnect,
+    BotAdd,
+    RoleCreate,
+    RoleUpdate,
+    RoleDelete,
+    InviteCreate,
+    InviteUpdate,
+    InviteDelete,
+    WebhookCreate,
+    WebhookUpdate,
+    WebhookDelete,
+    EmojiCreate,
+    EmojiUpdate,
+    EmojiDelete,
+    MessageDelete,
+    MessageBulkDelete,
+    MessagePin,
+    MessageUnpin,
+    IntegrationCreate,
+    IntegrationUpdate,
+    IntegrationDelete,
+    StageInstanceCreate,
+    StageInstanceUpdate,
+    StageInstanceDelete,
+    StickerCreate,
+    StickerUpdate,
+    StickerDelete,
+    GuildScheduledEventCreate,
+    GuildScheduledEventUpdate,
+    GuildScheduledEventDelete,
+    ThreadCreate,
+    ThreadUpdate,
+    ThreadDelete,
+    ApplicationCommandPermissionUpdate,
+    AutoModerationRuleCreate,
+    AutoModerationRuleUpdate,
+    AutoModerationRuleDelete,
+    AutoModerationBlockMessage,
+    AutoModerationFlagToChannel,
+    AutoModerationUserCommunicationDisabled,
+    Unknown(u16),
+}
+
+impl From<u16> for AuditLogActionType {
+    fn from(v: u16) -> Self {
+        match v {
+            1 => Self::GuildUpdate,
+            10 => Self::ChannelCreate,
+            11 => Self::ChannelUpdate,
+            12 => Self::ChannelDelete,
+            13 => Self::ChannelOverwriteCreate,
+            14 => Self::ChannelOverwriteUpdate,
+            15 => Self::ChannelOverwriteDelete,
+            20 => Self::MemberKick,
+            21 => Self::MemberPrune,
+            22 => Self::MemberBanAdd,
+            23 => Self::MemberBanRemove,
+            24 => Self::MemberUpdate,
+            25 => Self::MemberRoleUpdate,
+            26 => Self::MemberMove,
+            27 => Self::MemberDisconnect,
+            28 => Self::BotAdd,
+            30 => Self::RoleCreate,
+            31 => Self::RoleUpdate,
+            32 => Self::RoleDelete,
+            40 => Self::InviteCreate,
+            41 => Self::InviteUpdate,
+            42 => Self::InviteDelete,
+            50 => Self::WebhookCreate,
+            51 => Self::WebhookUpdate,
+            52 => Self::WebhookDelete,
+            60 => Self::EmojiCreate,
+            61 => Self::EmojiUpdate,
+            62 => Self::EmojiDelete,
+            72 => Self::MessageDelete,
+            73 => Self::MessageBulkDelete,
+            74 => Self::MessagePin,
+            75 => Self::MessageUnpin,
+            80 => Self::IntegrationCreate,
+            81 => Self::IntegrationUpdate,
+            82 => Self::IntegrationDelete,
+            83 => Self::StageInstanceCreate,
+            84 => Self::StageInstanceUpdate,
+            85 => Self::StageInstanceDelete,
+            90 => Self::StickerCreate,
+            91 => Self::StickerUpdate,
+            92 => Self::StickerDelete,
+            100 => Self::GuildScheduledEventCreate,
+            101 => Self::GuildScheduledEventUpdate,
+            102 => Self::GuildScheduledEventDelete,
+            110 => Self::ThreadCreate,
+            111 => Self::ThreadUpdate,
+            112 => Self::ThreadDelete,
+            121 => Self::ApplicationCommandPermissionUpdate,
+            140 => Self::AutoModerationRuleCreate,
+            141 => Self::AutoModerationRuleUpdate,
+            142 => Self::AutoModerationRuleDelete,
+            143 => Self::AutoModerationBlockMessage,
+            144 => Self::AutoModerationFlagToChannel,
+            145 => Self::AutoModerationUserCommunicationDisabled,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<AuditLogActionType> for u16 {
+    fn from(v: AuditLogActionType) -> Self {
+        match v {
+            AuditLogActionType::GuildUpdate => 1,
+            AuditLogActionType::ChannelCreate => 10,
+            AuditLogActionType::ChannelUpdate => 11,
+            AuditLogActionType::ChannelDelete => 12,
+            AuditLogActionType::ChannelOverwriteCreate => 13,
+            AuditLogActionType::ChannelOverwriteUpdate => 14,
+            AuditLogActionType::ChannelOverwriteDelete => 15,
+            AuditLogActionType::MemberKick => 20,
+            AuditLogActionType::MemberPrune => 21,
+            AuditLogActionType::MemberBanAdd => 22,
+            AuditLogActionType::MemberBanRemove => 23,
+            AuditLogActionType::MemberUpdate => 24,
+            AuditLogActionType::MemberRoleUpdate => 25,
+            AuditLogActionType::MemberMove => 26,
+            AuditLogActionType::MemberDisconnect => 27,
+            AuditLogActionType::BotAdd => 28,
+            AuditLogActionType::RoleCreate => 30,
+            AuditLogActionType::RoleUpdate => 31,
+            AuditLogActionType::RoleDelete => 32,
+            AuditLogActionType::InviteCreate => 40,
+            AuditLogActionType::InviteUpdate => 41,
+            AuditLogActionType::InviteDelete => 42,
+            AuditLogActionType::WebhookCreate => 50,
+            AuditLogActionType::WebhookUpdate => 51,
+            AuditLogActionType::WebhookDelete => 52,
+            AuditLogActionType::EmojiCreate => 60,
+            AuditLogActionType::EmojiUpdate => 61,
+            AuditLogActionType::EmojiDelete => 62,
+            AuditLogActionType::MessageDelete => 72,
+            AuditLogActionType::MessageBulkDelete => 73,
+            AuditLogActionType::MessagePin => 74,
+            AuditLogActionType::MessageUnpin => 75,
+            AuditLogActionType::IntegrationCreate => 80,
+            AuditLogActionType::IntegrationUpdate => 81,
+            AuditLogActionType::IntegrationDelete => 82,
+            AuditLogActionType::StageInstanceCreate => 83,
+            AuditLogActionType::StageInstanceUpdate => 84,
+            AuditLogActionType::StageInstanceDelete => 85,
+            AuditLogActionType::StickerCreate => 90,
+            AuditLogActionType::StickerUpdate => 91,
+            AuditLogActionType::StickerDelete => 92,
+            AuditLogActionType::GuildScheduledEventCreate => 100,
+            AuditLogActionType::GuildScheduledEventUpdate => 101,
+            AuditLogActionType::GuildScheduledEventDelete => 102,
+            AuditLogActionType::ThreadCreate => 110,
+            AuditLogActionType::ThreadUpdate => 111,
+            AuditLogActionType::ThreadDelete => 112,
+            AuditLogActionType::ApplicationCommandPermissionUpdate => 121,
+            AuditLogActionType::AutoModerationRuleCreate => 140,
+            AuditLogActionType::AutoModerationRuleUpdate => 141,
+            AuditLogActionType::AutoModerationRuleDelete => 142,
+            AuditLogActionType::AutoModerationBlockMessage => 143,
+            AuditLogActionType::AutoModerationFlagToChannel => 144,
+            AuditLogActionType::AutoModerationUserCommunicationDisabled => 145,
+            AuditLogActionType::Unknown(other) => other,
+        }
+    }
+}
+
+impl From<twilight_model::guild::audit_log::AuditLogEventType> for AuditLogActionType {
+    fn from(v: twilight_model::guild::audit_log::AuditLogEventType) -> Self {
+        u16::from(v).into()
+    }
+}
+
+impl From<AuditLogActionType> for twilight_model::guild::audit_log::AuditLogEventType {
+    fn from(v: AuditLogActionType) -> Self {
+        u16::from(v).into()
+    }
+}
@@ -0,0 +1,309 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AutoModerationRule.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct AutoModerationRule {
+    pub id: String,
+    pub name: String,
+    pub creator_id: String,
+    pub enabled: bool,
+    pub event_type: AutoModerationEventType,
+    pub trigger_type: AutoModerationTriggerType,
+    pub trigger_metadata: AutoModerationTriggerMetadata,
+    pub actions: Vec<AutoModerationAction>,
+    pub exempt_roles: Vec<String>,
+    pub exempt_channels: Vec<String>,
+}
+
+impl From<twilight_model::guild::auto_moderation::AutoModerationRule> for AutoModerationRule {
+    fn from(v: twilight_model::guild::auto_moderation::AutoModerationRule) -> Self {
+        Self {
+            id: v.id.to_string(),
+            name: v.name,
+            creator_id: v.creator_id.to_string(),
+            enabled: v.enabled,
+            event_type: v.event_type.into(),
+            trigger_type: v.trigger_type.into(),
+            trigger_metadata: v.trigger_metadata.into(),
+            actions: v.actions.into_iter().map(Into::into).collect(),
+            exempt_roles: v.exempt_roles.iter().map(ToString::to_string).collect(),
+            exempt_channels: v.exempt_channels.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AutoModerationAction.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct AutoModerationAction {
+    pub kind: AutoModerationActionType,
+    #[ts(optional)]
+    #[serde(default)]
+    pub metadata: Option<AutoModerationActionMetadata>,
+}
+
+impl From<twilight_model::guild::auto_moderation::AutoModerationAction> for AutoModerationAction {
+    fn from(v: twilight_model::guild::auto_moderation::AutoModerationAction) -> Self {
+        Self {
+            kind: v.kind.into(),
+            metadata: v.metadata.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AutoModerationActionMetadata.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct AutoModerationActionMetadata {
+    // channel to send alerts to, for SendAlertMessage actions
+    #[ts(optional)]
+    #[serde(default)]
+    pub channel_id: Option<String>,
+
+    // message shown to the user when their message is blocked, for BlockMessage actions
+    #[ts(optional)]
+    #[serde(default)]
+    pub custom_message: Option<String>,
+
+    // timeout duration, for Timeout actions
+    #[ts(optional)]
+    #[serde(default)]
+    pub duration_seconds: Option<u32>,
+}
+
+impl From<twilight_model::guild::auto_moderation::AutoModerationActionMetadata>
+    for AutoModerationActionMetadata
+{
+    fn from(v: twilight_model::guild::auto_moderation::AutoModerationActionMetadata) -> Self {
+        Self {
+            channel_id: v.channel_id.as_ref().map(ToString::to_string),
+            custom_message: v.custom_message,
+            duration_seconds: v.duration_seconds,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AutoModerationTriggerMetadata.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct AutoModerationTriggerMetadata {
+    // substrings to match, for Keyword and MemberProfile triggers
+    #[ts(optional)]
+    #[serde(default)]
+    pub keyword_filter: Option<Vec<String>>,
+
+    // rust flavored regex patterns to match, for Keyword and MemberProfile triggers
+    #[ts(optional)]
+    #[serde(default)]
+    pub regex_patterns: Option<Vec<String>>,
+
+    // for KeywordPreset triggers
+    #[ts(optional)]
+    #[serde(default)]
+    pub presets: Option<Vec<AutoModerationKeywordPresetType>>,
+
+    // substrings that should not trigger the rule
+    #[ts(optional)]
+    #[serde(default)]
+    pub allow_list: Option<Vec<String>>,
+
+    // for MentionSpam triggers
+    #[ts(optional)]
+    #[serde(default)]
+    pub mention_total_limit: Option<u8>,
+
+    // for MentionSpam triggers
+    #[ts(optional)]
+    #[serde(default)]
+    pub mention_raid_protection_enabled: Option<bool>,
+}
+
+impl From<twilight_model::guild::auto_moderation::AutoModerationTriggerMetadata>
+    for AutoModerationTriggerMetadata
+{
+    fn from(v: twilight_model::guild::auto_moderation::AutoModerationTriggerMetadata) -> Self {
+        Self {
+            keyword_filter: v.keyword_filter,
+            regex_patterns: v.regex_patterns,
+            presets: v
+                .presets
+                .map(|presets| presets.into_iter().map(Into::into).collect()),
+            allow_list: v.allow_list,
+            mention_total_limit: v.mention_total_limit,
+            mention_raid_protection_enabled: v.mention_raid_protection_enabled,
+        }
+    }
+}
+
+impl From<AutoModerationTriggerMetadata>
+    for twilight_model::guild::auto_moderation::AutoModerationTriggerMetadata
+{
+    fn from(v: AutoModerationTriggerMetadata) -> Self {
+        Self {
+            allow_list: v.allow_list,
+            keyword_filter: v.keyword_filter,
+            presets: v
+                .presets
+                .map(|presets| presets.into_iter().map(Into::into).collect()),
+            mention_raid_protection_enabled: v.mention_raid_protection_enabled,
+            mention_total_limit: v.mention_total_limit,
+            regex_patterns: v.regex_patterns,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AutoModerationEventType.ts")]
+pub enum AutoModerationEventType {
+    MessageSend,
+    MemberUpdate,
+    Unknown(u8),
+}
+
+impl From<twilight_model::guild::auto_moderation::AutoModerationEventType>
+    for AutoModerationEventType
+{
+    fn from(v: twilight_model::guild::auto_moderation::AutoModerationEventType) -> Self {
+        match u8::from(v) {
+            1 => Self::MessageSend,
+            2 => Self::MemberUpdate,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<AutoModerationEventType>
+    for twilight_model::guild::auto_moderation::AutoModerationEventType
+{
+    fn from(v: AutoModerationEventType) -> Self {
+        Self::from(match v {
+            AutoModerationEventType::MessageSend => 1,
+            AutoModerationEventType::MemberUpdate => 2,
+            AutoModerationEventType::Unknown(other) => other,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AutoModerationTriggerType.ts")]
+pub enum AutoModerationTriggerType {
+    Keyword,
+    Spam,
+    KeywordPreset,
+    MentionSpam,
+    MemberProfile,
+    Unknown(u8),
+}
+
+impl From<twilight_model::guild::auto_moderation::AutoModerationTriggerType>
+    for AutoModerationTriggerType
+{
+    fn from(v: twilight_model::guild::auto_moderation::AutoModerationTriggerType) -> Self {
+        match u8::from(v) {
+            1 => Self::Keyword,
+            3 => Self::Spam,
+            4 => Self::KeywordPreset,
+            5 => Self::MentionSpam,
+            6 => Self::MemberProfile,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<AutoModerationTriggerType>
+    for twilight_model::guild::auto_moderation::AutoModerationTriggerType
+{
+    fn from(v: AutoModerationTriggerType) -> Self {
+        Self::from(match v {
+            AutoModerationTriggerType::Keyword => 1,
+            AutoModerationTriggerType::Spam => 3,
+            AutoModerationTriggerType::KeywordPreset => 4,
+            AutoModerationTriggerType::MentionSpam => 5,
+            AutoModerationTriggerType::MemberProfile => 6,
+            AutoModerationTriggerType::Unknown(other) => other,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AutoModerationKeywordPresetType.ts")]
+pub enum AutoModerationKeywordPresetType {
+    Profanity,
+    SexualContent,
+    Slurs,
+    Unknown(u8),
+}
+
+impl From<twilight_model::guild::auto_moderation::AutoModerationKeywordPresetType>
+    for AutoModerationKeywordPresetType
+{
+    fn from(v: twilight_model::guild::auto_moderation::AutoModerationKeywordPresetType) -> Self {
+        match u8::from(v) {
+            1 => Self::Profanity,
+            2 => Self::SexualContent,
+            3 => Self::Slurs,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<AutoModerationKeywordPresetType>
+    for twilight_model::guild::auto_moderation::AutoModerationKeywordPresetType
+{
+    fn from(v: AutoModerationKeywordPresetType) -> Self {
+        Self::from(match v {
+            AutoModerationKeywordPresetType::Profanity => 1,
+            AutoModerationKeywordPresetType::SexualContent => 2,
+            AutoModerationKeywordPresetType::Slurs => 3,
+            AutoModerationKeywordPresetType::Unknown(other) => other,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/AutoModerationActionType.ts")]
+pub enum AutoModerationActionType {
+    BlockMessage,
+    SendAlertMessage,
+    Timeout,
+    BlockMemberInteraction,
+    Unknown(u8),
+}
+
+impl From<twilight_model::guild::auto_moderation::AutoModerationActionType>
+    for AutoModerationActionType
+{
+    fn from(v: twilight_model::guild::auto_moderation::AutoModerationActionType) -> Self {
+        match u8::from(v) {
+            1 => Self::BlockMessage,
+            2 => Self::SendAlertMessage,
+            3 => Self::Timeout,
+            4 => Self::BlockMemberInteraction,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<AutoModerationActionType>
+    for twilight_model::guild::auto_moderation::AutoModerationActionType
+{
+    fn from(v: AutoModerationActionType) -> Self {
+        Self::from(match v {
+            AutoModerationActionType::BlockMessage => 1,
+            AutoModerationActionType::SendAlertMessage => 2,
+            AutoModerationActionType::Timeout => 3,
+            AutoModerationActionType::BlockMemberInteraction => 4,
+            AutoModerationActionType::Unknown(other) => other,
+        })
+    }
+}
@@ -31,6 +31,106 @@ impl From<VideoQualityMode> for twilight_model::channel::VideoQualityMode {
     }
 }
 
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/ForumTag.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct ForumTag {
+    pub id: String,
+    pub name: String,
+    pub moderated: bool,
+    pub emoji_id: Option<String>,
+    pub emoji_name: Option<String>,
+}
+
+impl From<twilight_model::channel::forum::ForumTag> for ForumTag {
+    fn from(v: twilight_model::channel::forum::ForumTag) -> Self {
+        Self {
+            id: v.id.to_string(),
+            name: v.name,
+            moderated: v.moderated,
+            emoji_id: v.emoji_id.as_ref().map(ToString::to_string),
+            emoji_name: v.emoji_name,
+        }
+    }
+}
+
+/// The emoji shown in the add reaction button on threads in a forum channel
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/ForumDefaultReaction.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct ForumDefaultReaction {
+    #[ts(optional)]
+    #[serde(default)]
+    pub emoji_id: Option<String>,
+    #[ts(optional)]
+    #[serde(default)]
+    pub emoji_name: Option<String>,
+}
+
+impl From<twilight_model::channel::forum::DefaultReaction> for ForumDefaultReaction {
+    fn from(v: twilight_model::channel::forum::DefaultReaction) -> Self {
+        Self {
+            emoji_id: v.emoji_id.as_ref().map(ToString::to_string),
+            emoji_name: v.emoji_name,
+        }
+    }
+}
+
+impl TryFrom<ForumDefaultReaction> for twilight_model::channel::forum::DefaultReaction {
+    type Error = anyhow::Error;
+
+    fn try_from(v: ForumDefaultReaction) -> Result<Self, Self::Error> {
+        let emoji_id = match v.emoji_id {
+            Some(id) => Some(
+                id.parse()
+                    .ok()
+                    .and_then(Id::new_checked)
+                    .ok_or_else(|| anyhow::anyhow!("invalid emoji id: {id}"))?,
+            ),
+            None => None,
+        };
+
+        if emoji_id.is_none() && v.emoji_name.is_none() {
+            return Err(anyhow::anyhow!(
+                "default reaction needs either an emoji id or an emoji name"
+            ));
+        }
+
+        Ok(Self {
+            emoji_id,
+            emoji_name: v.emoji_name,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/ForumSortOrder.ts")]
+pub enum ForumSortOrder {
+    LatestActivity,
+    CreationDate,
+}
+
+impl From<twilight_model::channel::forum::ForumSortOrder> for ForumSortOrder {
+    fn from(v: twilight_model::channel::forum::ForumSortOrder) -> Self {
+        match v {
+            twilight_model::channel::forum::ForumSortOrder::CreationDate => Self::CreationDate,
+            _ => Self::LatestActivity,
+        }
+    }
+}
+
+impl From<ForumSortOrder> for twilight_model::channel::forum::ForumSortOrder {
+    fn from(v: ForumSortOrder) -> Self {
+        match v {
+            ForumSortOrder::LatestActivity => Self::LatestActivity,
+            ForumSortOrder::CreationDate => Self::CreationDate,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, TS)]
 #[ts(export)]
 #[ts(export_to = "bindings/discord/ThreadMetadata.ts")]
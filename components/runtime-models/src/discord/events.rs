@@ -1,17 +1,25 @@
 use serde::Serialize;
 use ts_rs::TS;
 
-use crate::discord::message::ReactionType;
+use crate::{
+    discord::{
+        auto_moderation::{AutoModerationAction, AutoModerationTriggerType},
+        message::ReactionType,
+    },
+    internal::{messages::Message, user::User},
+};
 
 use super::channel::ChannelType;
 
 #[derive(Clone, Debug, Serialize, TS)]
-#[ts(export)]
-#[ts(export_to = "bindings/discord/EventMessageDelete.ts")]
+#[ts(export, rename = "IEventMessageDelete")]
+#[ts(export_to = "bindings/discord/IEventMessageDelete.ts")]
 #[serde(rename_all = "camelCase")]
 pub struct EventMessageDelete {
     pub channel_id: String,
     pub id: String,
+    // the message before it was deleted, none if it was not in the broker's message cache
+    pub previous: Option<Message>,
 }
 
 impl From<twilight_model::gateway::payload::incoming::MessageDelete> for EventMessageDelete {
@@ -19,6 +27,49 @@ impl From<twilight_model::gateway::payload::incoming::MessageDelete> for EventMe
         Self {
             channel_id: v.channel_id.to_string(),
             id: v.id.to_string(),
+            previous: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/EventMessageDeleteBulk.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventMessageDeleteBulk {
+    pub channel_id: String,
+    pub ids: Vec<String>,
+}
+
+impl From<twilight_model::gateway::payload::incoming::MessageDeleteBulk>
+    for EventMessageDeleteBulk
+{
+    fn from(v: twilight_model::gateway::payload::incoming::MessageDeleteBulk) -> Self {
+        Self {
+            channel_id: v.channel_id.to_string(),
+            ids: v.ids.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IEventMessagePollVote")]
+#[ts(export_to = "bindings/discord/IEventMessagePollVote.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventMessagePollVote {
+    pub user_id: String,
+    pub channel_id: String,
+    pub message_id: String,
+    pub answer_id: u8,
+}
+
+impl From<dbrokerapi::models::MessagePollVote> for EventMessagePollVote {
+    fn from(v: dbrokerapi::models::MessagePollVote) -> Self {
+        Self {
+            user_id: v.user_id.to_string(),
+            channel_id: v.channel_id.to_string(),
+            message_id: v.message_id.to_string(),
+            answer_id: v.answer_id,
         }
     }
 }
@@ -113,3 +164,95 @@ impl From<twilight_model::gateway::payload::incoming::ThreadDelete> for EventThr
         }
     }
 }
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/EventScheduledEventUser.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventScheduledEventUser {
+    pub event_id: String,
+    pub user_id: String,
+}
+
+impl From<twilight_model::gateway::payload::incoming::GuildScheduledEventUserAdd>
+    for EventScheduledEventUser
+{
+    fn from(v: twilight_model::gateway::payload::incoming::GuildScheduledEventUserAdd) -> Self {
+        Self {
+            event_id: v.guild_scheduled_event_id.to_string(),
+            user_id: v.user_id.to_string(),
+        }
+    }
+}
+
+impl From<twilight_model::gateway::payload::incoming::GuildScheduledEventUserRemove>
+    for EventScheduledEventUser
+{
+    fn from(v: twilight_model::gateway::payload::incoming::GuildScheduledEventUserRemove) -> Self {
+        Self {
+            event_id: v.guild_scheduled_event_id.to_string(),
+            user_id: v.user_id.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/EventAutoModerationActionExecution.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventAutoModerationActionExecution {
+    pub action: AutoModerationAction,
+    pub rule_id: String,
+    pub rule_trigger_type: AutoModerationTriggerType,
+    pub user_id: String,
+    pub channel_id: Option<String>,
+    pub message_id: Option<String>,
+    pub alert_system_message_id: Option<String>,
+    // requires the message content intent, empty otherwise
+    pub content: String,
+    pub matched_keyword: Option<String>,
+    pub matched_content: Option<String>,
+}
+
+impl From<twilight_model::gateway::payload::incoming::AutoModerationActionExecution>
+    for EventAutoModerationActionExecution
+{
+    fn from(v: twilight_model::gateway::payload::incoming::AutoModerationActionExecution) -> Self {
+        Self {
+            action: v.action.into(),
+            rule_id: v.rule_id.to_string(),
+            rule_trigger_type: v.rule_trigger_type.into(),
+            user_id: v.user_id.to_string(),
+            channel_id: v.channel_id.as_ref().map(ToString::to_string),
+            message_id: v.message_id.as_ref().map(ToString::to_string),
+            alert_system_message_id: v.alert_system_message_id.as_ref().map(ToString::to_string),
+            content: v.content,
+            matched_keyword: v.matched_keyword,
+            matched_content: v.matched_content,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IEventBan")]
+#[ts(export_to = "bindings/discord/IEventBan.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventBan {
+    pub user: User,
+}
+
+impl From<twilight_model::gateway::payload::incoming::BanAdd> for EventBan {
+    fn from(v: twilight_model::gateway::payload::incoming::BanAdd) -> Self {
+        Self {
+            user: v.user.into(),
+        }
+    }
+}
+
+impl From<twilight_model::gateway::payload::incoming::BanRemove> for EventBan {
+    fn from(v: twilight_model::gateway::payload::incoming::BanRemove) -> Self {
+        Self {
+            user: v.user.into(),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use twilight_cache_inmemory::model::CachedGuild;
 use twilight_model::guild::{
     DefaultMessageNotificationLevel as TwilightDefaultMessageNotificationLevel,
     ExplicitContentFilter as TwilightExplicitContentFilter, MfaLevel as TwilightMfaLevel,
-    NSFWLevel as TwilightNSFWLevel, PremiumTier as TwilightPremiumTier,
+    NSFWLevel as TwilightNSFWLevel, PartialGuild, PremiumTier as TwilightPremiumTier,
     VerificationLevel as TwilightVerificationLevel,
 };
 
@@ -140,6 +140,59 @@ impl From<BrokerGuild> for Guild {
     }
 }
 
+impl Guild {
+    /// Creates a guild from the partial guild sent in GUILD_UPDATE, which is missing some of the
+    /// fields, those are taken from the previous state if available
+    pub fn from_partial(v: PartialGuild, old: Option<&BrokerGuild>) -> Self {
+        Self {
+            afk_channel_id: v.afk_channel_id.as_ref().map(ToString::to_string),
+            afk_timeout: NotBigU64(v.afk_timeout.get() as u64),
+            application_id: v.application_id.as_ref().map(ToString::to_string),
+            banner: v.banner.as_ref().map(ToString::to_string),
+            default_message_notifications: v.default_message_notifications.into(),
+            description: v.description,
+            discovery_splash: v.discovery_splash.as_ref().map(ToString::to_string),
+            explicit_content_filter: v.explicit_content_filter.into(),
+            features: v
+                .features
+                .into_iter()
+                .map(|v| Cow::<str>::from(v).to_string())
+                .collect(),
+            icon: v.icon.as_ref().map(ToString::to_string),
+            id: v.id.to_string(),
+            joined_at: old
+                .and_then(|o| o.joined_at)
+                .map(|ts| NotBigU64(ts.as_micros() as u64 / 1000))
+                .unwrap_or_default(),
+            max_members: v.max_members.map(NotBigU64),
+            max_presences: v.max_presences.map(NotBigU64),
+            member_count: v
+                .member_count
+                .or_else(|| old.and_then(|o| o.member_count))
+                .map(NotBigU64)
+                .unwrap_or_default(),
+            mfa_level: v.mfa_level.into(),
+            name: v.name,
+            nsfw_level: v.nsfw_level.into(),
+            owner_id: v.owner_id.to_string(),
+            preferred_locale: v.preferred_locale,
+            premium_subscription_count: v
+                .premium_subscription_count
+                .map(NotBigU64)
+                .unwrap_or_default(),
+            premium_tier: v.premium_tier.into(),
+            rules_channel_id: v.rules_channel_id.as_ref().map(ToString::to_string),
+            splash: v.splash.as_ref().map(ToString::to_string),
+            system_channel_id: v.system_channel_id.as_ref().map(ToString::to_string),
+            vanity_url_code: v.vanity_url_code,
+            verification_level: v.verification_level.into(),
+            widget_channel_id: v.widget_channel_id.as_ref().map(ToString::to_string),
+            widget_enabled: v.widget_enabled,
+            system_channel_flags: v.system_channel_flags.into(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
 #[ts(export)]
 #[ts(export_to = "bindings/discord/DefaultMessageNotificationLevel.ts")]
@@ -158,6 +211,15 @@ impl From<TwilightDefaultMessageNotificationLevel> for DefaultMessageNotificatio
     }
 }
 
+impl From<DefaultMessageNotificationLevel> for TwilightDefaultMessageNotificationLevel {
+    fn from(v: DefaultMessageNotificationLevel) -> Self {
+        match v {
+            DefaultMessageNotificationLevel::All => Self::All,
+            DefaultMessageNotificationLevel::Mentions => Self::Mentions,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
 #[ts(export)]
 #[ts(export_to = "bindings/discord/ExplicitContentFilter.ts")]
@@ -264,6 +326,18 @@ impl From<TwilightVerificationLevel> for VerificationLevel {
     }
 }
 
+impl From<VerificationLevel> for TwilightVerificationLevel {
+    fn from(v: VerificationLevel) -> Self {
+        match v {
+            VerificationLevel::None => Self::None,
+            VerificationLevel::Low => Self::Low,
+            VerificationLevel::Medium => Self::Medium,
+            VerificationLevel::High => Self::High,
+            VerificationLevel::VeryHigh => Self::VeryHigh,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Serialize, TS)]
 #[ts(export)]
 #[ts(export_to = "bindings/discord/SystemChannelFlags.ts")]
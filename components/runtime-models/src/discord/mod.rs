@@ -1,3 +1,5 @@
+pub mod audit_log;
+pub mod auto_moderation;
 pub mod channel;
 pub mod component;
 pub mod embed;
@@ -6,5 +8,9 @@ pub mod guild;
 pub mod invite;
 pub mod member;
 pub mod message;
+pub mod poll;
 pub mod role;
+pub mod scheduled_event;
+pub mod sticker;
 pub mod util;
+pub mod webhook;
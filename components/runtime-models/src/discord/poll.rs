@@ -0,0 +1,149 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::{
+    discord::message::{ReactionType, SendEmoji},
+    util::NotBigU64,
+};
+
+// the raw discord poll models live in dbrokerapi as the broker also parses them out of gateway events
+pub use dbrokerapi::models::{
+    DiscordMessagePoll, DiscordPoll, DiscordPollAnswer, DiscordPollAnswerCount, DiscordPollEmoji,
+    DiscordPollMedia, DiscordPollResults,
+};
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IPoll")]
+#[ts(export_to = "bindings/discord/IPoll.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct Poll {
+    pub question: PollMedia,
+    pub answers: Vec<PollAnswer>,
+    pub expiry: Option<NotBigU64>,
+    pub allow_multiselect: bool,
+    pub results: Option<PollResults>,
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/PollMedia.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct PollMedia {
+    pub text: Option<String>,
+    pub emoji: Option<ReactionType>,
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/PollAnswer.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct PollAnswer {
+    pub answer_id: u8,
+    pub media: PollMedia,
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/PollResults.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct PollResults {
+    /// Whether the votes have been precisely counted, if false the counts are approximate
+    pub is_finalized: bool,
+    pub answer_counts: Vec<PollAnswerCount>,
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/PollAnswerCount.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct PollAnswerCount {
+    pub answer_id: u8,
+    pub count: u32,
+    pub me_voted: bool,
+}
+
+impl From<DiscordPoll> for Poll {
+    fn from(v: DiscordPoll) -> Self {
+        Self {
+            question: v.question.into(),
+            answers: v.answers.into_iter().map(Into::into).collect(),
+            expiry: v.expiry.map(|ts| NotBigU64(ts.as_micros() as u64 / 1000)),
+            allow_multiselect: v.allow_multiselect,
+            results: v.results.map(Into::into),
+        }
+    }
+}
+
+impl From<DiscordPollMedia> for PollMedia {
+    fn from(v: DiscordPollMedia) -> Self {
+        Self {
+            text: v.text,
+            emoji: v.emoji.and_then(|emoji| match (emoji.id, emoji.name) {
+                (Some(id), name) => Some(ReactionType::Custom {
+                    animated: emoji.animated,
+                    id,
+                    name,
+                }),
+                (None, Some(name)) => Some(ReactionType::Unicode { unicode: name }),
+                (None, None) => None,
+            }),
+        }
+    }
+}
+
+impl From<DiscordPollAnswer> for PollAnswer {
+    fn from(v: DiscordPollAnswer) -> Self {
+        Self {
+            answer_id: v.answer_id,
+            media: v.poll_media.into(),
+        }
+    }
+}
+
+impl From<DiscordPollResults> for PollResults {
+    fn from(v: DiscordPollResults) -> Self {
+        Self {
+            is_finalized: v.is_finalized,
+            answer_counts: v
+                .answer_counts
+                .into_iter()
+                .map(|c| PollAnswerCount {
+                    answer_id: c.id,
+                    count: c.count,
+                    me_voted: c.me_voted,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<SendEmoji> for DiscordPollEmoji {
+    fn from(v: SendEmoji) -> Self {
+        match v {
+            SendEmoji::Custom { id, name } => Self {
+                id: Some(id),
+                name,
+                animated: false,
+            },
+            SendEmoji::Unicode { unicode } => Self {
+                id: None,
+                name: Some(unicode),
+                animated: false,
+            },
+        }
+    }
+}
+
+/// The poll field of a create message request
+#[derive(Clone, Debug, Serialize)]
+pub struct DiscordCreatePoll {
+    pub question: DiscordPollMedia,
+    pub answers: Vec<DiscordCreatePollAnswer>,
+    pub duration: u32,
+    pub allow_multiselect: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DiscordCreatePollAnswer {
+    pub poll_media: DiscordPollMedia,
+}
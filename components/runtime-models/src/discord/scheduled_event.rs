@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/ScheduledEventEntityType.ts")]
+pub enum ScheduledEventEntityType {
+    StageInstance,
+    Voice,
+    External,
+    Unknown(u8),
+}
+
+impl From<twilight_model::guild::scheduled_event::EntityType> for ScheduledEventEntityType {
+    fn from(v: twilight_model::guild::scheduled_event::EntityType) -> Self {
+        match u8::from(v) {
+            1 => Self::StageInstance,
+            2 => Self::Voice,
+            3 => Self::External,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<ScheduledEventEntityType> for twilight_model::guild::scheduled_event::EntityType {
+    fn from(v: ScheduledEventEntityType) -> Self {
+        match v {
+            ScheduledEventEntityType::StageInstance => Self::StageInstance,
+            ScheduledEventEntityType::Voice => Self::Voice,
+            ScheduledEventEntityType::External => Self::External,
+            ScheduledEventEntityType::Unknown(other) => Self::from(other),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/ScheduledEventStatus.ts")]
+pub enum ScheduledEventStatus {
+    Scheduled,
+    Active,
+    Completed,
+    Cancelled,
+    Unknown(u8),
+}
+
+impl From<twilight_model::guild::scheduled_event::Status> for ScheduledEventStatus {
+    fn from(v: twilight_model::guild::scheduled_event::Status) -> Self {
+        match u8::from(v) {
+            1 => Self::Scheduled,
+            2 => Self::Active,
+            3 => Self::Completed,
+            4 => Self::Cancelled,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<ScheduledEventStatus> for twilight_model::guild::scheduled_event::Status {
+    fn from(v: ScheduledEventStatus) -> Self {
+        match v {
+            ScheduledEventStatus::Scheduled => Self::Scheduled,
+            ScheduledEventStatus::Active => Self::Active,
+            ScheduledEventStatus::Completed => Self::Completed,
+            ScheduledEventStatus::Cancelled => Self::Cancelled,
+            ScheduledEventStatus::Unknown(other) => Self::from(other),
+        }
+    }
+}
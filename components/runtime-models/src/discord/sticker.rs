@@ -0,0 +1,44 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+#[derive(Clone, Copy, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/StickerFormatType.ts")]
+pub enum StickerFormatType {
+    Png,
+    Apng,
+    Lottie,
+    Gif,
+    Unknown(u8),
+}
+
+impl From<twilight_model::channel::message::sticker::StickerFormatType> for StickerFormatType {
+    fn from(v: twilight_model::channel::message::sticker::StickerFormatType) -> Self {
+        match u8::from(v) {
+            1 => Self::Png,
+            2 => Self::Apng,
+            3 => Self::Lottie,
+            4 => Self::Gif,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/StickerType.ts")]
+pub enum StickerType {
+    Standard,
+    Guild,
+    Unknown(u8),
+}
+
+impl From<twilight_model::channel::message::sticker::StickerType> for StickerType {
+    fn from(v: twilight_model::channel::message::sticker::StickerType) -> Self {
+        match u8::from(v) {
+            1 => Self::Standard,
+            2 => Self::Guild,
+            other => Self::Unknown(other),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+#[derive(Clone, Copy, Debug, Serialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/discord/WebhookType.ts")]
+pub enum WebhookType {
+    Incoming,
+    ChannelFollower,
+    Application,
+    Unknown(u8),
+}
+
+impl From<twilight_model::channel::WebhookType> for WebhookType {
+    fn from(v: twilight_model::channel::WebhookType) -> Self {
+        match u8::from(v) {
+            1 => Self::Incoming,
+            2 => Self::ChannelFollower,
+            3 => Self::Application,
+            other => Self::Unknown(other),
+        }
+    }
+}
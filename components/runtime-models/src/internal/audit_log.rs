@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use super::user::User;
+use crate::discord::audit_log::{AuditLogActionType, AuditLogEntry};
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IAuditLog")]
+#[ts(export_to = "bindings/internal/IAuditLog.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLog {
+    pub entries: Vec<AuditLogEntry>,
+    // users referenced in the entries
+    pub users: Vec<User>,
+}
+
+impl From<twilight_model::guild::audit_log::AuditLog> for AuditLog {
+    fn from(v: twilight_model::guild::audit_log::AuditLog) -> Self {
+        Self {
+            entries: v.entries.into_iter().map(Into::into).collect(),
+            users: v.users.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "IGetAuditLog")]
+#[ts(export_to = "bindings/internal/IGetAuditLog.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct GetAuditLogFields {
+    #[ts(optional)]
+    #[serde(default)]
+    pub user_id: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub action_type: Option<AuditLogActionType>,
+
+    // entry id to return entries before
+    #[ts(optional)]
+    #[serde(default)]
+    pub before: Option<String>,
+
+    // entry id to return entries after
+    #[ts(optional)]
+    #[serde(default)]
+    pub after: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub limit: Option<u16>,
+}
@@ -0,0 +1,79 @@
+use serde::Deserialize;
+use ts_rs::TS;
+
+use crate::discord::auto_moderation::{
+    AutoModerationAction, AutoModerationEventType, AutoModerationTriggerMetadata,
+    AutoModerationTriggerType,
+};
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "ICreateAutoModerationRule")]
+#[ts(export_to = "bindings/internal/ICreateAutoModerationRule.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAutoModerationRuleFields {
+    pub name: String,
+    pub event_type: AutoModerationEventType,
+    pub trigger_type: AutoModerationTriggerType,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub trigger_metadata: Option<AutoModerationTriggerMetadata>,
+
+    pub actions: Vec<AutoModerationAction>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub enabled: Option<bool>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub exempt_roles: Option<Vec<String>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub exempt_channels: Option<Vec<String>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "IEditAutoModerationRule")]
+#[ts(export_to = "bindings/internal/IEditAutoModerationRule.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EditAutoModerationRuleFields {
+    pub rule_id: String,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub event_type: Option<AutoModerationEventType>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub trigger_metadata: Option<AutoModerationTriggerMetadata>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub actions: Option<Vec<AutoModerationAction>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub enabled: Option<bool>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub exempt_roles: Option<Vec<String>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub exempt_channels: Option<Vec<String>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
@@ -1,10 +1,15 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
-use twilight_model::id::Id;
-use twilight_validate::channel::ChannelValidationError;
+use twilight_model::id::{
+    marker::{ChannelMarker, EmojiMarker, TagMarker},
+    Id,
+};
 
 use crate::{
-    discord::channel::{ChannelType, PermissionOverwrite, ThreadMetadata, VideoQualityMode},
+    discord::channel::{
+        ChannelType, ForumDefaultReaction, ForumSortOrder, ForumTag, PermissionOverwrite,
+        ThreadMetadata, VideoQualityMode,
+    },
     internal::member::Member,
     util::NotBigU64,
 };
@@ -121,6 +126,9 @@ impl From<twilight_model::channel::Channel> for VoiceChannel {
 #[ts(export_to = "bindings/internal/TextChannel.ts")]
 #[serde(rename_all = "camelCase")]
 pub struct TextChannel {
+    pub available_tags: Vec<ForumTag>,
+    pub default_reaction_emoji: Option<ForumDefaultReaction>,
+    pub default_sort_order: Option<ForumSortOrder>,
     pub id: String,
     #[ts(type = "'Text'|'News'|'Forum'|'GuildDirectory'")]
     pub kind: ChannelType,
@@ -137,6 +145,14 @@ pub struct TextChannel {
 impl From<twilight_model::channel::Channel> for TextChannel {
     fn from(v: twilight_model::channel::Channel) -> Self {
         Self {
+            available_tags: v
+                .available_tags
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            default_reaction_emoji: v.default_reaction_emoji.map(Into::into),
+            default_sort_order: v.default_sort_order.map(Into::into),
             id: v.id.to_string(),
             kind: v.kind.into(),
             last_pin_timestamp: v
@@ -163,6 +179,7 @@ impl From<twilight_model::channel::Channel> for TextChannel {
 #[ts(export_to = "bindings/internal/PublicThread.ts")]
 #[serde(rename_all = "camelCase")]
 pub struct PublicThread {
+    pub applied_tags: Vec<String>,
     pub default_auto_archive_duration_minutes: Option<u32>,
     pub id: String,
     #[ts(type = "'PublicThread'")]
@@ -180,6 +197,12 @@ pub struct PublicThread {
 impl From<twilight_model::channel::Channel> for PublicThread {
     fn from(v: twilight_model::channel::Channel) -> Self {
         Self {
+            applied_tags: v
+                .applied_tags
+                .unwrap_or_default()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
             default_auto_archive_duration_minutes: v
                 .default_auto_archive_duration
                 .map(|v| v.number() as u32),
@@ -421,72 +444,93 @@ pub struct EditChannel {
     #[ts(optional)]
     #[serde(default)]
     video_quality_mode: Option<VideoQualityMode>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    available_tags: Option<Vec<ForumTagFields>>,
+
+    #[ts(optional)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_undefined_null_optional_field"
+    )]
+    default_reaction_emoji: Option<Option<ForumDefaultReaction>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    default_sort_order: Option<ForumSortOrder>,
 }
 
 impl EditChannel {
-    pub fn apply<'a, 'b, 'c>(
-        &'a self,
-        perms_buf: &'b mut Vec<twilight_model::channel::permission_overwrite::PermissionOverwrite>,
-        mut req: twilight_http::request::channel::UpdateChannel<'c>,
-    ) -> Result<twilight_http::request::channel::UpdateChannel<'c>, ChannelValidationError>
-    where
-        'a: 'c,
-        'b: 'c,
-    {
-        if let Some(bitrate) = &self.bitrate {
-            req = req.bitrate(*bitrate)?;
+    /// Builds the body of the update channel request
+    ///
+    /// `current_tags` are the tags currently on the channel, tags referencing an id
+    /// not in there are rejected
+    pub fn request_body(
+        &self,
+        current_tags: &[twilight_model::channel::forum::ForumTag],
+    ) -> Result<ChannelRequestBody, anyhow::Error> {
+        let mut body = ChannelRequestBody::default();
+
+        if let Some(bitrate) = self.bitrate {
+            twilight_validate::channel::bitrate(bitrate)?;
+            body.bitrate = Some(bitrate);
         }
 
         if let Some(name) = &self.name {
-            req = req.name(name)?;
+            twilight_validate::channel::name(name)?;
+            body.name = Some(name.clone());
         }
 
-        if let Some(nsfw) = &self.nsfw {
-            req = req.nsfw(*nsfw);
-        }
+        body.nsfw = self.nsfw;
 
         if let Some(parent_id) = &self.parent_id {
             // TODO: Should we error on invalid ID's?
-            let parent_id = parent_id
-                .as_ref()
-                .and_then(|s| Id::new_checked(s.parse().ok()?));
-
-            req = req.parent_id(parent_id);
-        }
-
-        if let Some(permission_overwrites) = &self.permission_overwrites {
-            // TODO: should we error on bad overwrites instead of throwing them away?
-            perms_buf.extend(
-                permission_overwrites
-                    .clone()
-                    .into_iter()
-                    .filter_map(|v| v.try_into().ok()),
+            body.parent_id = Some(
+                parent_id
+                    .as_ref()
+                    .and_then(|s| Id::new_checked(s.parse().ok()?)),
             );
-
-            req = req.permission_overwrites(perms_buf);
         }
 
-        if let Some(position) = &self.position {
-            req = req.position(position.0);
-        }
+        body.permission_overwrites = self
+            .permission_overwrites
+            .as_ref()
+            .map(|v| convert_permission_overwrites(v));
+        body.position = self.position.map(|v| v.0);
 
-        if let Some(rate_limit_per_user) = &self.rate_limit_per_user {
-            req = req.rate_limit_per_user(*rate_limit_per_user)?;
+        if let Some(rate_limit_per_user) = self.rate_limit_per_user {
+            twilight_validate::channel::rate_limit_per_user(rate_limit_per_user)?;
+            body.rate_limit_per_user = Some(rate_limit_per_user);
         }
 
         if let Some(topic) = &self.topic {
-            req = req.topic(topic)?;
+            twilight_validate::channel::topic(topic)?;
+            body.topic = Some(topic.clone());
         }
 
-        if let Some(user_limit) = &self.user_limit {
-            req = req.user_limit(*user_limit)?;
+        if let Some(user_limit) = self.user_limit {
+            validate_user_limit(user_limit)?;
+            body.user_limit = Some(user_limit);
         }
 
-        if let Some(video_quality_mode) = &self.video_quality_mode {
-            req = req.video_quality_mode((*video_quality_mode).into());
-        }
+        body.video_quality_mode = self.video_quality_mode.map(Into::into);
 
-        Ok(req)
+        body.available_tags = self
+            .available_tags
+            .as_ref()
+            .map(|tags| ForumTagUpdate::from_fields(tags, current_tags))
+            .transpose()?;
+
+        body.default_reaction_emoji = self
+            .default_reaction_emoji
+            .clone()
+            .map(|v| v.map(TryInto::try_into).transpose())
+            .transpose()?;
+
+        body.default_sort_order = self.default_sort_order.map(Into::into);
+
+        Ok(body)
     }
 }
 
@@ -567,68 +611,274 @@ pub struct CreateChannel {
     #[ts(optional)]
     #[serde(default)]
     user_limit: Option<u16>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    available_tags: Option<Vec<ForumTagFields>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    default_reaction_emoji: Option<ForumDefaultReaction>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    default_sort_order: Option<ForumSortOrder>,
 }
 
 impl CreateChannel {
-    pub fn apply<'a, 'b, 'c>(
-        &'a self,
-        perms_buf: &'b mut Vec<twilight_model::channel::permission_overwrite::PermissionOverwrite>,
-        mut req: twilight_http::request::guild::CreateGuildChannel<'c>,
-    ) -> Result<twilight_http::request::guild::CreateGuildChannel<'c>, ChannelValidationError>
-    where
-        'a: 'c,
-        'b: 'c,
-    {
-        if let Some(bitrate) = &self.bitrate {
-            req = req.bitrate(*bitrate)?;
+    /// Builds the body of the create channel request
+    pub fn request_body(&self) -> Result<ChannelRequestBody, anyhow::Error> {
+        if (self.available_tags.is_some()
+            || self.default_reaction_emoji.is_some()
+            || self.default_sort_order.is_some())
+            && !matches!(self.kind, Some(ChannelType::Forum))
+        {
+            return Err(anyhow::anyhow!(
+                "tags, default reaction and sort order can only be set on forum channels"
+            ));
         }
 
-        if let Some(nsfw) = &self.nsfw {
-            req = req.nsfw(*nsfw);
-        }
+        twilight_validate::channel::name(&self.name)?;
 
-        if let Some(parent_id) = &self.parent_id {
-            // TODO: Should we error on invalid ID's?
-            if let Ok(parsed) = parent_id.parse() {
-                if let Some(id) = Id::new_checked(parsed) {
-                    req = req.parent_id(id);
-                }
-            }
+        let mut body = ChannelRequestBody {
+            name: Some(self.name.clone()),
+            kind: self.kind.map(Into::into),
+            ..Default::default()
+        };
+
+        if let Some(bitrate) = self.bitrate {
+            twilight_validate::channel::bitrate(bitrate)?;
+            body.bitrate = Some(bitrate);
         }
 
-        if let Some(permission_overwrites) = &self.permission_overwrites {
-            // TODO: should we error on bad overwrites instead of throwing them away?
-            perms_buf.extend(
-                permission_overwrites
-                    .clone()
-                    .into_iter()
-                    .filter_map(|v| v.try_into().ok()),
-            );
+        body.nsfw = self.nsfw;
 
-            req = req.permission_overwrites(perms_buf);
-        }
+        // TODO: Should we error on invalid ID's?
+        body.parent_id = self
+            .parent_id
+            .as_ref()
+            .and_then(|s| Id::new_checked(s.parse().ok()?))
+            .map(Some);
 
-        if let Some(position) = &self.position {
-            req = req.position(position.0);
-        }
+        body.permission_overwrites = self
+            .permission_overwrites
+            .as_ref()
+            .map(|v| convert_permission_overwrites(v));
+        body.position = self.position.map(|v| v.0);
 
-        if let Some(rate_limit_per_user) = &self.rate_limit_per_user {
-            req = req.rate_limit_per_user(*rate_limit_per_user)?;
+        if let Some(rate_limit_per_user) = self.rate_limit_per_user {
+            twilight_validate::channel::rate_limit_per_user(rate_limit_per_user)?;
+            body.rate_limit_per_user = Some(rate_limit_per_user);
         }
 
         if let Some(topic) = &self.topic {
-            req = req.topic(topic)?;
+            twilight_validate::channel::topic(topic)?;
+            body.topic = Some(topic.clone());
         }
 
-        if let Some(user_limit) = &self.user_limit {
-            req = req.user_limit(*user_limit);
+        if let Some(user_limit) = self.user_limit {
+            validate_user_limit(user_limit)?;
+            body.user_limit = Some(user_limit);
         }
 
-        if let Some(kind) = self.kind {
-            req = req.kind(kind.into())
+        body.available_tags = self
+            .available_tags
+            .as_ref()
+            .map(|tags| ForumTagUpdate::from_fields(tags, &[]))
+            .transpose()?;
+
+        body.default_reaction_emoji = self
+            .default_reaction_emoji
+            .clone()
+            .map(|v| v.try_into().map(Some))
+            .transpose()?;
+
+        body.default_sort_order = self.default_sort_order.map(Into::into);
+
+        Ok(body)
+    }
+}
+
+const MAX_VOICE_USER_LIMIT: u16 = 99;
+
+fn validate_user_limit(user_limit: u16) -> Result<(), anyhow::Error> {
+    if user_limit > MAX_VOICE_USER_LIMIT {
+        return Err(anyhow::anyhow!(
+            "user limit can be at most {MAX_VOICE_USER_LIMIT}"
+        ));
+    }
+
+    Ok(())
+}
+
+// TODO: should we error on bad overwrites instead of throwing them away?
+fn convert_permission_overwrites(
+    overwrites: &[PermissionOverwrite],
+) -> Vec<twilight_model::channel::permission_overwrite::PermissionOverwrite> {
+    overwrites
+        .iter()
+        .cloned()
+        .filter_map(|v| v.try_into().ok())
+        .collect()
+}
+
+/// A forum tag provided by a script, tags without an id are created
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(
+    export,
+    rename = "IForumTagFields",
+    export_to = "bindings/internal/IForumTagFields.ts"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ForumTagFields {
+    #[ts(optional)]
+    #[serde(default)]
+    pub id: Option<String>,
+
+    pub name: String,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub moderated: Option<bool>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub emoji_id: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub emoji_name: Option<String>,
+}
+
+const MAX_FORUM_TAGS: usize = 20;
+const MAX_FORUM_TAG_NAME_LEN: usize = 20;
+
+/// The body of a create or update channel request, forum settings included
+///
+/// This is sent as a raw request body since twilight's forum tag model requires an id,
+/// which new tags don't have yet, and this way the channel is configured in a single request
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ChannelRequestBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<twilight_model::channel::ChannelType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bitrate: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Option<Id<ChannelMarker>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_overwrites:
+        Option<Vec<twilight_model::channel::permission_overwrite::PermissionOverwrite>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_limit: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_quality_mode: Option<twilight_model::channel::VideoQualityMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_tags: Option<Vec<ForumTagUpdate>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_reaction_emoji: Option<Option<twilight_model::channel::forum::DefaultReaction>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sort_order: Option<twilight_model::channel::forum::ForumSortOrder>,
+}
+
+impl ChannelRequestBody {
+    /// Whether any of the forum specific fields are set
+    pub fn has_forum_settings(&self) -> bool {
+        self.available_tags.is_some()
+            || self.default_reaction_emoji.is_some()
+            || self.default_sort_order.is_some()
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ForumTagUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id<TagMarker>>,
+    pub name: String,
+    pub moderated: bool,
+    pub emoji_id: Option<Id<EmojiMarker>>,
+    pub emoji_name: Option<String>,
+}
+
+impl ForumTagUpdate {
+    fn from_fields(
+        fields: &[ForumTagFields],
+        current_tags: &[twilight_model::channel::forum::ForumTag],
+    ) -> Result<Vec<Self>, anyhow::Error> {
+        if fields.len() > MAX_FORUM_TAGS {
+            return Err(anyhow::anyhow!(
+                "forum channels can have at most {MAX_FORUM_TAGS} tags"
+            ));
         }
 
-        Ok(req)
+        fields
+            .iter()
+            .map(|tag| {
+                let name_len = tag.name.chars().count();
+                if !(1..=MAX_FORUM_TAG_NAME_LEN).contains(&name_len) {
+                    return Err(anyhow::anyhow!(
+                        "tag names have to be between 1 and {MAX_FORUM_TAG_NAME_LEN} characters long"
+                    ));
+                }
+
+                let id = match &tag.id {
+                    Some(id) => {
+                        let parsed: Id<TagMarker> = id
+                            .parse()
+                            .ok()
+                            .and_then(Id::new_checked)
+                            .ok_or_else(|| anyhow::anyhow!("invalid tag id: {id}"))?;
+
+                        if !current_tags.iter().any(|v| v.id == parsed) {
+                            return Err(anyhow::anyhow!("unknown tag: {id}"));
+                        }
+
+                        Some(parsed)
+                    }
+                    None => None,
+                };
+
+                let emoji_id = match &tag.emoji_id {
+                    Some(id) => Some(
+                        id.parse()
+                            .ok()
+                            .and_then(Id::new_checked)
+                            .ok_or_else(|| anyhow::anyhow!("invalid emoji id: {id}"))?,
+                    ),
+                    None => None,
+                };
+
+                Ok(Self {
+                    id,
+                    name: tag.name.clone(),
+                    moderated: tag.moderated.unwrap_or_default(),
+                    emoji_id,
+                    emoji_name: tag.emoji_name.clone(),
+                })
+            })
+            .collect()
     }
 }
 
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::util::JsBytes;
+
+use super::user::User;
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "ICustomEmoji")]
+#[ts(export_to = "bindings/internal/ICustomEmoji.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct CustomEmoji {
+    pub animated: bool,
+    pub available: bool,
+    pub id: String,
+    pub managed: bool,
+    pub name: String,
+    pub require_colons: bool,
+    pub roles: Vec<String>,
+    pub user: Option<User>,
+}
+
+impl From<twilight_model::guild::Emoji> for CustomEmoji {
+    fn from(v: twilight_model::guild::Emoji) -> Self {
+        Self {
+            animated: v.animated,
+            available: v.available,
+            id: v.id.to_string(),
+            managed: v.managed,
+            name: v.name,
+            require_colons: v.require_colons,
+            roles: v.roles.iter().map(ToString::to_string).collect(),
+            user: v.user.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "ICreateEmoji")]
+#[ts(export_to = "bindings/internal/ICreateEmoji.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEmojiFields {
+    pub name: String,
+
+    pub image_data: JsBytes,
+
+    // mime type of the image, e.g "image/png"
+    pub content_type: String,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub roles: Option<Vec<String>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "IEditEmoji")]
+#[ts(export_to = "bindings/internal/IEditEmoji.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EditEmojiFields {
+    pub emoji_id: String,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub roles: Option<Vec<String>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
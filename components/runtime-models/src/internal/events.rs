@@ -4,10 +4,18 @@ use ts_rs::TS;
 use crate::{
     discord::{
         embed::Embed,
+        guild::Guild,
         invite::{InviteTargetType, InviteTargetUser},
         message::{Attachment, MessageType, ReactionType},
+        role::Role,
+    },
+    internal::{
+        emoji::CustomEmoji,
+        member::Member,
+        messages::{Message, UserMention},
+        sticker::Sticker,
+        user::User,
     },
-    internal::{member::Member, messages::UserMention, user::User},
     util::NotBigU64,
 };
 
@@ -91,6 +99,8 @@ pub struct EventMessageUpdate {
     pub timestamp: Option<NotBigU64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tts: Option<bool>,
+    // the message before the update, none if it was not in the broker's message cache
+    pub previous: Option<Message>,
 }
 
 impl From<twilight_model::gateway::payload::incoming::MessageUpdate> for EventMessageUpdate {
@@ -119,6 +129,7 @@ impl From<twilight_model::gateway::payload::incoming::MessageUpdate> for EventMe
                 .timestamp
                 .map(|ts| NotBigU64(ts.as_micros() as u64 / 1000)),
             tts: v.tts,
+            previous: None,
         }
     }
 }
@@ -295,3 +306,69 @@ impl From<twilight_model::gateway::payload::incoming::ThreadMembersUpdate>
         }
     }
 }
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IEventGuildEmojisUpdate")]
+#[ts(export_to = "bindings/internal/IEventGuildEmojisUpdate.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventGuildEmojisUpdate {
+    pub emojis: Vec<CustomEmoji>,
+}
+
+impl From<twilight_model::gateway::payload::incoming::GuildEmojisUpdate>
+    for EventGuildEmojisUpdate
+{
+    fn from(value: twilight_model::gateway::payload::incoming::GuildEmojisUpdate) -> Self {
+        Self {
+            emojis: value.emojis.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IEventGuildStickersUpdate")]
+#[ts(export_to = "bindings/internal/IEventGuildStickersUpdate.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventGuildStickersUpdate {
+    pub stickers: Vec<Sticker>,
+}
+
+impl From<twilight_model::gateway::payload::incoming::GuildStickersUpdate>
+    for EventGuildStickersUpdate
+{
+    fn from(value: twilight_model::gateway::payload::incoming::GuildStickersUpdate) -> Self {
+        Self {
+            stickers: value.stickers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IEventRoleUpdate")]
+#[ts(export_to = "bindings/internal/IEventRoleUpdate.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventRoleUpdate {
+    pub new: Role,
+    // none if the role was not in the cache
+    pub old: Option<Role>,
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IEventRoleDelete")]
+#[ts(export_to = "bindings/internal/IEventRoleDelete.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventRoleDelete {
+    pub role_id: String,
+    // none if the role was not in the cache
+    pub old: Option<Role>,
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IEventGuildUpdate")]
+#[ts(export_to = "bindings/internal/IEventGuildUpdate.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EventGuildUpdate {
+    pub new: Guild,
+    // none if the guild was not in the cache
+    pub old: Option<Guild>,
+}
@@ -0,0 +1,75 @@
+use serde::Deserialize;
+use ts_rs::TS;
+
+use crate::{
+    discord::guild::{DefaultMessageNotificationLevel, VerificationLevel},
+    util::JsBytes,
+};
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "IEditGuild")]
+#[ts(export_to = "bindings/internal/IEditGuild.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EditGuildFields {
+    #[ts(optional)]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    // null removes the icon
+    #[ts(optional)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_undefined_null_optional_field"
+    )]
+    pub icon: Option<Option<GuildImage>>,
+
+    // null removes the banner
+    #[ts(optional)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_undefined_null_optional_field"
+    )]
+    pub banner: Option<Option<GuildImage>>,
+
+    #[ts(optional)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_undefined_null_optional_field"
+    )]
+    pub system_channel_id: Option<Option<String>>,
+
+    #[ts(optional)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_undefined_null_optional_field"
+    )]
+    pub afk_channel_id: Option<Option<String>>,
+
+    // one of 60, 300, 900, 1800 or 3600
+    #[ts(optional)]
+    #[serde(default)]
+    pub afk_timeout_seconds: Option<u16>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub verification_level: Option<VerificationLevel>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub default_message_notifications: Option<DefaultMessageNotificationLevel>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "IGuildImage")]
+#[ts(export_to = "bindings/internal/IGuildImage.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct GuildImage {
+    pub image_data: JsBytes,
+
+    // mime type of the image, e.g "image/png"
+    pub content_type: String,
+}
@@ -8,6 +8,8 @@ use ts_rs::TS;
 #[ts(export, export_to = "bindings/internal/Interaction.ts")]
 pub enum Interaction {
     Command(Box<CommandInteraction>),
+    // the focused option has a value of kind "Focused"
+    Autocomplete(Box<CommandInteraction>),
     MessageComponent(MessageComponentInteraction),
     ModalSubmit(ModalInteraction),
 }
@@ -59,7 +61,7 @@ impl TryFrom<twilight_model::application::interaction::Interaction> for Interact
                     }
                 }
 
-                Ok(Self::Command(Box::new(CommandInteraction {
+                let interaction = Box::new(CommandInteraction {
                     name,
                     parent_name,
                     parent_parent_name,
@@ -72,7 +74,13 @@ impl TryFrom<twilight_model::application::interaction::Interaction> for Interact
 
                     kind: data.kind.into(),
                     target_id: data.target_id.as_ref().map(ToString::to_string),
-                })))
+                });
+
+                if v.kind == InteractionType::ApplicationCommandAutocomplete {
+                    Ok(Self::Autocomplete(interaction))
+                } else {
+                    Ok(Self::Command(interaction))
+                }
             }
             Some(twilight_model::application::interaction::InteractionData::MessageComponent(
                 data,
@@ -136,8 +144,9 @@ pub struct MessageComponentInteraction {
 use std::collections::HashMap;
 
 use serde::Deserialize;
-use twilight_model::application::interaction::application_command::{
-    CommandDataOption, CommandInteractionDataResolved, CommandOptionValue,
+use twilight_model::application::interaction::{
+    application_command::{CommandDataOption, CommandInteractionDataResolved, CommandOptionValue},
+    InteractionType,
 };
 
 use crate::{
@@ -11,7 +11,7 @@ use crate::{
 };
 use twilight_model::application::interaction::application_command;
 
-use super::messages::OpCreateMessageFields;
+use super::{messages::OpCreateMessageFields, script::CommandOptionChoice};
 
 #[derive(Clone, Debug, Serialize, TS)]
 #[ts(export)]
@@ -80,7 +80,8 @@ pub struct InteractionCallback {
 #[derive(Clone, Debug, Deserialize, TS)]
 #[ts(export)]
 #[ts(export_to = "bindings/internal/InteractionResponse.ts")]
-#[serde(tag = "kind")]
+// adjacently tagged so that the attachment buffers don't go through serde's content buffering
+#[serde(tag = "kind", content = "response")]
 pub enum InteractionResponse {
     Pong,
     ChannelMessageWithSource(InteractionCallbackData),
@@ -88,7 +89,20 @@ pub enum InteractionResponse {
     DeferredUpdateMessage,
     UpdateMessage(InteractionCallbackData),
     Modal(ModalCallbackData),
-    // Autocomplete(Autocomplete),
+    Autocomplete(AutocompleteCallbackData),
+}
+
+impl InteractionResponse {
+    pub fn message_fields(&self) -> Option<&OpCreateMessageFields> {
+        match self {
+            Self::ChannelMessageWithSource(src)
+            | Self::DeferredChannelMessageWithSource(src)
+            | Self::UpdateMessage(src) => Some(&src.fields),
+            Self::Pong | Self::DeferredUpdateMessage | Self::Modal(_) | Self::Autocomplete(_) => {
+                None
+            }
+        }
+    }
 }
 
 impl From<InteractionResponse> for twilight_model::http::interaction::InteractionResponse {
@@ -120,6 +134,10 @@ impl From<InteractionResponse> for twilight_model::http::interaction::Interactio
                 kind: TwilightInteractionResponseType::Modal,
                 data: Some(src.into()),
             },
+            InteractionResponse::Autocomplete(src) => Self {
+                kind: TwilightInteractionResponseType::ApplicationCommandAutocompleteResult,
+                data: Some(src.into()),
+            },
         }
     }
 }
@@ -150,6 +168,7 @@ impl From<InteractionCallbackData> for TwilightCallbackData {
             flags: v.flags.map(Into::into),
             tts: None,
 
+            // attachments need to be decoded, this is handled by the op
             attachments: None,
             choices: None,
             custom_id: None,
@@ -181,3 +200,23 @@ impl From<ModalCallbackData> for TwilightCallbackData {
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "bindings/internal/IAutocompleteCallbackData.ts",
+    rename = "IAutocompleteCallbackData"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AutocompleteCallbackData {
+    choices: Vec<CommandOptionChoice>,
+}
+
+impl From<AutocompleteCallbackData> for TwilightCallbackData {
+    fn from(v: AutocompleteCallbackData) -> Self {
+        Self {
+            choices: Some(v.choices.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+}
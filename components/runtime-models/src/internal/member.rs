@@ -36,6 +36,26 @@ pub struct UpdateGuildMemberFields {
     pub communication_disabled_until: Option<Option<NotBigU64>>,
 }
 
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "ISearchMembers")]
+#[ts(export_to = "bindings/internal/ISearchMembers.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct SearchMembersFields {
+    // matched against the username, global name and nickname
+    pub query: String,
+
+    // max 1000, defaults to 25
+    #[ts(optional)]
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    // also match by substrings and subsequences instead of only prefixes,
+    // not supported when falling back to the discord api
+    #[ts(optional)]
+    #[serde(default)]
+    pub fuzzy: Option<bool>,
+}
+
 #[derive(Clone, Debug, Serialize, TS)]
 #[ts(export, rename = "IMember")]
 #[ts(export_to = "bindings/internal/Member.ts")]
@@ -5,11 +5,12 @@ use crate::{
         member::PartialMember,
         message::{
             Attachment, ChannelMention, MessageActivity, MessageApplication, MessageFlags,
-            MessageReaction, MessageReference, MessageType,
+            MessageReaction, MessageReference, MessageType, SendEmoji,
         },
+        poll::{DiscordCreatePoll, DiscordCreatePollAnswer, DiscordPollMedia, Poll},
     },
     internal::user::User,
-    util::NotBigU64,
+    util::{JsBytes, NotBigU64},
 };
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -45,6 +46,105 @@ pub struct OpDeleteMessagesBulk {
 pub struct OpCreateChannelMessage {
     pub channel_id: String,
     pub fields: OpCreateMessageFields,
+    #[serde(default)]
+    #[ts(optional)]
+    pub poll: Option<CreatePollFields>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export, rename = "ICreatePoll")]
+#[ts(export_to = "bindings/internal/ICreatePoll.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePollFields {
+    pub question: String,
+    pub answers: Vec<CreatePollAnswer>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub duration_hours: Option<u32>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub allow_multiselect: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export, rename = "ICreatePollAnswer")]
+#[ts(export_to = "bindings/internal/ICreatePollAnswer.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePollAnswer {
+    pub text: String,
+    #[serde(default)]
+    #[ts(optional)]
+    pub emoji: Option<SendEmoji>,
+}
+
+const POLL_QUESTION_MAX_LEN: usize = 300;
+const POLL_ANSWER_MAX_LEN: usize = 55;
+const POLL_MAX_ANSWERS: usize = 10;
+const POLL_MAX_DURATION_HOURS: u32 = 768;
+
+impl TryFrom<CreatePollFields> for DiscordCreatePoll {
+    type Error = anyhow::Error;
+
+    fn try_from(v: CreatePollFields) -> Result<Self, Self::Error> {
+        if v.question.is_empty() || v.question.chars().count() > POLL_QUESTION_MAX_LEN {
+            return Err(anyhow::anyhow!(
+                "poll question has to be between 1 and {POLL_QUESTION_MAX_LEN} characters long"
+            ));
+        }
+
+        if v.answers.is_empty() || v.answers.len() > POLL_MAX_ANSWERS {
+            return Err(anyhow::anyhow!(
+                "polls need between 1 and {POLL_MAX_ANSWERS} answers"
+            ));
+        }
+
+        if let Some(answer) = v
+            .answers
+            .iter()
+            .find(|a| a.text.is_empty() || a.text.chars().count() > POLL_ANSWER_MAX_LEN)
+        {
+            return Err(anyhow::anyhow!(
+                "poll answers have to be between 1 and {POLL_ANSWER_MAX_LEN} characters long: \
+                 {}",
+                answer.text
+            ));
+        }
+
+        let duration = v.duration_hours.unwrap_or(24);
+        if !(1..=POLL_MAX_DURATION_HOURS).contains(&duration) {
+            return Err(anyhow::anyhow!(
+                "poll duration has to be between 1 and {POLL_MAX_DURATION_HOURS} hours"
+            ));
+        }
+
+        Ok(Self {
+            question: DiscordPollMedia {
+                text: Some(v.question),
+                emoji: None,
+            },
+            answers: v
+                .answers
+                .into_iter()
+                .map(|a| DiscordCreatePollAnswer {
+                    poll_media: DiscordPollMedia {
+                        text: Some(a.text),
+                        emoji: a.emoji.map(Into::into),
+                    },
+                })
+                .collect(),
+            duration,
+            allow_multiselect: v.allow_multiselect.unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/SendDirectMessage.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct OpSendDirectMessage {
+    pub user_id: String,
+    pub fields: OpCreateMessageFields,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
@@ -84,6 +184,21 @@ pub struct OpCreateMessageFields {
     #[serde(default)]
     #[ts(optional)]
     pub components: Option<Vec<Component>>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub attachments: Option<Vec<OpCreateMessageAttachment>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/CreateMessageAttachment.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct OpCreateMessageAttachment {
+    pub filename: String,
+    #[serde(default)]
+    #[ts(optional)]
+    pub description: Option<String>,
+    pub data: JsBytes,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
@@ -185,6 +300,8 @@ pub struct Message {
     pub timestamp: NotBigU64,
     pub tts: bool,
     pub webhook_id: Option<String>,
+    /// Not available on the previous message of update and delete events
+    pub poll: Option<Poll>,
 }
 
 impl From<twilight_model::channel::Message> for Message {
@@ -217,6 +334,7 @@ impl From<twilight_model::channel::Message> for Message {
             timestamp: NotBigU64(v.timestamp.as_micros() as u64 / 1000),
             tts: v.tts,
             webhook_id: v.webhook_id.as_ref().map(ToString::to_string),
+            poll: None,
         }
     }
 }
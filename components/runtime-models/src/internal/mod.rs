@@ -1,6 +1,10 @@
+pub mod audit_log;
+pub mod auto_moderation;
 pub mod channel;
 pub mod console;
+pub mod emoji;
 pub mod events;
+pub mod guild;
 pub mod httpclient;
 pub mod interaction;
 pub mod interactions;
@@ -8,8 +12,12 @@ pub mod invite;
 pub mod member;
 pub mod messages;
 pub mod misc_op;
+pub mod role;
+pub mod scheduled_event;
 pub mod script;
+pub mod sticker;
 pub mod storage;
 pub mod tasks;
 pub mod timers;
 pub mod user;
+pub mod webhook;
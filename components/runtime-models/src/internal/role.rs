@@ -0,0 +1,98 @@
+use serde::Deserialize;
+use ts_rs::TS;
+
+use crate::util::NotBigU64;
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(
+    export,
+    rename = "ICreateRole",
+    export_to = "bindings/internal/ICreateRole.ts"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRoleFields {
+    #[ts(optional)]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub color: Option<u32>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub hoist: Option<bool>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub mentionable: Option<bool>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub permissions_raw: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub unicode_emoji: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(
+    export,
+    rename = "IEditRole",
+    export_to = "bindings/internal/IEditRole.ts"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct EditRoleFields {
+    pub role_id: String,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[ts(optional)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_undefined_null_optional_field"
+    )]
+    pub color: Option<Option<u32>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub hoist: Option<bool>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub mentionable: Option<bool>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub permissions_raw: Option<String>,
+
+    #[ts(optional)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_undefined_null_optional_field"
+    )]
+    pub unicode_emoji: Option<Option<String>>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(
+    export,
+    rename = "IUpdateRolePosition",
+    export_to = "bindings/internal/IUpdateRolePosition.ts"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRolePosition {
+    pub role_id: String,
+    pub position: NotBigU64,
+}
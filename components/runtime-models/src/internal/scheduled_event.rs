@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use super::{member::Member, user::User};
+use crate::{
+    discord::scheduled_event::{ScheduledEventEntityType, ScheduledEventStatus},
+    util::NotBigU64,
+};
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IScheduledEvent")]
+#[ts(export_to = "bindings/internal/IScheduledEvent.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledEvent {
+    pub id: String,
+    pub channel_id: Option<String>,
+    pub creator: Option<User>,
+    pub creator_id: Option<String>,
+    pub description: Option<String>,
+    pub entity_type: ScheduledEventEntityType,
+    // only present for external events
+    pub location: Option<String>,
+    pub image: Option<String>,
+    pub name: String,
+    pub scheduled_start_time: NotBigU64,
+    pub scheduled_end_time: Option<NotBigU64>,
+    pub status: ScheduledEventStatus,
+    pub user_count: Option<NotBigU64>,
+}
+
+impl From<twilight_model::guild::scheduled_event::GuildScheduledEvent> for ScheduledEvent {
+    fn from(v: twilight_model::guild::scheduled_event::GuildScheduledEvent) -> Self {
+        Self {
+            id: v.id.to_string(),
+            channel_id: v.channel_id.as_ref().map(ToString::to_string),
+            creator: v.creator.map(Into::into),
+            creator_id: v.creator_id.as_ref().map(ToString::to_string),
+            description: v.description,
+            entity_type: v.entity_type.into(),
+            location: v.entity_metadata.and_then(|v| v.location),
+            image: v.image.as_ref().map(ToString::to_string),
+            name: v.name,
+            scheduled_start_time: NotBigU64(v.scheduled_start_time.as_micros() as u64 / 1000),
+            scheduled_end_time: v
+                .scheduled_end_time
+                .map(|ts| NotBigU64(ts.as_micros() as u64 / 1000)),
+            status: v.status.into(),
+            user_count: v.user_count.map(NotBigU64),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IScheduledEventUser")]
+#[ts(export_to = "bindings/internal/IScheduledEventUser.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledEventUser {
+    pub user: User,
+    pub member: Option<Member>,
+}
+
+impl From<twilight_model::guild::scheduled_event::GuildScheduledEventUser> for ScheduledEventUser {
+    fn from(v: twilight_model::guild::scheduled_event::GuildScheduledEventUser) -> Self {
+        Self {
+            user: v.user.into(),
+            member: v.member.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "ICreateScheduledEvent")]
+#[ts(export_to = "bindings/internal/ICreateScheduledEvent.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct CreateScheduledEventFields {
+    pub name: String,
+    pub entity_type: ScheduledEventEntityType,
+
+    // required for voice and stage instance events
+    #[ts(optional)]
+    #[serde(default)]
+    pub channel_id: Option<String>,
+
+    // required for external events
+    #[ts(optional)]
+    #[serde(default)]
+    pub location: Option<String>,
+
+    pub scheduled_start_time: NotBigU64,
+
+    // required for external events
+    #[ts(optional)]
+    #[serde(default)]
+    pub scheduled_end_time: Option<NotBigU64>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub description: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "IEditScheduledEvent")]
+#[ts(export_to = "bindings/internal/IEditScheduledEvent.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EditScheduledEventFields {
+    pub event_id: String,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub description: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub entity_type: Option<ScheduledEventEntityType>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub channel_id: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub location: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub scheduled_start_time: Option<NotBigU64>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub scheduled_end_time: Option<NotBigU64>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub status: Option<ScheduledEventStatus>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "IGetScheduledEventUsers")]
+#[ts(export_to = "bindings/internal/IGetScheduledEventUsers.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct GetScheduledEventUsersFields {
+    pub event_id: String,
+
+    // user id to return users after, used for pagination
+    #[ts(optional)]
+    #[serde(default)]
+    pub after: Option<String>,
+
+    // user id to return users before, used for pagination
+    #[ts(optional)]
+    #[serde(default)]
+    pub before: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub limit: Option<u16>,
+}
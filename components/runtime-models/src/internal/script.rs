@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
@@ -67,6 +69,14 @@ pub struct CommandGroup {
     pub name: String,
     pub description: String,
     pub sub_groups: Vec<CommandSubGroup>,
+
+    // permissions bitfield required to see and use the commands in this group
+    #[serde(default)]
+    #[ts(optional)]
+    pub default_member_permissions: Option<String>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub nsfw: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, TS)]
@@ -94,6 +104,24 @@ pub struct Command {
     pub sub_group: Option<String>,
 
     pub kind: CommandType,
+
+    // permissions bitfield required to see and use the command,
+    // commands in groups inherit this from the group instead
+    #[serde(default)]
+    #[ts(optional)]
+    pub default_member_permissions: Option<String>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub nsfw: Option<bool>,
+
+    // locale => name
+    #[serde(default)]
+    #[ts(optional)]
+    pub name_localizations: Option<HashMap<String, String>>,
+    // locale => description
+    #[serde(default)]
+    #[ts(optional)]
+    pub description_localizations: Option<HashMap<String, String>>,
 }
 
 impl From<Command> for twilight_model::application::command::CommandOption {
@@ -106,8 +134,8 @@ impl From<Command> for twilight_model::application::command::CommandOption {
             } else {
                 Some(cmd.options.into_iter().map(Into::into).collect())
             },
-            description_localizations: Default::default(),
-            name_localizations: Default::default(),
+            description_localizations: cmd.description_localizations,
+            name_localizations: cmd.name_localizations,
             autocomplete: None,
             channel_types: None,
             choices: None,
@@ -162,6 +190,13 @@ pub struct CommandOption {
     pub kind: CommandOptionType,
     pub required: bool,
     pub extra_options: ExtraCommandOptions,
+
+    #[serde(default)]
+    #[ts(optional)]
+    pub name_localizations: Option<HashMap<String, String>>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub description_localizations: Option<HashMap<String, String>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, TS)]
@@ -184,6 +219,11 @@ pub struct ExtraCommandOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub choices: Option<Vec<CommandOptionChoice>>,
+
+    // only valid for string, integer and number options, can't be combined with choices
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub autocomplete: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, TS)]
@@ -191,8 +231,12 @@ pub struct ExtraCommandOptions {
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "bindings/internal/CommandOptionChoice.ts")]
 pub struct CommandOptionChoice {
-    name: String,
-    value: CommandOptionChoiceValue,
+    pub name: String,
+    pub value: CommandOptionChoiceValue,
+
+    #[serde(default)]
+    #[ts(optional)]
+    pub name_localizations: Option<HashMap<String, String>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, TS)]
@@ -220,7 +264,7 @@ impl From<CommandOptionChoice> for twilight_model::application::command::Command
     fn from(v: CommandOptionChoice) -> Self {
         Self {
             name: v.name,
-            name_localizations: None,
+            name_localizations: v.name_localizations,
             value: v.value.into(),
         }
     }
@@ -240,18 +284,18 @@ impl From<CommandOption> for twilight_model::application::command::CommandOption
                 description: v.description,
                 required: Some(v.required),
                 kind: twilight_model::application::command::CommandOptionType::String,
-                autocomplete: None,
+                autocomplete: v.extra_options.autocomplete,
                 channel_types: None,
                 choices: v
                     .extra_options
                     .choices
                     .map(|iv| iv.into_iter().map(Into::into).collect()),
-                description_localizations: None,
+                description_localizations: v.description_localizations,
                 max_length: None,
                 max_value: None,
                 min_length: None,
                 min_value: None,
-                name_localizations: None,
+                name_localizations: v.name_localizations,
                 options: None,
             },
             CommandOptionType::Integer => Self {
@@ -267,16 +311,16 @@ impl From<CommandOption> for twilight_model::application::command::CommandOption
                     .extra_options
                     .max_value
                     .map(|v| CommandOptionValue::Integer(v as i64)),
-                autocomplete: None,
+                autocomplete: v.extra_options.autocomplete,
                 channel_types: None,
                 choices: v
                     .extra_options
                     .choices
                     .map(|iv| iv.into_iter().map(Into::into).collect()),
-                description_localizations: None,
+                description_localizations: v.description_localizations,
                 max_length: None,
                 min_length: None,
-                name_localizations: None,
+                name_localizations: v.name_localizations,
                 options: None,
             },
             CommandOptionType::Boolean => Self {
@@ -284,8 +328,8 @@ impl From<CommandOption> for twilight_model::application::command::CommandOption
                 description: v.description,
                 required: Some(v.required),
                 kind: twilight_model::application::command::CommandOptionType::Boolean,
-                description_localizations: Default::default(),
-                name_localizations: Default::default(),
+                description_localizations: v.description_localizations,
+                name_localizations: v.name_localizations,
                 autocomplete: None,
                 channel_types: None,
                 choices: None,
@@ -300,8 +344,8 @@ impl From<CommandOption> for twilight_model::application::command::CommandOption
                 description: v.description,
                 required: Some(v.required),
                 kind: twilight_model::application::command::CommandOptionType::User,
-                description_localizations: Default::default(),
-                name_localizations: Default::default(),
+                description_localizations: v.description_localizations,
+                name_localizations: v.name_localizations,
                 autocomplete: None,
                 channel_types: None,
                 choices: None,
@@ -324,8 +368,8 @@ impl From<CommandOption> for twilight_model::application::command::CommandOption
                         .map(Into::into)
                         .collect(),
                 ),
-                description_localizations: Default::default(),
-                name_localizations: Default::default(),
+                description_localizations: v.description_localizations,
+                name_localizations: v.name_localizations,
                 autocomplete: None,
                 choices: None,
                 max_length: None,
@@ -340,8 +384,8 @@ impl From<CommandOption> for twilight_model::application::command::CommandOption
                 required: Some(v.required),
                 kind: twilight_model::application::command::CommandOptionType::Role,
 
-                description_localizations: Default::default(),
-                name_localizations: Default::default(),
+                description_localizations: v.description_localizations,
+                name_localizations: v.name_localizations,
                 autocomplete: None,
                 channel_types: None,
                 choices: None,
@@ -356,8 +400,8 @@ impl From<CommandOption> for twilight_model::application::command::CommandOption
                 description: v.description,
                 required: Some(v.required),
                 kind: twilight_model::application::command::CommandOptionType::Mentionable,
-                description_localizations: Default::default(),
-                name_localizations: Default::default(),
+                description_localizations: v.description_localizations,
+                name_localizations: v.name_localizations,
                 autocomplete: None,
                 channel_types: None,
                 choices: None,
@@ -374,16 +418,16 @@ impl From<CommandOption> for twilight_model::application::command::CommandOption
                 kind: twilight_model::application::command::CommandOptionType::Number,
                 min_value: v.extra_options.min_value.map(CommandOptionValue::Number),
                 max_value: v.extra_options.max_value.map(CommandOptionValue::Number),
-                autocomplete: None,
+                autocomplete: v.extra_options.autocomplete,
                 channel_types: None,
                 choices: v
                     .extra_options
                     .choices
                     .map(|iv| iv.into_iter().map(Into::into).collect()),
-                description_localizations: None,
+                description_localizations: v.description_localizations,
                 max_length: None,
                 min_length: None,
-                name_localizations: None,
+                name_localizations: v.name_localizations,
                 options: None,
             },
         }
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::{
+    discord::sticker::{StickerFormatType, StickerType},
+    util::JsBytes,
+};
+
+use super::user::User;
+
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "ISticker")]
+#[ts(export_to = "bindings/internal/ISticker.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct Sticker {
+    pub available: bool,
+    pub description: Option<String>,
+    pub format_type: StickerFormatType,
+    pub guild_id: Option<String>,
+    pub id: String,
+    pub kind: StickerType,
+    pub name: String,
+    pub tags: String,
+    pub user: Option<User>,
+}
+
+impl From<twilight_model::channel::message::sticker::Sticker> for Sticker {
+    fn from(v: twilight_model::channel::message::sticker::Sticker) -> Self {
+        Self {
+            available: v.available,
+            description: v.description,
+            format_type: v.format_type.into(),
+            guild_id: v.guild_id.as_ref().map(ToString::to_string),
+            id: v.id.to_string(),
+            kind: v.kind.into(),
+            name: v.name,
+            tags: v.tags,
+            user: v.user.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "ICreateSticker")]
+#[ts(export_to = "bindings/internal/ICreateSticker.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStickerFields {
+    pub name: String,
+    pub description: String,
+
+    // autocomplete/suggestion tags for the sticker
+    pub tags: String,
+
+    // png, apng or lottie json file
+    pub file_data: JsBytes,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "IEditSticker")]
+#[ts(export_to = "bindings/internal/IEditSticker.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct EditStickerFields {
+    pub sticker_id: String,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub description: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub tags: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
@@ -1,8 +1,10 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 use stores::bucketstore::{self, SetCondition};
 use ts_rs::TS;
 
-use crate::util::{NotBigU64, PluginId};
+use crate::util::{JsBytes, NotBigU64, PluginId};
 
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
 #[ts(export)]
@@ -19,6 +21,7 @@ pub struct OpStorageBucket {
 pub enum OpStorageBucketValue {
     Json(#[ts(type = "any")] serde_json::Value),
     Double(f64),
+    Bytes(JsBytes),
 }
 
 impl From<bucketstore::StoreValue> for OpStorageBucketValue {
@@ -26,6 +29,7 @@ impl From<bucketstore::StoreValue> for OpStorageBucketValue {
         match v {
             bucketstore::StoreValue::Json(s) => Self::Json(s),
             bucketstore::StoreValue::Float(f) => Self::Double(f),
+            bucketstore::StoreValue::Bytes(b) => Self::Bytes(JsBytes(b)),
         }
     }
 }
@@ -35,6 +39,7 @@ impl From<OpStorageBucketValue> for bucketstore::StoreValue {
         match v {
             OpStorageBucketValue::Json(s) => Self::Json(s),
             OpStorageBucketValue::Double(f) => Self::Float(f),
+            OpStorageBucketValue::Bytes(b) => Self::Bytes(b.0),
         }
     }
 }
@@ -97,9 +102,52 @@ pub struct OpStorageBucketList {
     #[ts(optional)]
     pub limit: Option<u32>,
 
+    #[serde(default)]
+    #[ts(optional)]
+    pub cursor: Option<String>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub reverse: Option<bool>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub range: Option<OpStorageBucketKeyRange>,
+
     pub plugin_id: Option<PluginId>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageBucketKeyRange.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct OpStorageBucketKeyRange {
+    #[serde(default)]
+    #[ts(optional)]
+    pub key_prefix: Option<String>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub start: Option<String>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub end: Option<String>,
+}
+
+impl TryFrom<OpStorageBucketKeyRange> for bucketstore::KeyRange {
+    type Error = anyhow::Error;
+
+    fn try_from(v: OpStorageBucketKeyRange) -> Result<Self, Self::Error> {
+        match v.key_prefix {
+            Some(_) if v.start.is_some() || v.end.is_some() => Err(anyhow::anyhow!(
+                "keyPrefix can't be combined with start and end"
+            )),
+            Some(prefix) => Ok(Self::prefix(prefix)),
+            None => Ok(Self {
+                start: v.start,
+                end: v.end,
+            }),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
 #[ts(export)]
 #[ts(export_to = "bindings/internal/StorageBucketListOrder.ts")]
@@ -133,9 +181,68 @@ pub struct OpStorageBucketSortedList {
     #[ts(optional)]
     pub limit: Option<u32>,
 
+    #[serde(default)]
+    #[ts(optional)]
+    pub cursor: Option<String>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub range: Option<OpStorageBucketKeyRange>,
+
     pub order: OpStorageBucketListOrder,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageBucketSortedRank.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct OpStorageBucketSortedRank {
+    pub bucket_name: String,
+    pub plugin_id: Option<PluginId>,
+    pub key: String,
+    pub order: OpStorageBucketListOrder,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageBucketSortedNeighbors.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct OpStorageBucketSortedNeighbors {
+    pub bucket_name: String,
+    pub plugin_id: Option<PluginId>,
+    pub key: String,
+    pub order: OpStorageBucketListOrder,
+
+    #[serde(default)]
+    #[ts(optional)]
+    pub before: Option<u32>,
+
+    #[serde(default)]
+    #[ts(optional)]
+    pub after: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageBucketNeighbors.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct OpStorageBucketNeighbors {
+    rank: NotBigU64,
+    before: Vec<OpStorageBucketEntry>,
+    entry: OpStorageBucketEntry,
+    after: Vec<OpStorageBucketEntry>,
+}
+
+impl From<bucketstore::SortedNeighbors> for OpStorageBucketNeighbors {
+    fn from(v: bucketstore::SortedNeighbors) -> Self {
+        Self {
+            rank: NotBigU64(v.rank),
+            before: v.before.into_iter().map(Into::into).collect(),
+            entry: v.entry.into(),
+            after: v.after.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
 #[ts(export)]
 #[ts(export_to = "bindings/internal/StorageBucketIncr.ts")]
@@ -158,11 +265,13 @@ pub struct OpStorageBucketEntry {
     key: String,
     value: OpStorageBucketValue,
     expires_at: Option<NotBigU64>,
+    cursor: String,
 }
 
 impl From<bucketstore::Entry> for OpStorageBucketEntry {
     fn from(v: bucketstore::Entry) -> Self {
         Self {
+            cursor: v.cursor().encode(),
             plugin_id: v.plugin_id.map(PluginId),
             bucket_name: v.bucket,
             key: v.key,
@@ -188,3 +297,152 @@ impl From<OpStorageBucketSetCondition> for SetCondition {
         }
     }
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageBucketTransactionOp.ts")]
+// adjacently tagged as internally tagged enums buffer their content, which breaks the
+// serde_v8 buffers used by bytes values
+#[serde(tag = "kind", content = "op")]
+pub enum OpStorageBucketTransactionOp {
+    Set(OpStorageBucketSetValue),
+    SetIf(OpStorageBucketSetIf),
+    Incr(OpStorageBucketIncr),
+    Del(OpStorageBucketEntryId),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageBucketTransactionResult.ts")]
+#[serde(tag = "kind")]
+pub enum OpStorageBucketTransactionResult {
+    Committed {
+        entries: Vec<Option<OpStorageBucketEntry>>,
+    },
+    ConditionFailed {
+        index: u32,
+    },
+}
+
+impl From<bucketstore::TransactionResult> for OpStorageBucketTransactionResult {
+    fn from(v: bucketstore::TransactionResult) -> Self {
+        match v {
+            bucketstore::TransactionResult::Committed(entries) => Self::Committed {
+                entries: entries
+                    .into_iter()
+                    .map(|e| e.map(|write| write.entry.into()))
+                    .collect(),
+            },
+            bucketstore::TransactionResult::ConditionFailed(index) => Self::ConditionFailed {
+                index: index as u32,
+            },
+        }
+    }
+}
+
+impl From<OpStorageBucketTransactionOp> for bucketstore::TransactionOp {
+    fn from(v: OpStorageBucketTransactionOp) -> Self {
+        match v {
+            OpStorageBucketTransactionOp::Set(set) => Self::Set {
+                plugin_id: set.plugin_id.map(Into::into),
+                bucket: set.bucket_name,
+                key: set.key,
+                value: set.value.into(),
+                ttl: set.ttl.map(|ttl| Duration::from_secs(ttl as u64)),
+                cond: None,
+            },
+            OpStorageBucketTransactionOp::SetIf(set) => Self::Set {
+                plugin_id: set.plugin_id.map(Into::into),
+                bucket: set.bucket_name,
+                key: set.key,
+                value: set.value.into(),
+                ttl: set.ttl.map(|ttl| Duration::from_secs(ttl as u64)),
+                cond: Some(set.cond.into()),
+            },
+            OpStorageBucketTransactionOp::Incr(incr) => Self::Incr {
+                plugin_id: incr.plugin_id.map(Into::into),
+                bucket: incr.bucket_name,
+                key: incr.key,
+                incr_by: incr.amount,
+            },
+            OpStorageBucketTransactionOp::Del(del) => Self::Del {
+                plugin_id: del.plugin_id.map(Into::into),
+                bucket: del.bucket_name,
+                key: del.key,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageBucketSubscribe.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct OpStorageBucketSubscribe {
+    pub bucket_name: String,
+    pub plugin_id: Option<PluginId>,
+
+    #[serde(default)]
+    #[ts(optional)]
+    pub key_prefix: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageValueKind.ts")]
+pub enum OpStorageValueKind {
+    Json,
+    Double,
+    Bytes,
+}
+
+/// Describes a value without including it, the values themselves can be up to 1MB
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageValueMeta.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct OpStorageValueMeta {
+    pub kind: OpStorageValueKind,
+    /// The value itself for number values
+    pub number: Option<f64>,
+    /// Size of the value in bytes, json values are measured serialized
+    pub size: u32,
+    pub expires_at: Option<NotBigU64>,
+}
+
+impl From<&bucketstore::EntryMeta> for OpStorageValueMeta {
+    fn from(v: &bucketstore::EntryMeta) -> Self {
+        Self {
+            kind: match v.kind {
+                bucketstore::StoreValueKind::Json => OpStorageValueKind::Json,
+                bucketstore::StoreValueKind::Float => OpStorageValueKind::Double,
+                bucketstore::StoreValueKind::Bytes => OpStorageValueKind::Bytes,
+            },
+            number: v.number,
+            size: v.size as u32,
+            expires_at: v.expires_at.map(|e| NotBigU64(e.timestamp_millis() as u64)),
+        }
+    }
+}
+
+impl From<&bucketstore::Entry> for OpStorageValueMeta {
+    fn from(v: &bucketstore::Entry) -> Self {
+        (&bucketstore::EntryMeta::from(v)).into()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[ts(export_to = "bindings/internal/StorageChangedEvent.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct StorageChangedEvent {
+    pub plugin_id: Option<PluginId>,
+    pub bucket_name: String,
+    pub key: String,
+    /// Empty if the key didn't exist before
+    pub old: Option<OpStorageValueMeta>,
+    /// Empty if the key was deleted
+    pub new: Option<OpStorageValueMeta>,
+    /// How many STORAGE_CHANGED handlers deep the change was made, 0 if it wasn't made from one
+    pub depth: u32,
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use super::{messages::OpCreateMessageFields, user::User};
+use crate::discord::webhook::WebhookType;
+
+// note: the token is intentionally left out, scripts never get to see it
+#[derive(Clone, Debug, Serialize, TS)]
+#[ts(export, rename = "IWebhook")]
+#[ts(export_to = "bindings/internal/IWebhook.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    pub id: String,
+    pub kind: WebhookType,
+    pub channel_id: String,
+    pub name: Option<String>,
+    pub avatar: Option<String>,
+    pub application_id: Option<String>,
+    pub user: Option<User>,
+}
+
+impl From<twilight_model::channel::Webhook> for Webhook {
+    fn from(v: twilight_model::channel::Webhook) -> Self {
+        Self {
+            id: v.id.to_string(),
+            kind: v.kind.into(),
+            channel_id: v.channel_id.to_string(),
+            name: v.name,
+            avatar: v.avatar.map(|v| v.to_string()),
+            application_id: v.application_id.map(|v| v.to_string()),
+            user: v.user.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "ICreateWebhook")]
+#[ts(export_to = "bindings/internal/ICreateWebhook.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhookFields {
+    pub channel_id: String,
+    pub name: String,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub audit_log_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, TS)]
+#[ts(export, rename = "IExecuteWebhook")]
+#[ts(export_to = "bindings/internal/IExecuteWebhook.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct ExecuteWebhookFields {
+    pub webhook_id: String,
+
+    // send the message to this thread inside the webhook's channel
+    #[ts(optional)]
+    #[serde(default)]
+    pub thread_id: Option<String>,
+
+    // wait for the message to be created and return it
+    #[serde(default)]
+    pub wait: bool,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub username: Option<String>,
+
+    #[ts(optional)]
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+
+    pub fields: OpCreateMessageFields,
+}
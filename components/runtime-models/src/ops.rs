@@ -1,15 +1,29 @@
 use crate::{
-    discord::guild::Guild,
+    discord::{
+        auto_moderation::AutoModerationRule, guild::Guild, role::Role, util::AuditLogExtras,
+    },
     internal::{
+        audit_log::{AuditLog, GetAuditLogFields},
+        auto_moderation::{CreateAutoModerationRuleFields, EditAutoModerationRuleFields},
         channel::{
             CreateForumThread, CreateThread, CreateThreadFromMessage, ForumThreadResponse,
             GuildChannel, ListThreadMembersRequest, ListThreadsRequest, ThreadMember,
             ThreadsListing, UpdateThread,
         },
+        emoji::{CreateEmojiFields, CustomEmoji, EditEmojiFields},
+        guild::EditGuildFields,
+        member::{Member, SearchMembersFields},
         messages::{
             Message, OpCreateChannelMessage, OpDeleteMessage, OpDeleteMessagesBulk,
-            OpEditChannelMessage, OpGetMessages,
+            OpEditChannelMessage, OpGetMessages, OpSendDirectMessage,
+        },
+        role::{CreateRoleFields, EditRoleFields, UpdateRolePosition},
+        scheduled_event::{
+            CreateScheduledEventFields, EditScheduledEventFields, GetScheduledEventUsersFields,
+            ScheduledEvent, ScheduledEventUser,
         },
+        sticker::{CreateStickerFields, EditStickerFields, Sticker},
+        webhook::{CreateWebhookFields, ExecuteWebhookFields, Webhook},
     },
 };
 
@@ -95,6 +109,9 @@ macro_rules! ops_async {
 
 ops_async! {
     discord_get_guild(()) => Guild,
+    discord_edit_guild(EditGuildFields) => (),
+
+    discord_search_members(SearchMembersFields) => Vec<Member>,
 
     // channel_id, message_id
     discord_get_message((String, String)) => Message,
@@ -105,6 +122,7 @@ ops_async! {
     discord_crosspost_message((String,String)) => (),
     discord_delete_message(OpDeleteMessage) => (),
     discord_bulk_delete_messages(OpDeleteMessagesBulk) => (),
+    discord_send_direct_message(OpSendDirectMessage) => Message,
 
     discord_start_thread_from_message(CreateThreadFromMessage) => GuildChannel,
     discord_start_thread_without_message(CreateThread) => GuildChannel,
@@ -116,5 +134,53 @@ ops_async! {
     discord_list_active_threads(()) => ThreadsListing,
     discord_list_public_archived_threads(ListThreadsRequest) => ThreadsListing,
     discord_list_private_archived_threads(ListThreadsRequest) => ThreadsListing,
-    discord_edit_thread(UpdateThread) => GuildChannel
+    discord_edit_thread(UpdateThread) => GuildChannel,
+
+    discord_create_role(CreateRoleFields) => Role,
+    discord_edit_role(EditRoleFields) => Role,
+    // role_id
+    discord_delete_role((String, AuditLogExtras)) => (),
+    discord_update_role_positions(Vec<UpdateRolePosition>) => Vec<Role>,
+
+    discord_get_emojis(()) => Vec<CustomEmoji>,
+    // emoji_id
+    discord_get_emoji(String) => CustomEmoji,
+    discord_create_emoji(CreateEmojiFields) => CustomEmoji,
+    discord_edit_emoji(EditEmojiFields) => CustomEmoji,
+    // emoji_id
+    discord_delete_emoji((String, AuditLogExtras)) => (),
+
+    discord_get_stickers(()) => Vec<Sticker>,
+    // sticker_id
+    discord_get_sticker(String) => Sticker,
+    discord_create_sticker(CreateStickerFields) => Sticker,
+    discord_edit_sticker(EditStickerFields) => Sticker,
+    // sticker_id
+    discord_delete_sticker((String, AuditLogExtras)) => (),
+
+    discord_create_webhook(CreateWebhookFields) => Webhook,
+    // channel_id
+    discord_get_channel_webhooks(String) => Vec<Webhook>,
+    // webhook_id
+    discord_delete_webhook((String, AuditLogExtras)) => (),
+    discord_execute_webhook(ExecuteWebhookFields) => Option<Message>,
+
+    discord_get_audit_log(GetAuditLogFields) => AuditLog,
+
+    discord_get_scheduled_events(()) => Vec<ScheduledEvent>,
+    // event_id
+    discord_get_scheduled_event(String) => ScheduledEvent,
+    discord_create_scheduled_event(CreateScheduledEventFields) => ScheduledEvent,
+    discord_edit_scheduled_event(EditScheduledEventFields) => ScheduledEvent,
+    // event_id
+    discord_delete_scheduled_event(String) => (),
+    discord_get_scheduled_event_users(GetScheduledEventUsersFields) => Vec<ScheduledEventUser>,
+
+    discord_get_auto_moderation_rules(()) => Vec<AutoModerationRule>,
+    // rule_id
+    discord_get_auto_moderation_rule(String) => AutoModerationRule,
+    discord_create_auto_moderation_rule(CreateAutoModerationRuleFields) => AutoModerationRule,
+    discord_edit_auto_moderation_rule(EditAutoModerationRuleFields) => AutoModerationRule,
+    // rule_id
+    discord_delete_auto_moderation_rule((String, AuditLogExtras)) => ()
 }
@@ -115,3 +115,48 @@ impl From<PluginId> for u64 {
         value.0
     }
 }
+
+/// Binary data, passed to and from scripts as a Uint8Array
+///
+/// Only works with serde_v8, it can't be serialized to other formats
+#[derive(Debug, Clone, Default)]
+pub struct JsBytes(pub Vec<u8>);
+
+impl ts_rs::TS for JsBytes {
+    const EXPORT_TO: Option<&'static str> = None;
+    fn decl() -> String {
+        "type JsBytes = Uint8Array;".to_owned()
+    }
+
+    fn name() -> String {
+        "Uint8Array".to_owned()
+    }
+    fn inline() -> String {
+        "Uint8Array".to_string()
+    }
+
+    fn dependencies() -> Vec<ts_rs::Dependency> {
+        vec![]
+    }
+    fn transparent() -> bool {
+        false
+    }
+}
+
+impl Serialize for JsBytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_v8::ToJsBuffer::from(self.0.clone()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JsBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde_v8::JsBuffer::deserialize(deserializer).map(|buf| JsBytes(buf.to_vec()))
+    }
+}
@@ -1,23 +1,43 @@
 use deno_core::{op2, OpState};
-use guild_logger::entry::CreateLogEntry;
+use guild_logger::entry::{CreateLogEntry, LineCol};
 use runtime_models::internal::console::ConsoleLogMessage;
 
 use crate::RuntimeContext;
 
-deno_core::extension!(bl_console, ops = [op_botloader_log,]);
+deno_core::extension!(
+    bl_console,
+    ops = [op_botloader_log, op_botloader_log_error,]
+);
 
 #[op2]
 pub fn op_botloader_log(state: &mut OpState, #[serde] args: ConsoleLogMessage) {
-    let (name, line_col) = if let (Some(orig_name), Some(line)) = (args.file_name, args.line_number)
-    {
-        let col = args.col_number.unwrap_or_default();
-        (orig_name, Some((line, col)))
-    } else {
-        (String::new(), None)
-    };
+    let (name, line_col) = log_location(args.file_name, args.line_number, args.col_number);
 
     let ctx = state.borrow::<RuntimeContext>();
 
     ctx.guild_logger
         .log(CreateLogEntry::script_console(args.message, name, line_col));
 }
+
+#[op2]
+pub fn op_botloader_log_error(state: &mut OpState, #[serde] args: ConsoleLogMessage) {
+    let (name, line_col) = log_location(args.file_name, args.line_number, args.col_number);
+
+    let ctx = state.borrow::<RuntimeContext>();
+
+    ctx.guild_logger
+        .log(CreateLogEntry::script_error(args.message, name, line_col));
+}
+
+fn log_location(
+    file_name: Option<String>,
+    line_number: Option<u32>,
+    col_number: Option<u32>,
+) -> (String, Option<LineCol>) {
+    if let (Some(orig_name), Some(line)) = (file_name, line_number) {
+        let col = col_number.unwrap_or_default();
+        (orig_name, Some((line, col)))
+    } else {
+        (String::new(), None)
+    }
+}
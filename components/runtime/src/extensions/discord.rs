@@ -5,32 +5,51 @@ use deno_core::{
     op2, OpState,
 };
 use futures::TryFutureExt;
+use guild_logger::entry::CreateLogEntry;
 use runtime_models::{
     discord::{
+        auto_moderation::{AutoModerationAction, AutoModerationRule, AutoModerationTriggerType},
         channel::{PermissionOverwrite, PermissionOverwriteType},
         guild::Guild,
         message::SendEmoji,
+        poll::{DiscordCreatePoll, DiscordMessagePoll},
+        role::Role,
+        scheduled_event::ScheduledEventEntityType,
         util::AuditLogExtras,
     },
     internal::{
+        audit_log::{AuditLog, GetAuditLogFields},
+        auto_moderation::{CreateAutoModerationRuleFields, EditAutoModerationRuleFields},
         channel::{
-            CreateChannel, CreateForumThread, CreateThread, CreateThreadFromMessage, EditChannel,
-            ForumThreadResponse, GuildChannel, ListThreadMembersRequest, ListThreadsRequest,
-            ThreadMember, ThreadsListing, UpdateThread,
+            ChannelRequestBody, CreateChannel, CreateForumThread, CreateThread,
+            CreateThreadFromMessage, EditChannel, ForumThreadResponse, GuildChannel,
+            ListThreadMembersRequest, ListThreadsRequest, ThreadMember, ThreadsListing,
+            UpdateThread,
         },
+        emoji::{CreateEmojiFields, CustomEmoji, EditEmojiFields},
         events::VoiceState,
+        guild::{EditGuildFields, GuildImage},
         interactions::InteractionCallback,
         invite::CreateInviteFields,
-        member::{Ban, UpdateGuildMemberFields},
+        member::{Ban, Member, SearchMembersFields, UpdateGuildMemberFields},
         messages::{
-            Message, OpCreateChannelMessage, OpCreateFollowUpMessage, OpDeleteMessage,
-            OpDeleteMessagesBulk, OpEditChannelMessage, OpGetMessages,
+            Message, OpCreateChannelMessage, OpCreateFollowUpMessage, OpCreateMessageAttachment,
+            OpCreateMessageFields, OpDeleteMessage, OpDeleteMessagesBulk, OpEditChannelMessage,
+            OpGetMessages, OpSendDirectMessage,
         },
         misc_op::{CreateBanFields, GetReactionsFields},
+        role::{CreateRoleFields, EditRoleFields, UpdateRolePosition},
+        scheduled_event::{
+            CreateScheduledEventFields, EditScheduledEventFields, GetScheduledEventUsersFields,
+            ScheduledEvent, ScheduledEventUser,
+        },
+        sticker::{CreateStickerFields, EditStickerFields, Sticker},
         user::User,
+        webhook::{CreateWebhookFields, ExecuteWebhookFields, Webhook},
     },
     ops::{handle_async_op, EasyOpsASync, EasyOpsHandlerASync},
 };
+use serde::Serialize;
 use std::{
     borrow::Cow,
     collections::VecDeque,
@@ -38,20 +57,33 @@ use std::{
     time::{Duration, Instant},
 };
 use std::{cell::RefCell, rc::Rc};
+use stores::config::GuildWebhook;
 use tracing::{info, warn};
 use twilight_http::error::ErrorType;
 use twilight_http::request::AuditLogReason;
 use twilight_http::{
     api_error::{ApiError, GeneralApiError},
     response::StatusCode,
+    routing::Route,
 };
 use twilight_model::id::marker::{
-    GenericMarker, InteractionMarker, MessageMarker, RoleMarker, TagMarker,
+    GenericMarker, GuildMarker, InteractionMarker, MessageMarker, RoleMarker, TagMarker,
+    WebhookMarker,
 };
 use twilight_model::id::Id;
 use twilight_model::{
-    guild::Permissions,
+    guild::{
+        auto_moderation::{
+            AutoModerationAction as TwilightAutoModerationAction,
+            AutoModerationActionMetadata as TwilightAutoModerationActionMetadata,
+            AutoModerationActionType as TwilightAutoModerationActionType,
+        },
+        scheduled_event::PrivacyLevel,
+        AfkTimeout, Permissions,
+    },
+    http::attachment::Attachment as TwilightAttachment,
     id::marker::{ChannelMarker, UserMarker},
+    util::Timestamp,
 };
 use vm::AnyError;
 
@@ -286,6 +318,161 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
         }
     }
 
+    async fn discord_edit_guild(&self, arg: EditGuildFields) -> Result<(), anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        check_bot_guild_permissions(&self.state, &rt_ctx, Permissions::MANAGE_GUILD).await?;
+
+        let icon = arg
+            .icon
+            .as_ref()
+            .map(|v| v.as_ref().map(guild_image_data_uri).transpose())
+            .transpose()?;
+        let banner = arg
+            .banner
+            .as_ref()
+            .map(|v| v.as_ref().map(guild_image_data_uri).transpose())
+            .transpose()?;
+
+        let system_channel = match &arg.system_channel_id {
+            Some(Some(id)) => Some(Some(
+                parse_get_guild_channel(&self.state, &rt_ctx, id).await?.id,
+            )),
+            Some(None) => Some(None),
+            None => None,
+        };
+
+        let afk_channel = match &arg.afk_channel_id {
+            Some(Some(id)) => Some(Some(
+                parse_get_guild_channel(&self.state, &rt_ctx, id).await?.id,
+            )),
+            Some(None) => Some(None),
+            None => None,
+        };
+
+        let afk_timeout = arg.afk_timeout_seconds.map(parse_afk_timeout).transpose()?;
+
+        let changes = describe_guild_changes(&arg);
+        let cloned_ctx = rt_ctx.clone();
+
+        discord_request_with_extra_error(&self.state, async move {
+            let mut req = cloned_ctx
+                .discord_config
+                .client
+                .update_guild(cloned_ctx.guild_id);
+
+            if let Some(name) = &arg.name {
+                req = req.name(name)?;
+            }
+
+            if let Some(icon) = &icon {
+                req = req.icon(icon.as_deref());
+            }
+
+            if let Some(banner) = &banner {
+                req = req.banner(banner.as_deref());
+            }
+
+            if let Some(channel_id) = system_channel {
+                req = req.system_channel(channel_id);
+            }
+
+            if let Some(channel_id) = afk_channel {
+                req = req.afk_channel_id(channel_id);
+            }
+
+            if let Some(timeout) = afk_timeout {
+                req = req.afk_timeout(timeout);
+            }
+
+            if let Some(level) = arg.verification_level {
+                req = req.verification_level(Some(level.into()));
+            }
+
+            if let Some(level) = arg.default_message_notifications {
+                req = req.default_message_notifications(Some(level.into()));
+            }
+
+            if let Some(reason) = &arg.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?;
+
+        for change in changes {
+            rt_ctx.guild_logger.log(CreateLogEntry::info(format!(
+                "script edited server settings: {change}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn discord_search_members(
+        &self,
+        arg: SearchMembersFields,
+    ) -> Result<Vec<Member>, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        if arg.query.is_empty() {
+            return Err(anyhow!("search query can't be empty"));
+        }
+
+        let limit = arg.limit.unwrap_or(25);
+        if !(1..=1000).contains(&limit) {
+            return Err(anyhow!("limit has to be between 1 and 1000"));
+        }
+
+        // the broker only knows about the cached members, so unless the cache is known
+        // to hold all of them we fill up the results from the api
+        let mut members = match rt_ctx
+            .bot_state
+            .search_guild_members(
+                rt_ctx.guild_id,
+                &arg.query,
+                limit,
+                arg.fuzzy.unwrap_or_default(),
+            )
+            .await
+        {
+            Ok(Some(resp)) if resp.complete || resp.members.len() >= limit as usize => {
+                return Ok(resp.members.into_iter().map(Into::into).collect())
+            }
+            Ok(Some(resp)) => resp.members,
+            Ok(None) => Vec::new(),
+            Err(err) => {
+                warn!(%err, "failed searching members through the broker");
+                Vec::new()
+            }
+        };
+
+        let api_members = discord_request_with_extra_error(&self.state, async move {
+            Ok(rt_ctx
+                .discord_config
+                .client
+                .search_guild_members(rt_ctx.guild_id, &arg.query)
+                .limit(limit as u16)?
+                .await)
+        })
+        .await?
+        .models()
+        .await?;
+
+        for member in api_members {
+            if members.len() >= limit as usize {
+                break;
+            }
+
+            if !members.iter().any(|m| m.user.id == member.user.id) {
+                members.push(member);
+            }
+        }
+
+        Ok(members.into_iter().map(Into::into).collect())
+    }
+
     async fn discord_get_message(
         &self,
         (channel_id_raw, message_id_raw): (String, String),
@@ -295,17 +482,16 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
         let channel = parse_get_guild_channel(&self.state, &rt_ctx, &channel_id_raw).await?;
         let message_id = parse_discord_id(&message_id_raw)?;
 
-        Ok(discord_request(&self.state, async move {
+        let res = discord_request(&self.state, async move {
             rt_ctx
                 .discord_config
                 .client
                 .message(channel.id, message_id)
                 .await
         })
-        .await?
-        .model()
-        .await?
-        .into())
+        .await?;
+
+        message_with_poll(res).await
     }
 
     async fn discord_get_messages(
@@ -351,9 +537,7 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
         })
         .await?;
 
-        let messages = res.model().await?;
-
-        Ok(messages.into_iter().map(Into::into).collect())
+        messages_with_polls(res).await
     }
 
     async fn discord_create_message(
@@ -364,45 +548,74 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
 
         let channel = parse_get_guild_channel(&self.state, &rt_ctx, &args.channel_id).await?;
 
-        Ok(discord_request_with_extra_error(&self.state, async move {
-            let maybe_embeds = args
-                .fields
-                .embeds
-                .unwrap_or_default()
-                .into_iter()
-                .map(Into::into)
-                .collect::<Vec<_>>();
+        match args.poll {
+            Some(poll) => {
+                create_poll_message(
+                    &self.state,
+                    &rt_ctx,
+                    channel.id,
+                    args.fields,
+                    poll.try_into()?,
+                )
+                .await
+            }
+            None => Ok(
+                create_message(&self.state, &rt_ctx, channel.id, args.fields)
+                    .await?
+                    .into(),
+            ),
+        }
+    }
 
-            let components = args
-                .fields
-                .components
-                .unwrap_or_default()
-                .into_iter()
-                .map(Into::into)
-                .collect::<Vec<_>>();
+    async fn discord_send_direct_message(
+        &self,
+        args: OpSendDirectMessage,
+    ) -> Result<Message, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
 
-            let mut mc = rt_ctx
-                .discord_config
-                .client
-                .create_message(channel.id)
-                .embeds(&maybe_embeds)?
-                .components(&components)?;
+        let conf = rt_ctx
+            .config_store
+            .get_guild_meta_config_or_default(rt_ctx.guild_id)
+            .await?;
+        if !conf.allow_direct_messages {
+            return Err(anyhow!(
+                "direct messages are disabled on this server, they can be enabled in the server \
+                 settings on the website"
+            ));
+        }
 
-            if let Some(content) = &args.fields.content {
-                mc = mc.content(content)?
-            }
+        let user_id: Id<UserMarker> = parse_discord_id(&args.user_id)?;
 
-            let mentions = args.fields.allowed_mentions.map(Into::into);
-            if mentions.is_some() {
-                mc = mc.allowed_mentions(mentions.as_ref());
+        // only members of this guild can be messaged
+        let member = fetch_members_through_api(&self.state, &rt_ctx, vec![Some(user_id)])
+            .await?
+            .pop()
+            .flatten();
+        match member {
+            Some(member) if member.user.bot => {
+                return Err(anyhow!("can't send direct messages to bots"));
             }
+            Some(_) => {}
+            None => return Err(not_found_error(format!("member `{user_id}` not found"))),
+        }
 
-            Ok(mc.await)
+        RateLimiters::discord_direct_messages(&self.state).await;
+
+        let cloned_discord = rt_ctx.discord_config.clone();
+        let channel = discord_request(&self.state, async move {
+            cloned_discord.client.create_private_channel(user_id).await
         })
         .await?
         .model()
-        .await?
-        .into())
+        .await?;
+
+        let message = create_message(&self.state, &rt_ctx, channel.id, args.fields).await?;
+
+        rt_ctx.guild_logger.log(CreateLogEntry::info(format!(
+            "script sent a direct message to user {user_id}"
+        )));
+
+        Ok(message.into())
     }
 
     async fn discord_edit_message(
@@ -413,6 +626,8 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
 
         let channel = parse_get_guild_channel(&self.state, &rt_ctx, &args.channel_id).await?;
         let message_id = parse_str_snowflake_id(&args.message_id)?;
+        let attachments =
+            convert_message_attachments(&self.state, args.fields.attachments.as_deref())?;
 
         let res = discord_request_with_extra_error(&self.state, async move {
             let maybe_embeds = args
@@ -436,6 +651,11 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
                 mc = mc.embeds(Some(embeds))?;
             }
 
+            // replaces the existing attachments on the message
+            if let Some(attachments) = &attachments {
+                mc = mc.attachments(attachments)?;
+            }
+
             let mentions = args.fields.allowed_mentions.map(Into::into);
             if mentions.is_some() {
                 mc = mc.allowed_mentions(mentions.as_ref());
@@ -577,6 +797,14 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
     ) -> Result<ForumThreadResponse, anyhow::Error> {
         let rt_ctx = get_rt_ctx(&self.state);
         let channel = parse_get_guild_channel(&self.state, &rt_ctx, &arg.channel_id).await?;
+        let attachments =
+            convert_message_attachments(&self.state, arg.message.attachments.as_deref())?;
+
+        let maybe_tags = arg
+            .tag_ids
+            .as_deref()
+            .map(|tag_ids| parse_applied_tags(&channel, tag_ids))
+            .transpose()?;
 
         let res = discord_request_with_extra_error(&self.state, async move {
             let mut req = rt_ctx
@@ -590,12 +818,6 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
                 )
             }
 
-            let maybe_tags: Option<Vec<Id<TagMarker>>> = arg.tag_ids.map(|v| {
-                v.into_iter()
-                    .filter_map(|string_id| parse_discord_id(&string_id).ok())
-                    .collect()
-            });
-
             if let Some(tags) = &maybe_tags {
                 req = req.applied_tags(tags);
             }
@@ -635,6 +857,10 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
                 req = req.content(content)?;
             }
 
+            if let Some(attachments) = &attachments {
+                req = req.attachments(attachments)?;
+            }
+
             let mentions = arg.message.allowed_mentions.map(Into::into);
             if mentions.is_some() {
                 req = req.allowed_mentions(mentions.as_ref());
@@ -757,108 +983,1593 @@ impl EasyOpsHandlerASync for EasyOpsHandler {
             })
             .transpose()?;
 
-        Ok(discord_request(&self.state, async move {
-            let mut threads_request = rt_ctx
-                .discord_config
-                .client
-                .public_archived_threads(channel.id);
+        Ok(discord_request(&self.state, async move {
+            let mut threads_request = rt_ctx
+                .discord_config
+                .client
+                .public_archived_threads(channel.id);
+
+            if let Some(before_str) = &before_str {
+                threads_request = threads_request.before(before_str);
+            }
+
+            threads_request.await
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_list_private_archived_threads(
+        &self,
+        arg: ListThreadsRequest,
+    ) -> Result<ThreadsListing, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+        let channel = parse_get_guild_channel(&self.state, &rt_ctx, &arg.channel_id).await?;
+
+        let before_str = arg
+            .before
+            .map(|v| {
+                chrono::Utc
+                    .timestamp_millis_opt(v.0 as i64)
+                    .single()
+                    .ok_or(anyhow!("bad 'before' timestamp"))
+                    .map(|ts| ts.to_rfc3339())
+            })
+            .transpose()?;
+
+        Ok(discord_request(&self.state, async move {
+            let mut threads_request = rt_ctx
+                .discord_config
+                .client
+                .private_archived_threads(channel.id);
+
+            if let Some(before_str) = &before_str {
+                threads_request = threads_request.before(before_str);
+            }
+
+            threads_request.await
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_edit_thread(&self, arg: UpdateThread) -> Result<GuildChannel, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+        let channel = parse_get_guild_channel(&self.state, &rt_ctx, &arg.channel_id).await?;
+
+        let maybe_tags = match (&arg.tag_ids, channel.parent_id) {
+            (Some(tag_ids), Some(parent_id)) => {
+                let parent = get_guild_channel(&self.state, &rt_ctx, parent_id).await?;
+                Some(parse_applied_tags(&parent, tag_ids)?)
+            }
+            (Some(_), None) => {
+                return Err(anyhow!(
+                    "tags can only be applied to threads in forum channels"
+                ))
+            }
+            (None, _) => None,
+        };
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx.discord_config.client.update_thread(channel.id);
+
+            if let Some(tags) = &maybe_tags {
+                req = req.applied_tags(Some(tags));
+            }
+
+            if let Some(archived) = arg.archived {
+                req = req.archived(archived);
+            }
+
+            if let Some(auto_archive_duration_minutes) = arg.auto_archive_duration_minutes {
+                req = req.auto_archive_duration(auto_archive_duration_minutes.into())
+            }
+
+            if let Some(invitable) = arg.invitable {
+                req = req.invitable(invitable)
+            }
+
+            if let Some(locked) = arg.locked {
+                req = req.locked(locked)
+            }
+
+            if let Some(name) = &arg.name {
+                req = req.name(name)?;
+            }
+
+            if let Some(rate_limit_per_user) = &arg.rate_limit_per_user {
+                req = req.rate_limit_per_user(*rate_limit_per_user)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_create_role(&self, arg: CreateRoleFields) -> Result<Role, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let permissions = arg
+            .permissions_raw
+            .as_deref()
+            .map(parse_permissions)
+            .transpose()?;
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx.discord_config.client.create_role(rt_ctx.guild_id);
+
+            if let Some(name) = &arg.name {
+                req = req.name(name);
+            }
+
+            if let Some(color) = arg.color {
+                req = req.color(color);
+            }
+
+            if let Some(hoist) = arg.hoist {
+                req = req.hoist(hoist);
+            }
+
+            if let Some(mentionable) = arg.mentionable {
+                req = req.mentionable(mentionable);
+            }
+
+            if let Some(permissions) = permissions {
+                req = req.permissions(permissions);
+            }
+
+            if let Some(unicode_emoji) = &arg.unicode_emoji {
+                req = req.unicode_emoji(unicode_emoji);
+            }
+
+            if let Some(reason) = &arg.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_edit_role(&self, arg: EditRoleFields) -> Result<Role, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let role_id: Id<RoleMarker> = parse_discord_id(&arg.role_id)?;
+        let bot_position = get_bot_highest_role_position(&self.state, &rt_ctx).await?;
+        check_manageable_role(&rt_ctx, role_id, bot_position).await?;
+
+        let permissions = arg
+            .permissions_raw
+            .as_deref()
+            .map(parse_permissions)
+            .transpose()?;
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .update_role(rt_ctx.guild_id, role_id);
+
+            if let Some(name) = &arg.name {
+                req = req.name(Some(name));
+            }
+
+            if let Some(color) = arg.color {
+                req = req.color(color);
+            }
+
+            if let Some(hoist) = arg.hoist {
+                req = req.hoist(hoist);
+            }
+
+            if let Some(mentionable) = arg.mentionable {
+                req = req.mentionable(mentionable);
+            }
+
+            if let Some(permissions) = permissions {
+                req = req.permissions(permissions);
+            }
+
+            if let Some(unicode_emoji) = &arg.unicode_emoji {
+                req = req.unicode_emoji(unicode_emoji.as_deref());
+            }
+
+            if let Some(reason) = &arg.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_delete_role(
+        &self,
+        (role_id_raw, extras): (String, AuditLogExtras),
+    ) -> Result<(), anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let role_id: Id<RoleMarker> = parse_discord_id(&role_id_raw)?;
+        let bot_position = get_bot_highest_role_position(&self.state, &rt_ctx).await?;
+        check_manageable_role(&rt_ctx, role_id, bot_position).await?;
+
+        discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .delete_role(rt_ctx.guild_id, role_id);
+
+            if let Some(reason) = &extras.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn discord_update_role_positions(
+        &self,
+        arg: Vec<UpdateRolePosition>,
+    ) -> Result<Vec<Role>, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let bot_position = get_bot_highest_role_position(&self.state, &rt_ctx).await?;
+
+        let mut positions = Vec::with_capacity(arg.len());
+        for item in arg {
+            let role_id: Id<RoleMarker> = parse_discord_id(&item.role_id)?;
+            check_manageable_role(&rt_ctx, role_id, bot_position).await?;
+
+            if item.position.0 as i64 >= bot_position {
+                return Err(role_hierarchy_error(format!(
+                    "can't move role `{role_id}` to position {}, the bot's highest role is at \
+                     position {bot_position}",
+                    item.position
+                )));
+            }
+
+            positions.push(twilight_model::guild::RolePosition {
+                id: role_id,
+                position: item.position.0,
+            });
+        }
+
+        Ok(discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .update_role_positions(rt_ctx.guild_id, &positions)
+                .await
+        })
+        .await?
+        .models()
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+    }
+
+    async fn discord_get_emojis(&self, _arg: ()) -> Result<Vec<CustomEmoji>, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        Ok(discord_request(&self.state, async move {
+            rt_ctx.discord_config.client.emojis(rt_ctx.guild_id).await
+        })
+        .await?
+        .models()
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+    }
+
+    async fn discord_get_emoji(&self, emoji_id_raw: String) -> Result<CustomEmoji, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let emoji_id = parse_discord_id(&emoji_id_raw)?;
+
+        Ok(discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .emoji(rt_ctx.guild_id, emoji_id)
+                .await
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_create_emoji(
+        &self,
+        arg: CreateEmojiFields,
+    ) -> Result<CustomEmoji, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        if !EMOJI_CONTENT_TYPES.contains(&arg.content_type.as_str()) {
+            return Err(anyhow!(
+                "unsupported emoji content type `{}`, expected one of: {}",
+                arg.content_type,
+                EMOJI_CONTENT_TYPES.join(", ")
+            ));
+        }
+
+        let roles = parse_role_ids(arg.roles.as_deref())?;
+        let image = format!(
+            "data:{};base64,{}",
+            arg.content_type,
+            base64::encode(&arg.image_data.0)
+        );
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let mut req =
+                rt_ctx
+                    .discord_config
+                    .client
+                    .create_emoji(rt_ctx.guild_id, &arg.name, &image);
+
+            if let Some(roles) = &roles {
+                req = req.roles(roles);
+            }
+
+            if let Some(reason) = &arg.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_edit_emoji(&self, arg: EditEmojiFields) -> Result<CustomEmoji, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let emoji_id = parse_discord_id(&arg.emoji_id)?;
+        let roles = parse_role_ids(arg.roles.as_deref())?;
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .update_emoji(rt_ctx.guild_id, emoji_id);
+
+            if let Some(name) = &arg.name {
+                req = req.name(name);
+            }
+
+            if let Some(roles) = &roles {
+                req = req.roles(roles);
+            }
+
+            if let Some(reason) = &arg.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_delete_emoji(
+        &self,
+        (emoji_id_raw, extras): (String, AuditLogExtras),
+    ) -> Result<(), anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let emoji_id = parse_discord_id(&emoji_id_raw)?;
+
+        discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .delete_emoji(rt_ctx.guild_id, emoji_id);
+
+            if let Some(reason) = &extras.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn discord_get_stickers(&self, _arg: ()) -> Result<Vec<Sticker>, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        Ok(discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .guild_stickers(rt_ctx.guild_id)
+                .await
+        })
+        .await?
+        .models()
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+    }
+
+    async fn discord_get_sticker(&self, sticker_id_raw: String) -> Result<Sticker, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let sticker_id = parse_discord_id(&sticker_id_raw)?;
+
+        Ok(discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .guild_sticker(rt_ctx.guild_id, sticker_id)
+                .await
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_create_sticker(
+        &self,
+        arg: CreateStickerFields,
+    ) -> Result<Sticker, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx.discord_config.client.create_guild_sticker(
+                rt_ctx.guild_id,
+                &arg.name,
+                &arg.description,
+                &arg.tags,
+                &arg.file_data.0,
+            )?;
+
+            if let Some(reason) = &arg.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_edit_sticker(&self, arg: EditStickerFields) -> Result<Sticker, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let sticker_id = parse_discord_id(&arg.sticker_id)?;
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .update_guild_sticker(rt_ctx.guild_id, sticker_id);
+
+            if let Some(name) = &arg.name {
+                req = req.name(name)?;
+            }
+
+            if let Some(description) = &arg.description {
+                req = req.description(description)?;
+            }
+
+            if let Some(tags) = &arg.tags {
+                req = req.tags(tags)?;
+            }
+
+            if let Some(reason) = &arg.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_delete_sticker(
+        &self,
+        (sticker_id_raw, extras): (String, AuditLogExtras),
+    ) -> Result<(), anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let sticker_id = parse_discord_id(&sticker_id_raw)?;
+
+        discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .delete_guild_sticker(rt_ctx.guild_id, sticker_id);
+
+            if let Some(reason) = &extras.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn discord_create_webhook(
+        &self,
+        args: CreateWebhookFields,
+    ) -> Result<Webhook, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let channel = parse_get_guild_channel(&self.state, &rt_ctx, &args.channel_id).await?;
+
+        let cloned_discord = rt_ctx.discord_config.clone();
+        let webhook = discord_request_with_extra_error(&self.state, async move {
+            let mut req = cloned_discord
+                .client
+                .create_webhook(channel.id, &args.name)?;
+
+            if let Some(reason) = &args.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?;
+
+        if let Some(token) = &webhook.token {
+            rt_ctx
+                .config_store
+                .set_guild_webhook(GuildWebhook {
+                    guild_id: rt_ctx.guild_id,
+                    webhook_id: webhook.id,
+                    channel_id: webhook.channel_id,
+                    token: token.clone(),
+                })
+                .await?;
+        }
+
+        Ok(webhook.into())
+    }
+
+    async fn discord_get_channel_webhooks(
+        &self,
+        channel_id: String,
+    ) -> Result<Vec<Webhook>, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let channel = parse_get_guild_channel(&self.state, &rt_ctx, &channel_id).await?;
+
+        let webhooks = discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .channel_webhooks(channel.id)
+                .await
+        })
+        .await?
+        .models()
+        .await?;
+
+        Ok(webhooks.into_iter().map(Into::into).collect())
+    }
+
+    async fn discord_delete_webhook(
+        &self,
+        (webhook_id_raw, extras): (String, AuditLogExtras),
+    ) -> Result<(), anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let webhook_id = parse_discord_id(&webhook_id_raw)?;
+        let webhook = get_guild_webhook(&rt_ctx, webhook_id).await?;
+
+        let cloned_discord = rt_ctx.discord_config.clone();
+        discord_request_with_extra_error(&self.state, async move {
+            let mut req = cloned_discord
+                .client
+                .delete_webhook(webhook_id)
+                .token(&webhook.token);
+
+            if let Some(reason) = &extras.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?;
+
+        rt_ctx
+            .config_store
+            .del_guild_webhook(rt_ctx.guild_id, webhook_id)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn discord_execute_webhook(
+        &self,
+        args: ExecuteWebhookFields,
+    ) -> Result<Option<Message>, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let webhook_id = parse_discord_id(&args.webhook_id)?;
+        let webhook = get_guild_webhook(&rt_ctx, webhook_id).await?;
+
+        let thread_id = if let Some(thread_id) = &args.thread_id {
+            let thread = parse_get_guild_channel(&self.state, &rt_ctx, thread_id).await?;
+            if thread.parent_id != Some(webhook.channel_id) {
+                return Err(anyhow!("thread is not in the webhook's channel"));
+            }
+
+            Some(thread.id)
+        } else {
+            None
+        };
+
+        let attachments =
+            convert_message_attachments(&self.state, args.fields.attachments.as_deref())?;
+
+        let resp = discord_request_with_extra_error(&self.state, async move {
+            let embeds = args
+                .fields
+                .embeds
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<_>>();
+
+            let components = args
+                .fields
+                .components
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<_>>();
+
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .execute_webhook(webhook_id, &webhook.token)
+                .embeds(&embeds)?
+                .components(&components)?;
+
+            if let Some(content) = &args.fields.content {
+                req = req.content(content)?;
+            }
+
+            if let Some(attachments) = &attachments {
+                req = req.attachments(attachments)?;
+            }
+
+            if let Some(username) = &args.username {
+                req = req.username(username)?;
+            }
+
+            if let Some(avatar_url) = &args.avatar_url {
+                req = req.avatar_url(avatar_url);
+            }
+
+            if let Some(thread_id) = thread_id {
+                req = req.thread_id(thread_id);
+            }
+
+            let mentions = args.fields.allowed_mentions.map(Into::into);
+            if mentions.is_some() {
+                req = req.allowed_mentions(mentions.as_ref());
+            }
+
+            if args.wait {
+                Ok(req.wait().await.map(Some))
+            } else {
+                Ok(req.await.map(|_| None))
+            }
+        })
+        .await?;
+
+        match resp {
+            Some(resp) => Ok(Some(resp.model().await?.into())),
+            None => Ok(None),
+        }
+    }
+
+    async fn discord_get_scheduled_events(
+        &self,
+        _args: (),
+    ) -> Result<Vec<ScheduledEvent>, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let events = discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .guild_scheduled_events(rt_ctx.guild_id)
+                .with_user_count(true)
+                .await
+        })
+        .await?
+        .models()
+        .await?;
+
+        Ok(events.into_iter().map(Into::into).collect())
+    }
+
+    async fn discord_get_scheduled_event(
+        &self,
+        event_id_raw: String,
+    ) -> Result<ScheduledEvent, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let event_id = parse_discord_id(&event_id_raw)?;
+
+        Ok(discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .guild_scheduled_event(rt_ctx.guild_id, event_id)
+                .with_user_count(true)
+                .await
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_create_scheduled_event(
+        &self,
+        args: CreateScheduledEventFields,
+    ) -> Result<ScheduledEvent, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let channel_id = match args.entity_type {
+            ScheduledEventEntityType::External => None,
+            ScheduledEventEntityType::Voice | ScheduledEventEntityType::StageInstance => {
+                let Some(channel_id) = &args.channel_id else {
+                    return Err(anyhow!(
+                        "channelId is required for voice and stage instance events"
+                    ));
+                };
+
+                Some(
+                    parse_get_guild_channel(&self.state, &rt_ctx, channel_id)
+                        .await?
+                        .id,
+                )
+            }
+            ScheduledEventEntityType::Unknown(_) => {
+                return Err(anyhow!("unknown scheduled event entity type"));
+            }
+        };
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let start = Timestamp::from_micros(args.scheduled_start_time.0 as i64 * 1000)?;
+            let end = args
+                .scheduled_end_time
+                .map(|v| Timestamp::from_micros(v.0 as i64 * 1000))
+                .transpose()?;
+
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .create_guild_scheduled_event(rt_ctx.guild_id, PrivacyLevel::GuildOnly);
+
+            if let Some(reason) = &args.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            match (args.entity_type, channel_id) {
+                (ScheduledEventEntityType::Voice, Some(channel_id)) => {
+                    let mut req = req.voice(channel_id, &args.name, &start)?;
+                    if let Some(end) = &end {
+                        req = req.scheduled_end_time(end);
+                    }
+
+                    if let Some(description) = &args.description {
+                        req = req.description(description)?;
+                    }
+
+                    Ok(req.await)
+                }
+                (ScheduledEventEntityType::StageInstance, Some(channel_id)) => {
+                    let mut req = req.stage_instance(channel_id, &args.name, &start)?;
+                    if let Some(end) = &end {
+                        req = req.scheduled_end_time(end);
+                    }
+
+                    if let Some(description) = &args.description {
+                        req = req.description(description)?;
+                    }
+
+                    Ok(req.await)
+                }
+                _ => {
+                    let (Some(location), Some(end)) = (&args.location, &end) else {
+                        return Err(anyhow!(
+                            "location and scheduledEndTime are required for external events"
+                        ));
+                    };
+
+                    let mut req = req.external(&args.name, location, &start, end)?;
+                    if let Some(description) = &args.description {
+                        req = req.description(description)?;
+                    }
+
+                    Ok(req.await)
+                }
+            }
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_edit_scheduled_event(
+        &self,
+        args: EditScheduledEventFields,
+    ) -> Result<ScheduledEvent, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let event_id = parse_discord_id(&args.event_id)?;
+        let channel_id = if let Some(channel_id) = &args.channel_id {
+            Some(
+                parse_get_guild_channel(&self.state, &rt_ctx, channel_id)
+                    .await?
+                    .id,
+            )
+        } else {
+            None
+        };
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let start = args
+                .scheduled_start_time
+                .map(|v| Timestamp::from_micros(v.0 as i64 * 1000))
+                .transpose()?;
+            let end = args
+                .scheduled_end_time
+                .map(|v| Timestamp::from_micros(v.0 as i64 * 1000))
+                .transpose()?;
+
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .update_guild_scheduled_event(rt_ctx.guild_id, event_id);
+
+            if let Some(name) = &args.name {
+                req = req.name(name)?;
+            }
+
+            if let Some(description) = &args.description {
+                req = req.description(Some(description))?;
+            }
+
+            if let Some(entity_type) = args.entity_type {
+                req = req.entity_type(entity_type.into());
+            }
+
+            if let Some(channel_id) = channel_id {
+                req = req.channel_id(channel_id);
+            }
+
+            if let Some(location) = &args.location {
+                req = req.location(Some(location));
+            }
+
+            if let Some(start) = &start {
+                req = req.scheduled_start_time(start);
+            }
+
+            if let Some(end) = &end {
+                req = req.scheduled_end_time(Some(end));
+            }
+
+            if let Some(status) = args.status {
+                req = req.status(status.into());
+            }
+
+            if let Some(reason) = &args.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_delete_scheduled_event(
+        &self,
+        event_id_raw: String,
+    ) -> Result<(), anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let event_id = parse_discord_id(&event_id_raw)?;
+
+        discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .delete_guild_scheduled_event(rt_ctx.guild_id, event_id)
+                .await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn discord_get_scheduled_event_users(
+        &self,
+        args: GetScheduledEventUsersFields,
+    ) -> Result<Vec<ScheduledEventUser>, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let event_id = parse_discord_id(&args.event_id)?;
+        let after = args
+            .after
+            .as_deref()
+            .map(parse_discord_id::<UserMarker>)
+            .transpose()?;
+        let before = args
+            .before
+            .as_deref()
+            .map(parse_discord_id::<UserMarker>)
+            .transpose()?;
+
+        let users = discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .guild_scheduled_event_users(rt_ctx.guild_id, event_id)
+                .with_member(true);
+
+            if let Some(after) = after {
+                req = req.after(after);
+            }
+
+            if let Some(before) = before {
+                req = req.before(before);
+            }
+
+            if let Some(limit) = args.limit {
+                req = req.limit(limit)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .models()
+        .await?;
+
+        Ok(users.into_iter().map(Into::into).collect())
+    }
+
+    async fn discord_get_auto_moderation_rules(
+        &self,
+        _args: (),
+    ) -> Result<Vec<AutoModerationRule>, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let rules = discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .auto_moderation_rules(rt_ctx.guild_id)
+                .await
+        })
+        .await?
+        .models()
+        .await?;
+
+        Ok(rules.into_iter().map(Into::into).collect())
+    }
+
+    async fn discord_get_auto_moderation_rule(
+        &self,
+        rule_id_raw: String,
+    ) -> Result<AutoModerationRule, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let rule_id = parse_discord_id(&rule_id_raw)?;
+
+        Ok(discord_request(&self.state, async move {
+            rt_ctx
+                .discord_config
+                .client
+                .auto_moderation_rule(rt_ctx.guild_id, rule_id)
+                .await
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_create_auto_moderation_rule(
+        &self,
+        args: CreateAutoModerationRuleFields,
+    ) -> Result<AutoModerationRule, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let actions = parse_auto_moderation_actions(&self.state, &rt_ctx, args.actions).await?;
+        let exempt_roles = parse_role_ids(args.exempt_roles.as_deref())?;
+        let exempt_channels = parse_channel_ids(args.exempt_channels.as_deref())?;
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx.discord_config.client.create_auto_moderation_rule(
+                rt_ctx.guild_id,
+                &args.name,
+                args.event_type.into(),
+            );
+
+            for action in &actions {
+                let metadata = action.metadata.as_ref();
+                req = match action.kind {
+                    TwilightAutoModerationActionType::BlockMessage => {
+                        match metadata.and_then(|v| v.custom_message.as_deref()) {
+                            Some(message) => req.action_block_message_with_explanation(message),
+                            None => req.action_block_message(),
+                        }
+                    }
+                    TwilightAutoModerationActionType::SendAlertMessage => {
+                        let Some(channel_id) = metadata.and_then(|v| v.channel_id) else {
+                            return Err(anyhow!("SendAlertMessage actions require a channelId"));
+                        };
+                        req.action_send_alert_message(channel_id)
+                    }
+                    TwilightAutoModerationActionType::Timeout => {
+                        let Some(duration) = metadata.and_then(|v| v.duration_seconds) else {
+                            return Err(anyhow!("Timeout actions require durationSeconds"));
+                        };
+                        req.action_timeout(duration)
+                    }
+                    _ => return Err(anyhow!("unsupported auto moderation action type")),
+                };
+            }
+
+            if let Some(enabled) = args.enabled {
+                req = req.enabled(enabled);
+            }
+
+            if let Some(exempt_roles) = &exempt_roles {
+                req = req.exempt_roles(exempt_roles);
+            }
+
+            if let Some(exempt_channels) = &exempt_channels {
+                req = req.exempt_channels(exempt_channels);
+            }
+
+            if let Some(reason) = &args.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            let metadata = args.trigger_metadata.unwrap_or_default();
+            let keyword_filter = str_slice(&metadata.keyword_filter);
+            let regex_patterns = str_slice(&metadata.regex_patterns);
+            let allow_list = str_slice(&metadata.allow_list);
+            let presets = metadata
+                .presets
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<_>>();
+
+            match args.trigger_type {
+                AutoModerationTriggerType::Keyword => Ok(req
+                    .with_keyword(&keyword_filter, &regex_patterns, &allow_list)
+                    .await),
+                AutoModerationTriggerType::KeywordPreset => {
+                    Ok(req.with_keyword_preset(&presets, &allow_list).await)
+                }
+                AutoModerationTriggerType::MentionSpam => {
+                    let Some(limit) = metadata.mention_total_limit else {
+                        return Err(anyhow!("MentionSpam rules require mentionTotalLimit"));
+                    };
+                    Ok(req.with_mention_spam(limit).await)
+                }
+                AutoModerationTriggerType::Spam => Ok(req.with_spam().await),
+                _ => Err(anyhow!("unsupported auto moderation trigger type")),
+            }
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_edit_auto_moderation_rule(
+        &self,
+        args: EditAutoModerationRuleFields,
+    ) -> Result<AutoModerationRule, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let rule_id = parse_discord_id(&args.rule_id)?;
+        let actions = if let Some(actions) = args.actions {
+            Some(parse_auto_moderation_actions(&self.state, &rt_ctx, actions).await?)
+        } else {
+            None
+        };
+        let exempt_roles = parse_role_ids(args.exempt_roles.as_deref())?;
+        let exempt_channels = parse_channel_ids(args.exempt_channels.as_deref())?;
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let trigger_metadata = args.trigger_metadata.map(Into::into);
+
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .update_auto_moderation_rule(rt_ctx.guild_id, rule_id);
+
+            if let Some(name) = &args.name {
+                req = req.name(name);
+            }
+
+            if let Some(event_type) = args.event_type {
+                req = req.event_type(event_type.into());
+            }
+
+            if let Some(trigger_metadata) = &trigger_metadata {
+                req = req.trigger_metadata(trigger_metadata);
+            }
+
+            if let Some(actions) = &actions {
+                req = req.actions(actions);
+            }
+
+            if let Some(enabled) = args.enabled {
+                req = req.enabled(enabled);
+            }
+
+            if let Some(exempt_roles) = &exempt_roles {
+                req = req.exempt_roles(exempt_roles);
+            }
+
+            if let Some(exempt_channels) = &exempt_channels {
+                req = req.exempt_channels(exempt_channels);
+            }
+
+            if let Some(reason) = &args.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+
+    async fn discord_delete_auto_moderation_rule(
+        &self,
+        (rule_id_raw, extras): (String, AuditLogExtras),
+    ) -> Result<(), anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let rule_id = parse_discord_id(&rule_id_raw)?;
+
+        discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx
+                .discord_config
+                .client
+                .delete_auto_moderation_rule(rt_ctx.guild_id, rule_id);
+
+            if let Some(reason) = &extras.audit_log_reason {
+                req = req.reason(reason)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn discord_get_audit_log(
+        &self,
+        args: GetAuditLogFields,
+    ) -> Result<AuditLog, anyhow::Error> {
+        let rt_ctx = get_rt_ctx(&self.state);
+
+        let user_id = args
+            .user_id
+            .as_deref()
+            .map(parse_discord_id::<UserMarker>)
+            .transpose()?;
+        let before = args
+            .before
+            .as_deref()
+            .map(parse_discord_id::<GenericMarker>)
+            .transpose()?;
+        let after = args
+            .after
+            .as_deref()
+            .map(parse_discord_id::<GenericMarker>)
+            .transpose()?;
+
+        Ok(discord_request_with_extra_error(&self.state, async move {
+            let mut req = rt_ctx.discord_config.client.audit_log(rt_ctx.guild_id);
+
+            if let Some(user_id) = user_id {
+                req = req.user_id(user_id);
+            }
+
+            if let Some(action_type) = args.action_type {
+                req = req.action_type(action_type.into());
+            }
+
+            if let Some(before) = before {
+                req = req.before(before.get());
+            }
+
+            if let Some(after) = after {
+                req = req.after(after.get());
+            }
+
+            if let Some(limit) = args.limit {
+                req = req.limit(limit)?;
+            }
+
+            Ok(req.await)
+        })
+        .await?
+        .model()
+        .await?
+        .into())
+    }
+}
+
+// Resolves a webhook created through the script api on the current guild
+//
+// Only those have their token stored, other webhooks on the guild could belong to
+// anything and are off limits to scripts
+async fn get_guild_webhook(
+    rt_ctx: &RuntimeContext,
+    webhook_id: Id<WebhookMarker>,
+) -> Result<GuildWebhook, AnyError> {
+    rt_ctx
+        .config_store
+        .get_guild_webhook(rt_ctx.guild_id, webhook_id)
+        .await?
+        .ok_or_else(|| {
+            not_found_error(format!(
+                "webhook `{webhook_id}` not found, only webhooks created by scripts on this server \
+                 can be used"
+            ))
+        })
+}
+
+const EMOJI_CONTENT_TYPES: &[&str] = &["image/png", "image/jpeg", "image/gif", "image/webp"];
+
+fn guild_image_data_uri(image: &GuildImage) -> Result<String, AnyError> {
+    if !EMOJI_CONTENT_TYPES.contains(&image.content_type.as_str()) {
+        return Err(anyhow!(
+            "unsupported image content type `{}`, expected one of: {}",
+            image.content_type,
+            EMOJI_CONTENT_TYPES.join(", ")
+        ));
+    }
+
+    Ok(format!(
+        "data:{};base64,{}",
+        image.content_type,
+        base64::encode(&image.image_data.0)
+    ))
+}
+
+fn parse_afk_timeout(seconds: u16) -> Result<AfkTimeout, AnyError> {
+    match seconds {
+        60 | 300 | 900 | 1800 | 3600 => Ok(AfkTimeout::from(seconds)),
+        _ => Err(anyhow!(
+            "invalid afk timeout {seconds}, expected one of: 60, 300, 900, 1800, 3600"
+        )),
+    }
+}
+
+// human readable descriptions of the changes, used for the guild log
+fn describe_guild_changes(arg: &EditGuildFields) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    if let Some(name) = &arg.name {
+        changes.push(format!("name changed to `{name}`"));
+    }
+
+    match &arg.icon {
+        Some(Some(_)) => changes.push("icon changed".to_string()),
+        Some(None) => changes.push("icon removed".to_string()),
+        None => {}
+    }
+
+    match &arg.banner {
+        Some(Some(_)) => changes.push("banner changed".to_string()),
+        Some(None) => changes.push("banner removed".to_string()),
+        None => {}
+    }
+
+    match &arg.system_channel_id {
+        Some(Some(id)) => changes.push(format!("system channel changed to <#{id}>")),
+        Some(None) => changes.push("system channel removed".to_string()),
+        None => {}
+    }
+
+    match &arg.afk_channel_id {
+        Some(Some(id)) => changes.push(format!("afk channel changed to <#{id}>")),
+        Some(None) => changes.push("afk channel removed".to_string()),
+        None => {}
+    }
+
+    if let Some(timeout) = arg.afk_timeout_seconds {
+        changes.push(format!("afk timeout changed to {timeout} seconds"));
+    }
+
+    if let Some(level) = arg.verification_level {
+        changes.push(format!("verification level changed to {level:?}"));
+    }
+
+    if let Some(level) = arg.default_message_notifications {
+        changes.push(format!(
+            "default message notifications changed to {level:?}"
+        ));
+    }
+
+    changes
+}
+
+// checks the guild level permissions of the bot, so that we can give a proper error
+// instead of sending a request discord will reject anyway
+async fn check_bot_guild_permissions(
+    state: &Rc<RefCell<OpState>>,
+    rt_ctx: &RuntimeContext,
+    required: Permissions,
+) -> Result<(), AnyError> {
+    let bot_user_id = rt_ctx.discord_config.bot_user.id;
+    let perms = MemberRoles::fetch(state, rt_ctx, bot_user_id, None)
+        .await?
+        .calculator()
+        .root();
+
+    if !perms.contains(required) {
+        return Err(custom_error(
+            "DiscordPermissionsError",
+            format!(
+                "the bot is missing the following permissions: {:?}",
+                required - perms
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn parse_role_ids(raw: Option<&[String]>) -> Result<Option<Vec<Id<RoleMarker>>>, AnyError> {
+    raw.map(|ids| ids.iter().map(|id| parse_discord_id(id)).collect())
+        .transpose()
+}
+
+fn convert_message_attachments(
+    state: &Rc<RefCell<OpState>>,
+    attachments: Option<&[OpCreateMessageAttachment]>,
+) -> Result<Option<Vec<TwilightAttachment>>, AnyError> {
+    let Some(attachments) = attachments else {
+        return Ok(None);
+    };
+
+    let limit_size = crate::limits::message_attachments_size(state);
+    let mut total_size = 0;
+
+    let mut result = Vec::with_capacity(attachments.len());
+    for (i, attachment) in attachments.iter().enumerate() {
+        total_size += attachment.data.0.len() as u64;
+        if total_size > limit_size {
+            return Err(anyhow!(
+                "attachments cannot be over {limit_size}bytes in total on your guild's plan"
+            ));
+        }
 
-            if let Some(before_str) = &before_str {
-                threads_request = threads_request.before(before_str);
-            }
+        let mut decoded = TwilightAttachment::from_bytes(
+            attachment.filename.clone(),
+            attachment.data.0.clone(),
+            i as u64,
+        );
+        if let Some(description) = &attachment.description {
+            decoded.description(description.clone());
+        }
 
-            threads_request.await
-        })
-        .await?
-        .model()
-        .await?
-        .into())
+        result.push(decoded);
     }
 
-    async fn discord_list_private_archived_threads(
-        &self,
-        arg: ListThreadsRequest,
-    ) -> Result<ThreadsListing, anyhow::Error> {
-        let rt_ctx = get_rt_ctx(&self.state);
-        let channel = parse_get_guild_channel(&self.state, &rt_ctx, &arg.channel_id).await?;
+    Ok(Some(result))
+}
 
-        let before_str = arg
-            .before
-            .map(|v| {
-                chrono::Utc
-                    .timestamp_millis_opt(v.0 as i64)
-                    .single()
-                    .ok_or(anyhow!("bad 'before' timestamp"))
-                    .map(|ts| ts.to_rfc3339())
-            })
-            .transpose()?;
+fn parse_channel_ids(raw: Option<&[String]>) -> Result<Option<Vec<Id<ChannelMarker>>>, AnyError> {
+    raw.map(|ids| ids.iter().map(|id| parse_discord_id(id)).collect())
+        .transpose()
+}
 
-        Ok(discord_request(&self.state, async move {
-            let mut threads_request = rt_ctx
-                .discord_config
-                .client
-                .private_archived_threads(channel.id);
+fn str_slice(v: &Option<Vec<String>>) -> Vec<&str> {
+    v.iter().flatten().map(String::as_str).collect()
+}
 
-            if let Some(before_str) = &before_str {
-                threads_request = threads_request.before(before_str);
-            }
+// converts the actions to their twilight counterparts, making sure alert channels belong to this guild
+async fn parse_auto_moderation_actions(
+    state: &Rc<RefCell<OpState>>,
+    rt_ctx: &RuntimeContext,
+    actions: Vec<AutoModerationAction>,
+) -> Result<Vec<TwilightAutoModerationAction>, AnyError> {
+    let mut result = Vec::with_capacity(actions.len());
+    for action in actions {
+        let metadata = if let Some(metadata) = action.metadata {
+            let channel_id = if let Some(channel_id) = &metadata.channel_id {
+                Some(parse_get_guild_channel(state, rt_ctx, channel_id).await?.id)
+            } else {
+                None
+            };
 
-            threads_request.await
-        })
-        .await?
-        .model()
-        .await?
-        .into())
+            Some(TwilightAutoModerationActionMetadata {
+                channel_id,
+                custom_message: metadata.custom_message,
+                duration_seconds: metadata.duration_seconds,
+            })
+        } else {
+            None
+        };
+
+        result.push(TwilightAutoModerationAction {
+            kind: action.kind.into(),
+            metadata,
+        });
     }
 
-    async fn discord_edit_thread(&self, arg: UpdateThread) -> Result<GuildChannel, anyhow::Error> {
-        let rt_ctx = get_rt_ctx(&self.state);
-        let channel = parse_get_guild_channel(&self.state, &rt_ctx, &arg.channel_id).await?;
+    Ok(result)
+}
 
-        Ok(discord_request_with_extra_error(&self.state, async move {
-            let mut req = rt_ctx.discord_config.client.update_thread(channel.id);
+fn parse_permissions(raw: &str) -> Result<Permissions, AnyError> {
+    raw.parse()
+        .map(Permissions::from_bits_truncate)
+        .map_err(|_| anyhow!("invalid permissions: `{raw}`"))
+}
 
-            let maybe_tags: Option<Vec<Id<TagMarker>>> = arg.tag_ids.map(|v| {
-                v.into_iter()
-                    .filter_map(|string_id| parse_discord_id(&string_id).ok())
-                    .collect()
-            });
+// returns the position of the highest role the bot has, roles at or above this
+// position can't be managed by the bot
+async fn get_bot_highest_role_position(
+    state: &Rc<RefCell<OpState>>,
+    rt_ctx: &RuntimeContext,
+) -> Result<i64, AnyError> {
+    let bot_user_id = rt_ctx.discord_config.bot_user.id;
+    Ok(MemberRoles::fetch(state, rt_ctx, bot_user_id, None)
+        .await?
+        .highest_position)
+}
 
-            if let Some(tags) = &maybe_tags {
-                req = req.applied_tags(Some(tags));
-            }
+// a member's roles resolved against the roles of the guild, shared by the permission
+// calculations and the role hierarchy checks
+struct MemberRoles {
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    owner_id: Id<UserMarker>,
+    everyone_permissions: Permissions,
+    role_permissions: Vec<(Id<RoleMarker>, Permissions)>,
+    highest_position: i64,
+}
 
-            if let Some(archived) = arg.archived {
-                req = req.archived(archived);
-            }
+impl MemberRoles {
+    // uses the provided role ids if any, otherwise the member is fetched to find them
+    async fn fetch(
+        state: &Rc<RefCell<OpState>>,
+        rt_ctx: &RuntimeContext,
+        user_id: Id<UserMarker>,
+        roles: Option<Vec<Id<RoleMarker>>>,
+    ) -> Result<Self, AnyError> {
+        let guild_id = rt_ctx.guild_id;
+
+        let member_roles = if let Some(roles) = roles {
+            roles
+        } else {
+            let cloned_discord = rt_ctx.discord_config.clone();
+            discord_request(state, async move {
+                cloned_discord.client.guild_member(guild_id, user_id).await
+            })
+            .await?
+            .model()
+            .await?
+            .roles
+        };
 
-            if let Some(auto_archive_duration_minutes) = arg.auto_archive_duration_minutes {
-                req = req.auto_archive_duration(auto_archive_duration_minutes.into())
-            }
+        let guild_roles = rt_ctx.bot_state.get_roles(guild_id).await?;
+        let Some(guild) = rt_ctx.bot_state.get_guild(guild_id).await? else {
+            return Err(anyhow!("guild not in state"));
+        };
 
-            if let Some(invitable) = arg.invitable {
-                req = req.invitable(invitable)
-            }
+        let member_guild_roles = guild_roles
+            .iter()
+            .filter(|r| member_roles.contains(&r.id))
+            .collect::<Vec<_>>();
 
-            if let Some(locked) = arg.locked {
-                req = req.locked(locked)
-            }
+        let everyone_permissions = guild_roles
+            .iter()
+            .find(|v| v.id == guild_id.cast::<RoleMarker>())
+            .map(|v| v.permissions)
+            .unwrap_or(Permissions::empty());
+
+        Ok(Self {
+            guild_id,
+            user_id,
+            owner_id: guild.owner_id,
+            everyone_permissions,
+            role_permissions: member_guild_roles
+                .iter()
+                .map(|r| (r.id, r.permissions))
+                .collect(),
+            highest_position: member_guild_roles
+                .iter()
+                .map(|r| r.position)
+                .max()
+                .unwrap_or_default(),
+        })
+    }
 
-            if let Some(name) = &arg.name {
-                req = req.name(name)?;
-            }
+    fn calculator(&self) -> twilight_util::permission_calculator::PermissionCalculator<'_> {
+        twilight_util::permission_calculator::PermissionCalculator::new(
+            self.guild_id,
+            self.user_id,
+            self.everyone_permissions,
+            self.role_permissions.as_slice(),
+        )
+        .owner_id(self.owner_id)
+    }
+}
 
-            if let Some(rate_limit_per_user) = &arg.rate_limit_per_user {
-                req = req.rate_limit_per_user(*rate_limit_per_user)?;
-            }
+// ensures the role exists on the guild and is below the bot's highest role
+async fn check_manageable_role(
+    rt_ctx: &RuntimeContext,
+    role_id: Id<RoleMarker>,
+    bot_position: i64,
+) -> Result<(), AnyError> {
+    let Some(role) = rt_ctx.bot_state.get_role(rt_ctx.guild_id, role_id).await? else {
+        return Err(not_found_error(format!("role `{role_id}` not found")));
+    };
 
-            Ok(req.await)
-        })
-        .await?
-        .model()
-        .await?
-        .into())
+    if role.position >= bot_position {
+        return Err(role_hierarchy_error(format!(
+            "role `{role_id}` is not below the bot's highest role"
+        )));
     }
+
+    Ok(())
+}
+
+fn role_hierarchy_error(message: String) -> AnyError {
+    custom_error("DiscordPermissionsError", message)
 }
 
 #[op2(async)]
@@ -977,11 +2688,22 @@ pub async fn op_discord_interaction_callback(
     let rt_ctx = get_rt_ctx(&state);
 
     let interaction_id: Id<InteractionMarker> = parse_discord_id(&args.interaction_id)?;
+    let attachments = convert_message_attachments(
+        &state,
+        args.data
+            .message_fields()
+            .and_then(|v| v.attachments.as_deref()),
+    )?;
+
+    let mut response: twilight_model::http::interaction::InteractionResponse = args.data.into();
+    if let Some(data) = &mut response.data {
+        data.attachments = attachments;
+    }
 
     discord_request(&state, async move {
         let client = rt_ctx.discord_config.interaction_client();
         client
-            .create_response(interaction_id, &args.interaction_token, &args.data.into())
+            .create_response(interaction_id, &args.interaction_token, &response)
             .await
     })
     .await?;
@@ -1025,6 +2747,8 @@ pub async fn op_discord_interaction_edit_original_response(
         .components
         .map(|inner| inner.into_iter().map(Into::into).collect::<Vec<_>>());
 
+    let attachments = convert_message_attachments(&state, args.fields.attachments.as_deref())?;
+
     Ok(discord_request_with_extra_error(&state, async move {
         let interaction_client = rt_ctx.discord_config.interaction_client();
 
@@ -1035,6 +2759,10 @@ pub async fn op_discord_interaction_edit_original_response(
             .components(components.as_deref())?
             .content(args.fields.content.as_deref())?;
 
+        if let Some(attachments) = &attachments {
+            mc = mc.attachments(attachments)?;
+        }
+
         let mentions = args.fields.allowed_mentions.map(Into::into);
         if mentions.is_some() {
             mc = mc.allowed_mentions(mentions.as_ref());
@@ -1107,6 +2835,8 @@ pub async fn op_discord_interaction_followup_message(
         .map(Into::into)
         .collect::<Vec<_>>();
 
+    let attachments = convert_message_attachments(&state, args.fields.attachments.as_deref())?;
+
     Ok(discord_request_with_extra_error(&state, async move {
         let interaction_client = rt_ctx.discord_config.interaction_client();
 
@@ -1115,6 +2845,10 @@ pub async fn op_discord_interaction_followup_message(
             .embeds(&maybe_embeds)?
             .components(&components)?;
 
+        if let Some(attachments) = &attachments {
+            mc = mc.attachments(attachments)?;
+        }
+
         if let Some(flags) = args.flags {
             mc = mc.flags(flags.into());
         }
@@ -1155,6 +2889,8 @@ pub async fn op_discord_interaction_edit_followup_message(
         .components
         .map(|inner| inner.into_iter().map(Into::into).collect::<Vec<_>>());
 
+    let attachments = convert_message_attachments(&state, args.fields.attachments.as_deref())?;
+
     discord_request_with_extra_error(&state, async move {
         let interaction_client = rt_ctx.discord_config.interaction_client();
 
@@ -1165,6 +2901,10 @@ pub async fn op_discord_interaction_edit_followup_message(
             .components(components.as_deref())?
             .content(args.fields.content.as_deref())?;
 
+        if let Some(attachments) = &attachments {
+            mc = mc.attachments(attachments)?;
+        }
+
         let mentions = args.fields.allowed_mentions.map(Into::into);
         if mentions.is_some() {
             mc = mc.allowed_mentions(mentions.as_ref());
@@ -1397,6 +3137,223 @@ pub async fn op_discord_get_channels(
     Ok(channels.into_iter().map(Into::into).collect())
 }
 
+async fn create_message(
+    state: &Rc<RefCell<OpState>>,
+    rt_ctx: &RuntimeContext,
+    channel_id: Id<ChannelMarker>,
+    fields: OpCreateMessageFields,
+) -> Result<twilight_model::channel::Message, AnyError> {
+    let attachments = convert_message_attachments(state, fields.attachments.as_deref())?;
+    let cloned_discord = rt_ctx.discord_config.clone();
+
+    Ok(discord_request_with_extra_error(state, async move {
+        let maybe_embeds = fields
+            .embeds
+            .unwrap_or_default()
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+
+        let components = fields
+            .components
+            .unwrap_or_default()
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+
+        let mut mc = cloned_discord
+            .client
+            .create_message(channel_id)
+            .embeds(&maybe_embeds)?
+            .components(&components)?;
+
+        if let Some(content) = &fields.content {
+            mc = mc.content(content)?
+        }
+
+        if let Some(attachments) = &attachments {
+            mc = mc.attachments(attachments)?;
+        }
+
+        let mentions = fields.allowed_mentions.map(Into::into);
+        if mentions.is_some() {
+            mc = mc.allowed_mentions(mentions.as_ref());
+        }
+
+        Ok(mc.await)
+    })
+    .await?
+    .model()
+    .await?)
+}
+
+/// Creates a message with a poll
+///
+/// This uses a raw request as twilight doesn't support polls yet, the body is validated the same
+/// way twilight validates regular messages before it's sent
+async fn create_poll_message(
+    state: &Rc<RefCell<OpState>>,
+    rt_ctx: &RuntimeContext,
+    channel_id: Id<ChannelMarker>,
+    fields: OpCreateMessageFields,
+    poll: DiscordCreatePoll,
+) -> Result<Message, AnyError> {
+    #[derive(Serialize)]
+    struct CreatePollMessageBody {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        content: Option<String>,
+        embeds: Vec<twilight_model::channel::message::Embed>,
+        components: Vec<twilight_model::channel::message::Component>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        allowed_mentions: Option<twilight_model::channel::message::AllowedMentions>,
+        poll: DiscordCreatePoll,
+    }
+
+    if fields.attachments.as_ref().is_some_and(|v| !v.is_empty()) {
+        return Err(anyhow!(
+            "attachments are not supported on messages with polls"
+        ));
+    }
+
+    let body = CreatePollMessageBody {
+        content: fields.content,
+        embeds: fields
+            .embeds
+            .unwrap_or_default()
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        components: fields
+            .components
+            .unwrap_or_default()
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        allowed_mentions: fields.allowed_mentions.map(Into::into),
+        poll,
+    };
+
+    // the same checks twilight runs on regular create message requests
+    if let Some(content) = &body.content {
+        twilight_validate::message::content(content)?;
+    }
+    twilight_validate::message::embeds(&body.embeds)?;
+    twilight_validate::message::components(&body.components)?;
+
+    let request = twilight_http::request::Request::builder(&Route::CreateMessage {
+        channel_id: channel_id.get(),
+    })
+    .json(&body)
+    .build()?;
+
+    let cloned_discord = rt_ctx.discord_config.clone();
+    let res = discord_request(state, async move {
+        cloned_discord
+            .client
+            .request::<twilight_model::channel::Message>(request)
+            .await
+    })
+    .await?;
+
+    message_with_poll(res).await
+}
+
+// twilight drops the poll when deserializing messages, so we parse the body twice to get it
+
+async fn message_with_poll(
+    res: twilight_http::Response<twilight_model::channel::Message>,
+) -> Result<Message, AnyError> {
+    let body = res.bytes().await?;
+
+    let message: twilight_model::channel::Message = serde_json::from_slice(&body)?;
+    let raw: DiscordMessagePoll = serde_json::from_slice(&body)?;
+
+    Ok(Message {
+        poll: raw.poll.map(Into::into),
+        ..message.into()
+    })
+}
+
+async fn messages_with_polls(
+    res: twilight_http::Response<
+        twilight_http::response::marker::ListBody<twilight_model::channel::Message>,
+    >,
+) -> Result<Vec<Message>, AnyError> {
+    let body = res.bytes().await?;
+
+    let messages: Vec<twilight_model::channel::Message> = serde_json::from_slice(&body)?;
+    let raw: Vec<DiscordMessagePoll> = serde_json::from_slice(&body)?;
+
+    Ok(messages
+        .into_iter()
+        .zip(raw)
+        .map(|(message, raw)| Message {
+            poll: raw.poll.map(Into::into),
+            ..message.into()
+        })
+        .collect())
+}
+
+const MAX_APPLIED_TAGS: usize = 5;
+
+/// Parses the tags to apply to a thread, making sure they exist on the forum channel
+fn parse_applied_tags(
+    forum: &twilight_model::channel::Channel,
+    tag_ids: &[String],
+) -> Result<Vec<Id<TagMarker>>, AnyError> {
+    if forum.kind != twilight_model::channel::ChannelType::GuildForum {
+        return Err(anyhow!(
+            "tags can only be applied to threads in forum channels"
+        ));
+    }
+
+    if tag_ids.len() > MAX_APPLIED_TAGS {
+        return Err(anyhow!(
+            "threads can have at most {MAX_APPLIED_TAGS} tags applied"
+        ));
+    }
+
+    let available = forum.available_tags.as_deref().unwrap_or_default();
+
+    tag_ids
+        .iter()
+        .map(|string_id| {
+            let id: Id<TagMarker> = parse_discord_id(string_id)?;
+            if available.iter().any(|tag| tag.id == id) {
+                Ok(id)
+            } else {
+                Err(anyhow!("unknown tag: {string_id}"))
+            }
+        })
+        .collect()
+}
+
+/// Sends a create or update channel request
+///
+/// This uses a raw request as twilight requires ids on all forum tags
+async fn send_channel_request(
+    state: &Rc<RefCell<OpState>>,
+    rt_ctx: &RuntimeContext,
+    route: Route<'_>,
+    body: &ChannelRequestBody,
+) -> Result<twilight_model::channel::Channel, AnyError> {
+    let request = twilight_http::request::Request::builder(&route)
+        .json(body)
+        .build()?;
+
+    let cloned_discord = rt_ctx.discord_config.clone();
+
+    Ok(discord_request(state, async move {
+        cloned_discord
+            .client
+            .request::<twilight_model::channel::Channel>(request)
+            .await
+    })
+    .await?
+    .model()
+    .await?)
+}
+
 #[op2(async)]
 #[serde]
 pub async fn op_discord_edit_channel(
@@ -1407,18 +3364,23 @@ pub async fn op_discord_edit_channel(
     let rt_ctx = get_rt_ctx(&state);
 
     // ensure the channel exists on the guild
-    get_guild_channel(&state, &rt_ctx, channel_id).await?;
+    let channel = get_guild_channel(&state, &rt_ctx, channel_id).await?;
 
-    Ok(discord_request_with_extra_error(&state, async move {
-        let mut overwrites = Vec::new();
-        let edit = rt_ctx.discord_config.client.update_channel(channel_id);
+    let body = params.request_body(channel.available_tags.as_deref().unwrap_or_default())?;
+    if body.has_forum_settings() && channel.kind != twilight_model::channel::ChannelType::GuildForum
+    {
+        return Err(anyhow!(
+            "tags, default reaction and sort order can only be set on forum channels"
+        ));
+    }
 
-        Ok(params.apply(&mut overwrites, edit)?.await)
-    })
-    .await?
-    .model()
-    .await?
-    .into())
+    let route = Route::UpdateChannel {
+        channel_id: channel_id.get(),
+    };
+
+    Ok(send_channel_request(&state, &rt_ctx, route, &body)
+        .await?
+        .into())
 }
 
 #[op2(async)]
@@ -1429,19 +3391,14 @@ pub async fn op_discord_create_channel(
 ) -> Result<runtime_models::internal::channel::GuildChannel, AnyError> {
     let rt_ctx = get_rt_ctx(&state);
 
-    Ok(discord_request_with_extra_error(&state, async move {
-        let mut overwrites = Vec::new();
-        let edit = rt_ctx
-            .discord_config
-            .client
-            .create_guild_channel(rt_ctx.guild_id, &params.name)?;
+    let body = params.request_body()?;
+    let route = Route::CreateChannel {
+        guild_id: rt_ctx.guild_id.get(),
+    };
 
-        Ok(params.apply(&mut overwrites, edit)?.await)
-    })
-    .await?
-    .model()
-    .await?
-    .into())
+    Ok(send_channel_request(&state, &rt_ctx, route, &body)
+        .await?
+        .into())
 }
 
 #[op2(async)]
@@ -1967,53 +3924,8 @@ pub async fn op_discord_get_member_permissions(
 ) -> Result<(String, Option<String>), AnyError> {
     let rt_ctx = get_rt_ctx(&state);
 
-    let member_roles = if let Some(roles) = roles {
-        roles
-    } else {
-        let cloned_discord = rt_ctx.discord_config.clone();
-        let member = discord_request(&state, async move {
-            cloned_discord
-                .client
-                .guild_member(rt_ctx.guild_id, user_id)
-                .await
-        })
-        .await?
-        .model()
-        .await?;
-
-        member.roles
-    };
-
-    let guild_roles = rt_ctx.bot_state.get_roles(rt_ctx.guild_id).await?;
-    let guild = if let Some(guild) = rt_ctx.bot_state.get_guild(rt_ctx.guild_id).await? {
-        guild
-    } else {
-        return Err(anyhow!("guild not in state"));
-    };
-
-    let role_perms_pair = member_roles
-        .iter()
-        .filter_map(|rid| {
-            guild_roles
-                .iter()
-                .find(|r| r.id == *rid)
-                .map(|r| (*rid, r.permissions))
-        })
-        .collect::<Vec<_>>();
-
-    let everyone_role = guild_roles
-        .iter()
-        .find(|v| v.id == rt_ctx.guild_id.cast::<RoleMarker>())
-        .map(|v| v.permissions)
-        .unwrap_or(Permissions::empty());
-
-    let calc = twilight_util::permission_calculator::PermissionCalculator::new(
-        rt_ctx.guild_id,
-        user_id,
-        everyone_role,
-        role_perms_pair.as_slice(),
-    )
-    .owner_id(guild.owner_id);
+    let member_roles = MemberRoles::fetch(&state, &rt_ctx, user_id, roles).await?;
+    let calc = member_roles.calculator();
 
     let guild_perms = calc.root();
     let channel_perms = if let Some(channel_id) = channel_id {
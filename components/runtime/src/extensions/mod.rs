@@ -19,6 +19,7 @@ pub mod discord;
 pub mod httpclient;
 pub mod storage;
 pub mod tasks;
+pub mod timers;
 
 pub(crate) fn parse_discord_id<T>(input: &str) -> Result<Id<T>, AnyError> {
     if let Some(id) = Id::new_checked(input.parse()?) {
@@ -2,19 +2,25 @@ use std::{cell::RefCell, rc::Rc, time::Duration};
 
 use anyhow::anyhow;
 use deno_core::{op2, OpState};
+use guild_logger::entry::CreateLogEntry;
 use runtime_models::{
     internal::storage::{
         OpStorageBucketEntry, OpStorageBucketEntryId, OpStorageBucketIncr, OpStorageBucketList,
-        OpStorageBucketSetIf, OpStorageBucketSetValue, OpStorageBucketSortedList,
-        OpStorageBucketValue,
+        OpStorageBucketNeighbors, OpStorageBucketSetIf, OpStorageBucketSetValue,
+        OpStorageBucketSortedList, OpStorageBucketSortedNeighbors, OpStorageBucketSortedRank,
+        OpStorageBucketSubscribe, OpStorageBucketTransactionOp, OpStorageBucketTransactionResult,
+        OpStorageBucketValue, OpStorageValueMeta, StorageChangedEvent,
     },
-    util::PluginId,
+    util::{NotBigU64, PluginId},
+};
+use stores::bucketstore::{
+    DeletedEntry, Entry, EntryCursor, EntryWrite, ListQuery, SortedQuery, TransactionResult,
 };
 use tracing::{info, instrument};
 use twilight_model::id::{marker::GuildMarker, Id};
 use vm::AnyError;
 
-use crate::RuntimeContext;
+use crate::{RuntimeContext, RuntimeEvent};
 
 deno_core::extension!(
     bl_storage,
@@ -28,12 +34,20 @@ deno_core::extension!(
         op_botloader_bucket_storage_count,
         op_botloader_bucket_storage_incr,
         op_botloader_bucket_storage_sorted_list,
+        op_botloader_bucket_storage_sorted_rank,
+        op_botloader_bucket_storage_sorted_neighbors,
+        op_botloader_bucket_storage_transaction,
+        op_botloader_bucket_storage_subscribe,
+        op_botloader_storage_changed_handler_enter,
+        op_botloader_storage_changed_handler_exit,
     ],
     state = |state| {
         state.put(StorageState {
             doing_limit_check: false,
             hit_limit: false,
             requests_until_limit_check: 0,
+            subscriptions: Vec::new(),
+            active_handler_depths: Vec::new(),
         });
         // state.put::<Options>(options.options);
     },
@@ -68,6 +82,12 @@ struct StorageState {
     requests_until_limit_check: u32,
     doing_limit_check: bool,
     hit_limit: bool,
+
+    // buckets and key prefixes scripts want STORAGE_CHANGED events for
+    subscriptions: Vec<StorageSubscription>,
+
+    // depths of the STORAGE_CHANGED handlers currently running
+    active_handler_depths: Vec<u32>,
 }
 
 #[op2(async)]
@@ -85,7 +105,7 @@ pub async fn op_botloader_bucket_storage_set(
     check_validate_key_len(&args.key)?;
     check_validate_storage_usage(rt_ctx.guild_id, &rt_ctx, state.clone()).await?;
 
-    let entry = rt_ctx
+    let write = rt_ctx
         .bucket_store
         .set(
             rt_ctx.guild_id,
@@ -97,7 +117,8 @@ pub async fn op_botloader_bucket_storage_set(
         )
         .await?;
 
-    Ok(entry.into())
+    emit_write(&state, &rt_ctx, &write);
+    Ok(write.entry.into())
 }
 
 #[op2(async)]
@@ -115,7 +136,7 @@ pub async fn op_botloader_bucket_storage_set_if(
     check_validate_key_len(&args.key)?;
     check_validate_storage_usage(rt_ctx.guild_id, &rt_ctx, state.clone()).await?;
 
-    let entry = rt_ctx
+    let write = rt_ctx
         .bucket_store
         .set_if(
             rt_ctx.guild_id,
@@ -128,7 +149,12 @@ pub async fn op_botloader_bucket_storage_set_if(
         )
         .await?;
 
-    Ok(entry.map(Into::into))
+    // nothing changed if the condition failed
+    if let Some(write) = &write {
+        emit_write(&state, &rt_ctx, write);
+    }
+
+    Ok(write.map(|write| write.entry.into()))
 }
 
 #[op2(async)]
@@ -176,12 +202,16 @@ pub async fn op_botloader_bucket_storage_del(
         )
         .await?;
 
-    if entry.is_some() {
-        let mut state = state.borrow_mut();
-        let storage_ctx = state.borrow_mut::<StorageState>();
+    if let Some(entry) = &entry {
+        {
+            let mut state = state.borrow_mut();
+            let storage_ctx = state.borrow_mut::<StorageState>();
 
-        // re-check in case were at the limti
-        storage_ctx.hit_limit = false;
+            // re-check in case were at the limti
+            storage_ctx.hit_limit = false;
+        }
+
+        emit_delete(&state, &rt_ctx, entry);
     }
 
     Ok(entry.map(Into::into))
@@ -200,15 +230,24 @@ pub async fn op_botloader_bucket_storage_del_many(
         state.borrow::<RuntimeContext>().clone()
     };
 
-    let res = rt_ctx
-        .bucket_store
-        .del_many(
-            rt_ctx.guild_id,
-            plugin_id.map(Into::into),
-            bucket_name,
-            key_pattern,
-        )
-        .await?;
+    let plugin_id = plugin_id.map(Into::into);
+
+    // the deleted keys are only needed for the change events
+    let res = if is_subscribed_to_bucket(&state, plugin_id, &bucket_name) {
+        let deleted = rt_ctx
+            .bucket_store
+            .del_many_meta(rt_ctx.guild_id, plugin_id, bucket_name.clone(), key_pattern)
+            .await?;
+
+        emit_del_many(&state, &rt_ctx, plugin_id, &bucket_name, &deleted);
+
+        deleted.len() as u64
+    } else {
+        rt_ctx
+            .bucket_store
+            .del_many(rt_ctx.guild_id, plugin_id, bucket_name, key_pattern)
+            .await?
+    };
 
     if res > 0 {
         let mut state = state.borrow_mut();
@@ -242,15 +281,29 @@ pub async fn op_botloader_bucket_storage_list(
         25
     };
 
+    let after = match args.cursor {
+        Some(cursor) => Some(decode_cursor(&cursor)?.key),
+        // the old pagination method, the key of the last entry
+        None => args.after.filter(|after| !after.is_empty()),
+    };
+
     let entries = rt_ctx
         .bucket_store
         .get_many(
             rt_ctx.guild_id,
             args.plugin_id.map(Into::into),
             args.bucket_name,
-            args.key_pattern.unwrap_or_else(|| "%".to_string()),
-            args.after.unwrap_or_default(),
-            limit,
+            ListQuery {
+                key_pattern: args.key_pattern.unwrap_or_else(|| "%".to_string()),
+                range: args
+                    .range
+                    .map(TryInto::try_into)
+                    .transpose()?
+                    .unwrap_or_default(),
+                after,
+                reverse: args.reverse.unwrap_or_default(),
+                limit,
+            },
         )
         .await?;
 
@@ -297,7 +350,7 @@ pub async fn op_botloader_bucket_storage_incr(
     check_validate_key_len(&args.key)?;
     check_validate_storage_usage(rt_ctx.guild_id, &rt_ctx, state.clone()).await?;
 
-    let entry = rt_ctx
+    let write = rt_ctx
         .bucket_store
         .incr(
             rt_ctx.guild_id,
@@ -308,7 +361,8 @@ pub async fn op_botloader_bucket_storage_incr(
         )
         .await?;
 
-    Ok(entry.into())
+    emit_write(&state, &rt_ctx, &write);
+    Ok(write.entry.into())
 }
 
 #[op2(async)]
@@ -332,21 +386,397 @@ pub async fn op_botloader_bucket_storage_sorted_list(
         25
     };
 
+    let after = match args.cursor {
+        Some(cursor) => {
+            let cursor = decode_cursor(&cursor)?;
+            if cursor.value_float.is_none() {
+                return Err(anyhow!("cursor is not from a number bucket entry"));
+            }
+            Some(cursor)
+        }
+        None => None,
+    };
+
     let entries = rt_ctx
         .bucket_store
         .sorted_entries(
             rt_ctx.guild_id,
             args.plugin_id.map(Into::into),
             args.bucket_name,
-            args.order.into(),
-            args.offset.unwrap_or_default(),
-            limit,
+            SortedQuery {
+                order: args.order.into(),
+                range: args
+                    .range
+                    .map(TryInto::try_into)
+                    .transpose()?
+                    .unwrap_or_default(),
+                after,
+                offset: args.offset.unwrap_or_default(),
+                limit,
+            },
         )
         .await?;
 
     Ok(entries.into_iter().map(Into::into).collect())
 }
 
+#[op2(async)]
+#[serde]
+pub async fn op_botloader_bucket_storage_sorted_rank(
+    state: Rc<RefCell<OpState>>,
+    #[serde] args: OpStorageBucketSortedRank,
+) -> Result<Option<NotBigU64>, AnyError> {
+    let rt_ctx = {
+        let state = state.borrow();
+        state.borrow::<RuntimeContext>().clone()
+    };
+
+    let rank = rt_ctx
+        .bucket_store
+        .sorted_rank(
+            rt_ctx.guild_id,
+            args.plugin_id.map(Into::into),
+            args.bucket_name,
+            args.key,
+            args.order.into(),
+        )
+        .await?;
+
+    Ok(rank.map(NotBigU64))
+}
+
+const MAX_NEIGHBORS: u32 = 50;
+
+#[op2(async)]
+#[serde]
+pub async fn op_botloader_bucket_storage_sorted_neighbors(
+    state: Rc<RefCell<OpState>>,
+    #[serde] args: OpStorageBucketSortedNeighbors,
+) -> Result<Option<OpStorageBucketNeighbors>, AnyError> {
+    let rt_ctx = {
+        let state = state.borrow();
+        state.borrow::<RuntimeContext>().clone()
+    };
+
+    let before = args.before.unwrap_or(5);
+    let after = args.after.unwrap_or(5);
+    if before > MAX_NEIGHBORS || after > MAX_NEIGHBORS {
+        return Err(anyhow!(
+            "before and after can't be larger than {MAX_NEIGHBORS}"
+        ));
+    }
+
+    let neighbors = rt_ctx
+        .bucket_store
+        .sorted_neighbors(
+            rt_ctx.guild_id,
+            args.plugin_id.map(Into::into),
+            args.bucket_name,
+            args.key,
+            args.order.into(),
+            before,
+            after,
+        )
+        .await?;
+
+    Ok(neighbors.map(Into::into))
+}
+
+const MAX_TRANSACTION_OPS: usize = 25;
+
+#[op2(async)]
+#[serde]
+pub async fn op_botloader_bucket_storage_transaction(
+    state: Rc<RefCell<OpState>>,
+    #[serde] ops: Vec<OpStorageBucketTransactionOp>,
+) -> Result<OpStorageBucketTransactionResult, AnyError> {
+    let rt_ctx = {
+        let state = state.borrow();
+        state.borrow::<RuntimeContext>().clone()
+    };
+
+    if ops.is_empty() || ops.len() > MAX_TRANSACTION_OPS {
+        return Err(anyhow!(
+            "transactions need between 1 and {MAX_TRANSACTION_OPS} operations"
+        ));
+    }
+
+    for op in &ops {
+        match op {
+            OpStorageBucketTransactionOp::Set(set) => {
+                check_validate_value_len(&set.value)?;
+                check_validate_key_len(&set.key)?;
+            }
+            OpStorageBucketTransactionOp::SetIf(set) => {
+                check_validate_value_len(&set.value)?;
+                check_validate_key_len(&set.key)?;
+            }
+            OpStorageBucketTransactionOp::Incr(incr) => {
+                check_validate_key_len(&incr.key)?;
+            }
+            OpStorageBucketTransactionOp::Del(_) => continue,
+        }
+
+        // every write counts towards the storage usage checks, same as if they were done separately
+        check_validate_storage_usage(rt_ctx.guild_id, &rt_ctx, state.clone()).await?;
+    }
+
+    let deletes = ops
+        .iter()
+        .map(|op| matches!(op, OpStorageBucketTransactionOp::Del(_)))
+        .collect::<Vec<_>>();
+
+    let res = rt_ctx
+        .bucket_store
+        .transaction(rt_ctx.guild_id, ops.into_iter().map(Into::into).collect())
+        .await?;
+
+    if deletes.contains(&true) {
+        let mut state = state.borrow_mut();
+        let storage_ctx = state.borrow_mut::<StorageState>();
+
+        // re-check in case were at the limti
+        storage_ctx.hit_limit = false;
+    }
+
+    if let TransactionResult::Committed(writes) = &res {
+        // deletes return the deleted entry, the rest the entry after the write
+        for (is_del, write) in deletes.into_iter().zip(writes) {
+            match write {
+                Some(write) if is_del => emit_delete(&state, &rt_ctx, &write.entry),
+                Some(write) => emit_write(&state, &rt_ctx, write),
+                None => {}
+            }
+        }
+    }
+
+    Ok(res.into())
+}
+
+const MAX_SUBSCRIPTIONS: usize = 100;
+
+#[derive(PartialEq)]
+struct StorageSubscription {
+    plugin_id: Option<u64>,
+    bucket: String,
+    key_prefix: Option<String>,
+}
+
+impl StorageSubscription {
+    fn matches(&self, plugin_id: Option<u64>, bucket: &str, key: &str) -> bool {
+        self.plugin_id == plugin_id
+            && self.bucket == bucket
+            && self
+                .key_prefix
+                .as_ref()
+                .map(|prefix| key.starts_with(prefix.as_str()))
+                .unwrap_or(true)
+    }
+}
+
+#[op2]
+pub fn op_botloader_bucket_storage_subscribe(
+    state: &mut OpState,
+    #[serde] args: OpStorageBucketSubscribe,
+) -> Result<(), AnyError> {
+    let storage_ctx = state.borrow_mut::<StorageState>();
+
+    let sub = StorageSubscription {
+        plugin_id: args.plugin_id.map(Into::into),
+        bucket: args.bucket_name,
+        key_prefix: args.key_prefix.filter(|prefix| !prefix.is_empty()),
+    };
+
+    if storage_ctx.subscriptions.contains(&sub) {
+        return Ok(());
+    }
+
+    if storage_ctx.subscriptions.len() >= MAX_SUBSCRIPTIONS {
+        return Err(anyhow!(
+            "too many storage change subscriptions (max {MAX_SUBSCRIPTIONS})"
+        ));
+    }
+
+    storage_ctx.subscriptions.push(sub);
+    Ok(())
+}
+
+fn is_subscribed(
+    state: &Rc<RefCell<OpState>>,
+    plugin_id: Option<u64>,
+    bucket: &str,
+    key: &str,
+) -> bool {
+    let state = state.borrow();
+    state
+        .borrow::<StorageState>()
+        .subscriptions
+        .iter()
+        .any(|sub| sub.matches(plugin_id, bucket, key))
+}
+
+/// Marks a STORAGE_CHANGED handler for an event with the given depth as running
+#[op2(fast)]
+pub fn op_botloader_storage_changed_handler_enter(state: &mut OpState, #[smi] depth: u32) {
+    let storage_ctx = state.borrow_mut::<StorageState>();
+    storage_ctx.active_handler_depths.push(depth);
+}
+
+#[op2(fast)]
+pub fn op_botloader_storage_changed_handler_exit(state: &mut OpState, #[smi] depth: u32) {
+    let storage_ctx = state.borrow_mut::<StorageState>();
+    if let Some(index) = storage_ctx
+        .active_handler_depths
+        .iter()
+        .position(|v| *v == depth)
+    {
+        storage_ctx.active_handler_depths.swap_remove(index);
+    }
+}
+
+fn is_subscribed_to_bucket(
+    state: &Rc<RefCell<OpState>>,
+    plugin_id: Option<u64>,
+    bucket: &str,
+) -> bool {
+    let state = state.borrow();
+    state
+        .borrow::<StorageState>()
+        .subscriptions
+        .iter()
+        .any(|sub| sub.plugin_id == plugin_id && sub.bucket == bucket)
+}
+
+/// Dispatches a STORAGE_CHANGED event for the write if a script is subscribed to the key
+fn emit_write(state: &Rc<RefCell<OpState>>, rt_ctx: &RuntimeContext, write: &EntryWrite) {
+    emit_change(
+        state,
+        rt_ctx,
+        ChangedKey::from(&write.entry),
+        write.previous.as_ref().map(Into::into),
+        Some((&write.entry).into()),
+    );
+}
+
+/// Dispatches a STORAGE_CHANGED event for the deleted entry if a script is subscribed to the key
+fn emit_delete(state: &Rc<RefCell<OpState>>, rt_ctx: &RuntimeContext, deleted: &Entry) {
+    emit_change(
+        state,
+        rt_ctx,
+        ChangedKey::from(deleted),
+        Some(deleted.into()),
+        None,
+    );
+}
+
+/// How many STORAGE_CHANGED events a single deleteAll can dispatch, so that clearing a bucket
+/// doesn't use up the bucket's event rate limit
+const MAX_DEL_MANY_EVENTS: usize = 25;
+
+/// Dispatches STORAGE_CHANGED events for the entries removed by a deleteAll, up to
+/// [`MAX_DEL_MANY_EVENTS`] of them
+fn emit_del_many(
+    state: &Rc<RefCell<OpState>>,
+    rt_ctx: &RuntimeContext,
+    plugin_id: Option<u64>,
+    bucket: &str,
+    deleted: &[DeletedEntry],
+) {
+    let mut dispatched = 0;
+    for (i, entry) in deleted.iter().enumerate() {
+        if dispatched >= MAX_DEL_MANY_EVENTS {
+            rt_ctx.guild_logger.log(CreateLogEntry::info(format!(
+                "only dispatched storage change events for {MAX_DEL_MANY_EVENTS} of the keys \
+                 deleted from bucket {bucket}, the remaining {} deleted keys were skipped",
+                deleted.len() - i
+            )));
+            return;
+        }
+
+        let key = ChangedKey {
+            plugin_id,
+            bucket,
+            key: &entry.key,
+        };
+
+        if emit_change(state, rt_ctx, key, Some((&entry.meta).into()), None) {
+            dispatched += 1;
+        }
+    }
+}
+
+struct ChangedKey<'a> {
+    plugin_id: Option<u64>,
+    bucket: &'a str,
+    key: &'a str,
+}
+
+impl<'a> From<&'a Entry> for ChangedKey<'a> {
+    fn from(v: &'a Entry) -> Self {
+        Self {
+            plugin_id: v.plugin_id,
+            bucket: &v.bucket,
+            key: &v.key,
+        }
+    }
+}
+
+/// How many STORAGE_CHANGED handlers can trigger each other before the events are dropped
+const MAX_STORAGE_CHANGED_DEPTH: u32 = 10;
+
+// returns whether a script is subscribed to the key, even if the event was dropped
+fn emit_change(
+    state: &Rc<RefCell<OpState>>,
+    rt_ctx: &RuntimeContext,
+    entry: ChangedKey,
+    old: Option<OpStorageValueMeta>,
+    new: Option<OpStorageValueMeta>,
+) -> bool {
+    if !is_subscribed(state, entry.plugin_id, entry.bucket, entry.key) {
+        return false;
+    }
+
+    // async handlers can't be told apart once they're running, so writes made while any handler
+    // runs are counted as coming from the deepest one
+    let depth = {
+        let state = state.borrow();
+        let storage_ctx = state.borrow::<StorageState>();
+        storage_ctx
+            .active_handler_depths
+            .iter()
+            .max()
+            .map(|v| v + 1)
+            .unwrap_or_default()
+    };
+
+    if depth > MAX_STORAGE_CHANGED_DEPTH {
+        rt_ctx.guild_logger.log(CreateLogEntry::error(format!(
+            "dropped a storage change event for key {} in bucket {}: STORAGE_CHANGED handlers \
+             triggered each other more than {MAX_STORAGE_CHANGED_DEPTH} times in a row, this is \
+             usually caused by a handler writing to a bucket it's subscribed to",
+            entry.key, entry.bucket
+        )));
+        return true;
+    }
+
+    let _ = rt_ctx
+        .event_tx
+        .send(RuntimeEvent::StorageChanged(StorageChangedEvent {
+            plugin_id: entry.plugin_id.map(PluginId),
+            bucket_name: entry.bucket.to_owned(),
+            key: entry.key.to_owned(),
+            old,
+            new,
+            depth,
+        }));
+
+    true
+}
+
+fn decode_cursor(cursor: &str) -> Result<EntryCursor, AnyError> {
+    EntryCursor::decode(cursor).ok_or_else(|| anyhow!("invalid cursor"))
+}
+
 fn check_validate_value_len(val: &OpStorageBucketValue) -> Result<(), AnyError> {
     match val {
         OpStorageBucketValue::Json(json) => {
@@ -358,6 +788,13 @@ fn check_validate_value_len(val: &OpStorageBucketValue) -> Result<(), AnyError>
             }
         }
         OpStorageBucketValue::Double(_) => Ok(()),
+        OpStorageBucketValue::Bytes(bytes) => {
+            if bytes.0.len() > 1_000_000 {
+                Err(anyhow::anyhow!("value too big, max value size is 1MB"))
+            } else {
+                Ok(())
+            }
+        }
     }
 }
 
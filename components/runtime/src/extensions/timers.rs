@@ -0,0 +1,111 @@
+use std::{borrow::Cow, cell::RefCell, rc::Rc, time::Duration};
+
+use deno_core::{op2, CancelFuture, CancelHandle, OpState, RcRef, Resource, ResourceId};
+use tokio::time::Instant;
+use vm::{AnyError, VmLastEventAt, VmShutdownCancelHandle};
+
+use crate::limits;
+
+// the timer ops are part of bl_script_core, see lib.rs
+
+struct TimerResource {
+    delay: Duration,
+    repeat: bool,
+    cancel: CancelHandle,
+}
+
+impl Resource for TimerResource {
+    fn name(&self) -> Cow<str> {
+        "timer".into()
+    }
+
+    fn close(self: Rc<Self>) {
+        self.cancel.cancel()
+    }
+}
+
+/// Timers are not allowed to keep the vm alive for longer than the tier limit after the last event,
+/// this also covers timers created from other timers as they don't count as events
+fn timer_deadline(state: &Rc<RefCell<OpState>>) -> Instant {
+    let last_event_at = state.borrow().borrow::<VmLastEventAt>().0;
+    Instant::from_std(last_event_at) + Duration::from_millis(limits::timer_max_lifetime_ms(state))
+}
+
+/// Creates a new timer, use op_bl_timer_wait to wait for it to fire and op_bl_timer_cancel to cancel it
+#[op2]
+#[smi]
+pub fn op_bl_timer_create(
+    state: Rc<RefCell<OpState>>,
+    #[number] delay_ms: u64,
+    repeat: bool,
+) -> Result<ResourceId, AnyError> {
+    if repeat && delay_ms == 0 {
+        return Err(anyhow::anyhow!("interval can't be 0"));
+    }
+
+    let delay = Duration::from_millis(delay_ms);
+    if Instant::now() + delay > timer_deadline(&state) {
+        return Err(anyhow::anyhow!(
+            "timer would fire more than {} seconds after the last event, use scheduled tasks for \
+             longer delays",
+            limits::timer_max_lifetime_ms(&state) / 1000
+        ));
+    }
+
+    let resource = TimerResource {
+        delay,
+        repeat,
+        cancel: CancelHandle::new(),
+    };
+
+    crate::try_insert_resource_table(&mut state.borrow_mut().resource_table, resource)
+}
+
+/// Waits for the next tick of the timer
+///
+/// Returns false if the timer was cancelled, the vm is shutting down or
+/// the next tick would be past the timer deadline, in which case the resource is also closed
+#[op2(async)]
+pub async fn op_bl_timer_wait(
+    state: Rc<RefCell<OpState>>,
+    #[smi] rid: ResourceId,
+) -> Result<bool, AnyError> {
+    let (resource, shutdown) = {
+        let state = state.borrow();
+        (
+            state.resource_table.get::<TimerResource>(rid)?,
+            state.borrow::<VmShutdownCancelHandle>().clone(),
+        )
+    };
+
+    let fire_at = Instant::now() + resource.delay;
+    let fired = if fire_at > timer_deadline(&state) {
+        false
+    } else {
+        let cancel = RcRef::map(&resource, |r| &r.cancel);
+        matches!(
+            tokio::time::sleep_until(fire_at)
+                .or_cancel(cancel)
+                .or_cancel(shutdown.0)
+                .await,
+            Ok(Ok(()))
+        )
+    };
+
+    if !fired || !resource.repeat {
+        if let Ok(r) = state.borrow_mut().resource_table.take::<TimerResource>(rid) {
+            r.close()
+        };
+    }
+
+    Ok(fired)
+}
+
+/// Cancels a timer, ids that don't belong to a timer are ignored
+#[op2(fast)]
+pub fn op_bl_timer_cancel(state: &mut OpState, #[smi] rid: ResourceId) {
+    // take only succeeds if the resource is a timer, other resources are left untouched
+    if let Ok(r) = state.resource_table.take::<TimerResource>(rid) {
+        r.close()
+    }
+}
@@ -7,7 +7,7 @@ use std::{
 use common::DiscordConfig;
 use deno_core::{op2, Extension, Op, OpState, ResourceId, ResourceTable};
 use guild_logger::{entry::CreateLogEntry, GuildLogSender};
-use runtime_models::internal::script::ScriptMeta;
+use runtime_models::internal::{script::ScriptMeta, storage::StorageChangedEvent};
 use stores::{
     bucketstore::BucketStore,
     config::{ConfigStore, PremiumSlotTier},
@@ -85,6 +85,9 @@ deno_core::extension!(
         op_get_current_bot_user,
         op_get_current_guild_id,
         op_get_run_mode,
+        extensions::timers::op_bl_timer_create,
+        extensions::timers::op_bl_timer_wait,
+        extensions::timers::op_bl_timer_cancel,
     ],
     options = {
         ctx: CoreRuntimeContext,
@@ -115,6 +118,9 @@ deno_core::extension!(
         op_get_current_bot_user,
         op_get_current_guild_id,
         op_get_run_mode,
+        extensions::timers::op_bl_timer_create,
+        extensions::timers::op_bl_timer_wait,
+        extensions::timers::op_bl_timer_cancel,
     ],
     options = {
         ctx: CoreRuntimeContext,
@@ -285,6 +291,7 @@ pub enum RuntimeEvent {
     ScriptStarted(ScriptMeta),
     NewTaskScheduled,
     InvalidRequestsExceeded,
+    StorageChanged(StorageChangedEvent),
 }
 
 impl RuntimeEvent {
@@ -293,6 +300,7 @@ impl RuntimeEvent {
             RuntimeEvent::ScriptStarted(_) => "RuntimeEvent::ScriptStarted",
             RuntimeEvent::NewTaskScheduled => "RuntimeEvent::NewTaskScheduled",
             RuntimeEvent::InvalidRequestsExceeded => "RuntimeEvent::InvalidRequestsExceeded",
+            RuntimeEvent::StorageChanged(_) => "RuntimeEvent::StorageChanged",
         }
     }
 }
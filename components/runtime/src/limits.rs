@@ -11,8 +11,18 @@ use stores::config::PremiumSlotTier;
 
 pub type RateLimiter = governor::RateLimiter<NotKeyed, InMemoryState, DefaultClock>;
 
+// limits are per second unless a period is specified
+macro_rules! quota_period {
+    () => {
+        Quota::per_second
+    };
+    (minute) => {
+        Quota::per_minute
+    };
+}
+
 macro_rules! ratelimits {
-    ($($name:ident => [$none:literal, $lite:literal, $premium:literal]),*) => {
+    ($($name:ident => [$none:literal, $lite:literal, $premium:literal] $(per $period:ident)?),*) => {
         pub struct RateLimiters {
             $($name: RateLimiter,)*
         }
@@ -28,7 +38,7 @@ macro_rules! ratelimits {
             pub fn new(tier: Option<PremiumSlotTier>) -> Self {
                 Self {
                     $(
-                        $name: RateLimiter::direct(Quota::per_second(
+                        $name: RateLimiter::direct(quota_period!($($period)?)(
                             NonZeroU32::new(match tier {
                                 None => $none,
                                 Some(PremiumSlotTier::Lite) => $lite,
@@ -68,7 +78,11 @@ ratelimits! {
 
     // number of times we can fetch a public discord invite,
     // needed because this endpoint is not guild scoped
-    discord_get_public_invite => [1, 1, 1]
+    discord_get_public_invite => [1, 1, 1],
+
+    // number of direct messages sent to members per minute,
+    // kept low to avoid the bot getting flagged for spam
+    discord_direct_messages => [2, 5, 10] per minute
 }
 
 // max total amount of bucket storage used on a guild
@@ -79,3 +93,9 @@ numeric_limit! {tasks_data_size => [1_000, 10_000, 10_000]}
 
 // max number of scheduled tasks
 numeric_limit! {tasks_scheduled_count => [10_000, 100_000, 100_000]}
+
+// max total size of the attachments in a single message
+numeric_limit! {message_attachments_size => [1_000_000, 8_000_000, 8_000_000]}
+
+// max amount of time after the last event that timers can keep the vm alive for, in milliseconds
+numeric_limit! {timer_max_lifetime_ms => [60_000, 300_000, 900_000]}
@@ -1,5 +1,5 @@
 import * as Internal from "./generated/internal/index";
-import { ChannelType, Interaction, Message, Role, IModalFields, MessageFlags } from "./discord/index";
+import { ChannelType, Interaction, Message, Role, IModalFields, MessageFlags, PermissionResolvable } from "./discord/index";
 import { User } from "./discord/user";
 import { Member } from "./discord/member";
 import { OpWrappers } from "./op_wrappers";
@@ -74,6 +74,53 @@ export namespace Commands {
             }
         }
 
+        /**
+         * @internal
+         */
+        async handleAutocomplete(interaction: Internal.CommandInteraction) {
+            const command = this.commands.find(cmd => matchesCommand(cmd, interaction.name, interaction.parentName, interaction.parentParentName));
+            if (!command) {
+                return;
+            }
+
+            const focused = interaction.options.find(opt => opt.value.kind === "focused");
+            if (!focused || focused.value.kind !== "focused") {
+                return;
+            }
+
+            const extraOptions = command.options?.[focused.name]?.extraOptions as { autocomplete?: AutocompleteProvider<string | number> } | undefined;
+            if (!extraOptions?.autocomplete) {
+                return;
+            }
+
+            const options: Record<string, unknown> = {};
+            for (const opt of interaction.options) {
+                if (opt !== focused) {
+                    options[opt.name] = opt.value.value;
+                }
+            }
+
+            const choices = await extraOptions.autocomplete({
+                value: focused.value.value,
+                optionName: focused.name,
+                options,
+                channelId: interaction.channelId,
+                member: new Member(interaction.member),
+            });
+
+            await OpWrappers.interactionCallback({
+                interactionId: interaction.id,
+                interactionToken: interaction.token,
+                data: {
+                    kind: "Autocomplete",
+                    response: {
+                        // discord only allows up to 25 choices
+                        choices: choices.slice(0, 25),
+                    },
+                }
+            })
+        }
+
         private resolveOption(map: Internal.CommandInteractionDataMap, opt: Internal.CommandInteractionOptionValue): unknown {
             switch (opt.kind) {
                 case "user":
@@ -206,9 +253,11 @@ export namespace Commands {
                 interactionToken: this.token,
                 data: {
                     kind: "Modal",
-                    title: modal.title,
-                    customId: modal.customId,
-                    components: modal.components,
+                    response: {
+                        title: modal.title,
+                        customId: modal.customId,
+                        components: modal.components,
+                    },
                 }
             })
         }
@@ -243,12 +292,56 @@ export namespace Commands {
         options?: OptionMap;
         ackMode: AckMode,
         ackMessageFlags: MessageFlags,
+        defaultMemberPermissions?: PermissionResolvable,
+        nsfw?: boolean,
+        nameLocalizations?: Localizations,
+        descriptionLocalizations?: Localizations,
         cb: (ctx: {}, args: {}) => any,
     }
 
+    /**
+     * Locales supported by discord, see https://discord.com/developers/docs/reference#locales
+     */
+    export type Locale = "id" | "da" | "de" | "en-GB" | "en-US" | "es-ES" | "es-419" | "fr" | "hr" | "it" | "lt" | "hu"
+        | "nl" | "no" | "pl" | "pt-BR" | "ro" | "fi" | "sv-SE" | "vi" | "tr" | "cs" | "el" | "bg" | "ru" | "uk" | "hi"
+        | "th" | "zh-CN" | "ja" | "zh-TW" | "ko";
+
+    /**
+     * Translations per locale, users with their discord client set to one of these locales
+     * will see the translated version instead of the default one.
+     */
+    export type Localizations = { [K in Locale]?: string };
+
     export type OptionType = Option["kind"];
 
-    export type AutocompleteProvider<T> = (data: {}) => Promise<OptionChoice<T>[]> | OptionChoice<T>[];
+    /**
+     * Returns the suggestions to show the user, discord only shows up to 25 suggestions.
+     * 
+     * This has to respond within 3 seconds, so keep it fast.
+     */
+    export type AutocompleteProvider<T> = (ctx: AutocompleteContext) => Promise<OptionChoice<T>[]> | OptionChoice<T>[];
+
+    export interface AutocompleteContext {
+        /**
+         * What the user has typed so far in the option being autocompleted
+         */
+        value: string;
+
+        /**
+         * Name of the option being autocompleted
+         */
+        optionName: string;
+
+        /**
+         * Raw values of the other options the user has filled in so far.
+         * 
+         * Users, channels, roles and mentionables are not resolved here, they are just ids.
+         */
+        options: Record<string, unknown>;
+
+        channelId: string;
+        member: Member;
+    }
 
     export type OptionMap = {
         [key: string]: Option,
@@ -259,6 +352,8 @@ export namespace Commands {
         description: string,
         required: boolean,
         extraOptions: StringOption | NumberOption | BooleanOption | ChannelOption | RoleOption | MentionableOption,
+        nameLocalizations?: Localizations,
+        descriptionLocalizations?: Localizations,
     }
 
     export interface StringOption {
@@ -266,7 +361,11 @@ export namespace Commands {
          * A list of choices to present the user, up to 25 entries.
          */
         choices?: OptionChoice<string>[],
-        // autocomplete?: AutocompleteProvider<string>,
+
+        /**
+         * Provide suggestions dynamically as the user types, cannot be combined with choices.
+         */
+        autocomplete?: AutocompleteProvider<string>,
     }
 
     export interface NumberOption {
@@ -278,7 +377,10 @@ export namespace Commands {
         minValue?: number,
         maxValue?: number,
 
-        // // autocomplete?: AutocompleteProvider<number>,
+        /**
+         * Provide suggestions dynamically as the user types, cannot be combined with choices.
+         */
+        autocomplete?: AutocompleteProvider<number>,
     }
 
     export interface IntegerOption {
@@ -290,7 +392,10 @@ export namespace Commands {
         minValue?: number,
         maxValue?: number,
 
-        // // autocomplete?: AutocompleteProvider<number>,
+        /**
+         * Provide suggestions dynamically as the user types, cannot be combined with choices.
+         */
+        autocomplete?: AutocompleteProvider<number>,
     }
 
     export interface BooleanOption {
@@ -316,6 +421,7 @@ export namespace Commands {
     export interface OptionChoice<T> {
         name: string,
         value: T,
+        nameLocalizations?: Localizations,
     }
 
     /**
@@ -331,13 +437,23 @@ export namespace Commands {
 
         subGroups: Group[] = [];
 
+        /**
+         * Members need these permissions to see and use the commands in this group,
+         * server admins can override this in the server settings.
+         */
+        defaultMemberPermissions?: PermissionResolvable;
+        nsfw?: boolean;
+
         /**
          * @param name name of the group as it shows in discord, 1-32 characters (no symbols except - and _)
          * @param description description of the group, 1-100 characters
+         * @param options permissions and nsfw flag of the group, commands inside groups cannot set these themselves
          */
-        constructor(name: string, description: string) {
+        constructor(name: string, description: string, options?: GroupOptions) {
             this.name = name;
             this.description = description;
+            this.defaultMemberPermissions = options?.defaultMemberPermissions;
+            this.nsfw = options?.nsfw;
         }
 
         /**
@@ -361,6 +477,19 @@ export namespace Commands {
         }
     }
 
+    export interface GroupOptions {
+        /**
+         * Members need these permissions to see and use the commands in this group,
+         * server admins can override this in the server settings.
+         */
+        defaultMemberPermissions?: PermissionResolvable,
+
+        /**
+         * Restricts the commands in this group to age-restricted channels
+         */
+        nsfw?: boolean,
+    }
+
     /**
      * Create a new slash command builder
      * @param name name of the command, 1-32 characters, (no symbols except - and _)
@@ -389,6 +518,10 @@ export namespace Commands {
         private group?: Group;
         private ackMode: AckMode = "DeferredMessage";
         private ackMessageFlags: MessageFlags = {}
+        private defaultMemberPermissions?: PermissionResolvable;
+        private nsfw?: boolean;
+        private nameLocalizations?: Localizations;
+        private descriptionLocalizations?: Localizations;
 
         constructor(name: string, description: string, options: OptionMap, group?: Group) {
             this.name = name;
@@ -433,6 +566,43 @@ export namespace Commands {
             return this
         }
 
+        /**
+         * Members need these permissions to see and use this command,
+         * server admins can override this in the server settings.
+         * 
+         * This can't be used on commands in groups, set it on the group instead.
+         */
+        setDefaultMemberPermissions(permissions: PermissionResolvable) {
+            this.defaultMemberPermissions = permissions;
+            return this;
+        }
+
+        /**
+         * Restricts this command to age-restricted channels.
+         * 
+         * This can't be used on commands in groups, set it on the group instead.
+         */
+        setNsfw(nsfw: boolean = true) {
+            this.nsfw = nsfw;
+            return this;
+        }
+
+        /**
+         * Translated names of this command, they follow the same rules as the name itself
+         */
+        setNameLocalizations(localizations: Localizations) {
+            this.nameLocalizations = localizations;
+            return this;
+        }
+
+        /**
+         * Translated descriptions of this command, they follow the same rules as the description itself
+         */
+        setDescriptionLocalizations(localizations: Localizations) {
+            this.descriptionLocalizations = localizations;
+            return this;
+        }
+
         /**
          * See {@link addOption}
          */
@@ -516,14 +686,18 @@ export namespace Commands {
                 required = opts.required;
             }
 
+            const { nameLocalizations, descriptionLocalizations, ...extraOptions } = { ...opts };
+
             let fullOpts = {
                 ...this.options,
                 [name]: {
                     kind: kind,
                     required: required,
                     description: description,
+                    nameLocalizations,
+                    descriptionLocalizations,
                     extraOptions: {
-                        ...opts,
+                        ...extraOptions,
                     }
                 },
             }
@@ -552,6 +726,10 @@ export namespace Commands {
                 group: this.group,
                 ackMode: this.ackMode,
                 ackMessageFlags: this.ackMessageFlags,
+                defaultMemberPermissions: this.defaultMemberPermissions,
+                nsfw: this.nsfw,
+                nameLocalizations: this.nameLocalizations,
+                descriptionLocalizations: this.descriptionLocalizations,
                 cb: callback as any,
             };
         }
@@ -563,6 +741,16 @@ export namespace Commands {
 
     export interface BaseOptionSettings<TRequired extends boolean | undefined = undefined> {
         required?: TRequired
+
+        /**
+         * Translated names of this option, they follow the same rules as the name itself
+         */
+        nameLocalizations?: Localizations,
+
+        /**
+         * Translated descriptions of this option, they follow the same rules as the description itself
+         */
+        descriptionLocalizations?: Localizations,
     }
 
     interface OptionsKindTable {
@@ -606,6 +794,9 @@ export namespace Commands {
         name: string;
         ackMode: AckMode = "DeferredMessage";
         private ackMessageFlags: MessageFlags = {}
+        private defaultMemberPermissions?: PermissionResolvable;
+        private nsfw?: boolean;
+        private nameLocalizations?: Localizations;
 
         constructor(name: string) {
             this.name = name;
@@ -636,6 +827,31 @@ export namespace Commands {
             return this
         }
 
+        /**
+         * Members need these permissions to see and use this command,
+         * server admins can override this in the server settings.
+         */
+        setDefaultMemberPermissions(permissions: PermissionResolvable) {
+            this.defaultMemberPermissions = permissions;
+            return this;
+        }
+
+        /**
+         * Restricts this command to age-restricted channels.
+         */
+        setNsfw(nsfw: boolean = true) {
+            this.nsfw = nsfw;
+            return this;
+        }
+
+        /**
+         * Translated names of this command, they follow the same rules as the name itself
+         */
+        setNameLocalizations(localizations: Localizations) {
+            this.nameLocalizations = localizations;
+            return this;
+        }
+
         build(cb: (ctx: ExecutedCommandContext, target: InteractionUser) => any): Command {
             return {
                 name: this.name,
@@ -644,6 +860,9 @@ export namespace Commands {
                 ackMode: this.ackMode,
                 cb: cb as any,
                 ackMessageFlags: this.ackMessageFlags,
+                defaultMemberPermissions: this.defaultMemberPermissions,
+                nsfw: this.nsfw,
+                nameLocalizations: this.nameLocalizations,
             }
         }
     }
@@ -663,6 +882,9 @@ export namespace Commands {
         name: string;
         ackMode: AckMode = "DeferredMessage";
         private ackMessageFlags: MessageFlags = {}
+        private defaultMemberPermissions?: PermissionResolvable;
+        private nsfw?: boolean;
+        private nameLocalizations?: Localizations;
 
         constructor(name: string) {
             this.name = name;
@@ -693,6 +915,31 @@ export namespace Commands {
             return this
         }
 
+        /**
+         * Members need these permissions to see and use this command,
+         * server admins can override this in the server settings.
+         */
+        setDefaultMemberPermissions(permissions: PermissionResolvable) {
+            this.defaultMemberPermissions = permissions;
+            return this;
+        }
+
+        /**
+         * Restricts this command to age-restricted channels.
+         */
+        setNsfw(nsfw: boolean = true) {
+            this.nsfw = nsfw;
+            return this;
+        }
+
+        /**
+         * Translated names of this command, they follow the same rules as the name itself
+         */
+        setNameLocalizations(localizations: Localizations) {
+            this.nameLocalizations = localizations;
+            return this;
+        }
+
         build(cb: (ctx: ExecutedCommandContext, target: Message) => any): Command {
             return {
                 name: this.name,
@@ -701,6 +948,9 @@ export namespace Commands {
                 ackMode: this.ackMode,
                 cb: cb as any,
                 ackMessageFlags: this.ackMessageFlags,
+                defaultMemberPermissions: this.defaultMemberPermissions,
+                nsfw: this.nsfw,
+                nameLocalizations: this.nameLocalizations,
             }
         }
     }
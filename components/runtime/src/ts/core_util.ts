@@ -46,9 +46,12 @@ function getCaller(skip: number): [string | undefined, number | undefined, numbe
         selected_line = lines[skip + 1];
     }
 
-    // parse it
-    const re = /(file:\/\/\/.+):(\d+):(\d+)/
-    const match = selected_line.match(re);
+    return parseStackLocation(selected_line);
+}
+
+function parseStackLocation(stack: string): [string | undefined, number | undefined, number | undefined] {
+    const re = /(file:\/\/\/.+?):(\d+):(\d+)/
+    const match = stack.match(re);
     if (!match || match.length < 4) {
         return [undefined, undefined, undefined]
     }
@@ -60,6 +63,89 @@ function getCaller(skip: number): [string | undefined, number | undefined, numbe
     log: console.log,
 };
 
+/**
+ * Calls `cb` once after `delayMs` milliseconds, returns an id that can be passed to `clearTimeout` to cancel it.
+ * 
+ * Pending timers keep your vm running for up to a limited time after the last event it handled, which depends on your premium tier
+ * (60 seconds without premium). Creating a timer that would fire after that throws, use scheduled tasks for anything longer.
+ * 
+ * Timers are cancelled when the vm shuts down or your scripts are reloaded.
+ */
+export function setTimeout(cb: () => any, delayMs: number): number {
+    const rid = OpWrappers.timers.createTimer(Math.max(0, Math.floor(delayMs)), false);
+    OpWrappers.timers.waitTimer(rid).then(async (fired) => {
+        if (fired) {
+            await runTimerCallback(cb);
+        }
+    });
+
+    return rid;
+}
+
+/**
+ * Cancels a timer created with `setTimeout`
+ */
+export function clearTimeout(id: number) {
+    OpWrappers.timers.cancelTimer(id);
+}
+
+/**
+ * Calls `cb` every `intervalMs` milliseconds, returns an id that can be passed to `clearInterval` to stop it.
+ * 
+ * Intervals are stopped automatically once the next tick would be past the max timer duration after the last event
+ * (60 seconds without premium, depends on your premium tier), when the vm shuts down or when your scripts are reloaded.
+ */
+export function setInterval(cb: () => any, intervalMs: number): number {
+    const rid = OpWrappers.timers.createTimer(Math.max(1, Math.floor(intervalMs)), true);
+
+    (async () => {
+        while (await OpWrappers.timers.waitTimer(rid)) {
+            await runTimerCallback(cb);
+        }
+    })();
+
+    return rid;
+}
+
+/**
+ * Stops an interval created with `setInterval`
+ */
+export function clearInterval(id: number) {
+    OpWrappers.timers.cancelTimer(id);
+}
+
+// runs a timer callback, reporting anything it throws or rejects with to the guild log
+// since there's nothing else awaiting it
+async function runTimerCallback(cb: () => any) {
+    try {
+        await cb();
+    } catch (e) {
+        let [file, line, col] = e instanceof Error && e.stack ? parseStackLocation(e.stack) : [undefined, undefined, undefined];
+
+        OpWrappers.consoleLogError({
+            message: "Uncaught error in timer callback: " + (e instanceof Error && e.stack ? e.stack : e),
+            fileName: file,
+            lineNumber: line,
+            colNumber: col,
+        })
+    }
+}
+
+/**
+ * Returns a promise that resolves after `delayMs` milliseconds, see `setTimeout` for limits.
+ */
+export function sleep(delayMs: number): Promise<void> {
+    return new Promise((resolve) => {
+        setTimeout(resolve, delayMs);
+    });
+}
+
+(globalThis as any).setTimeout = setTimeout;
+(globalThis as any).clearTimeout = clearTimeout;
+(globalThis as any).setInterval = setInterval;
+(globalThis as any).clearInterval = clearInterval;
+(globalThis as any).sleep = sleep;
+
 /**
  * Encode a string to its Uint8Array representation.
  */
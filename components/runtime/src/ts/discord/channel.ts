@@ -3,6 +3,10 @@ import { Member } from "./member";
 import type { IPermissionOverwrite } from "../generated/discord/IPermissionOverwrite";
 import type { ThreadMetadata } from "../generated/discord/ThreadMetadata";
 import type { VideoQualityMode } from "../generated/discord/VideoQualityMode";
+import type { ForumDefaultReaction } from "../generated/discord/ForumDefaultReaction";
+import type { ForumSortOrder } from "../generated/discord/ForumSortOrder";
+import type { ForumTag } from "../generated/discord/ForumTag";
+import type { IForumTagFields } from "../generated/internal/IForumTagFields";
 import type { ChannelType } from "../generated/discord/ChannelType";
 
 import type { InternalGuildChannel } from "../generated/internal/GuildChannel";
@@ -144,12 +148,30 @@ export class TextChannel extends BaseChannel {
     rateLimitPerUser: number | null;
     topic: string | null;
 
+    /**
+     * Tags that can be applied to threads in this channel, only set on forum channels
+     */
+    availableTags: ForumTag[];
+
+    /**
+     * The emoji shown in the add reaction button on threads in this channel, only set on forum channels
+     */
+    defaultReactionEmoji: ForumDefaultReaction | null;
+
+    /**
+     * How threads in this channel are sorted by default, only set on forum channels
+     */
+    defaultSortOrder: ForumSortOrder | null;
+
     /**
      * @internal
      */
     constructor(json: ITextChannel) {
         super(json);
 
+        this.availableTags = json.availableTags;
+        this.defaultReactionEmoji = json.defaultReactionEmoji;
+        this.defaultSortOrder = json.defaultSortOrder;
         this.kind = json.kind;
         this.lastPinTimestamp = json.lastPinTimestamp;
         this.nsfw = json.nsfw;
@@ -191,6 +213,62 @@ export class TextChannel extends BaseChannel {
             channelId: this.id
         })
     }
+
+    /**
+     * Finds a tag in this forum by its name (case insensitive)
+     */
+    findTag(name: string): ForumTag | undefined {
+        const lower = name.toLowerCase();
+        return this.availableTags.find(v => v.name.toLowerCase() === lower)
+    }
+
+    /**
+     * Creates a new tag in this forum channel
+     * 
+     * @returns The updated channel, the new tag will be in {@link availableTags}
+     */
+    createTag(fields: Omit<IForumTagFields, "id">) {
+        return this.updateTags([...this.tagsAsFields(), { ...fields }])
+    }
+
+    /**
+     * Edits an existing tag in this forum channel, fields you don't set will not be changed
+     */
+    editTag(tagId: string, fields: Partial<Omit<IForumTagFields, "id">>) {
+        const tags = this.tagsAsFields();
+        const index = tags.findIndex(v => v.id === tagId);
+        if (index === -1) {
+            throw new Error(`Unknown tag: ${tagId}`)
+        }
+
+        tags[index] = { ...tags[index], ...fields, id: tagId };
+        return this.updateTags(tags)
+    }
+
+    /**
+     * Deletes a tag from this forum channel, it will also be removed from threads it was applied to
+     */
+    deleteTag(tagId: string) {
+        return this.updateTags(this.tagsAsFields().filter(v => v.id !== tagId))
+    }
+
+    private tagsAsFields(): IForumTagFields[] {
+        return this.availableTags.map(v => ({
+            id: v.id,
+            name: v.name,
+            moderated: v.moderated,
+            emojiId: v.emojiId ?? undefined,
+            emojiName: v.emojiName ?? undefined,
+        }))
+    }
+
+    private async updateTags(tags: IForumTagFields[]): Promise<TextChannel> {
+        if (this.kind !== "Forum") {
+            throw new Error(`This channel is not a forum: ${this.id}`)
+        }
+
+        return await editChannel(this.id, { availableTags: tags }) as TextChannel
+    }
 }
 
 export type AutoArchiveMinutes = 60 | 1440 | 4320 | 10080
@@ -278,11 +356,53 @@ export class PrivateThread extends Thread {
 export class PublicThread extends Thread {
     kind: "PublicThread" = "PublicThread";
 
+    /**
+     * Ids of the forum tags applied to this thread
+     */
+    appliedTags: string[];
+
     /**
      * @internal
      */
     constructor(json: IPublicThread) {
         super(json);
+
+        this.appliedTags = json.appliedTags;
+    }
+
+    /**
+     * Replaces the tags applied to this thread, only available on threads in forum channels (max 5)
+     */
+    setTags(tagIds: string[]) {
+        return this.edit({
+            tagIds,
+        })
+    }
+
+    /**
+     * Applies a tag to this thread, keeping the current ones
+     * 
+     * @example ```ts
+     * const forum = await Discord.getChannel(thread.parentId!) as Discord.TextChannel;
+     * const solved = forum.findTag("Solved");
+     * if (solved) {
+     *     await thread.addTag(solved.id);
+     * }
+     * ```
+     */
+    async addTag(tagId: string): Promise<Thread> {
+        if (this.appliedTags.includes(tagId)) {
+            return this
+        }
+
+        return await this.setTags([...this.appliedTags, tagId])
+    }
+
+    /**
+     * Removes a tag from this thread
+     */
+    removeTag(tagId: string) {
+        return this.setTags(this.appliedTags.filter(v => v !== tagId))
     }
 }
 
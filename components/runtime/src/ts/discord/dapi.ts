@@ -1,14 +1,17 @@
-import { Guild, Role, Embed, IComponent, AuditLogExtras, SendEmoji, IPermissionOverwrite, VideoQualityMode, ChannelType, PermissionOverwriteType, InviteTargetType } from '../generated/discord/index';
+import { Guild, Role, Embed, AuditLogEntry, AuditLogActionType, AutoModerationRule, AutoModerationAction, AutoModerationEventType, AutoModerationTriggerType, AutoModerationTriggerMetadata, ScheduledEventEntityType, ScheduledEventStatus, IComponent, AuditLogExtras, SendEmoji, IPermissionOverwrite, VideoQualityMode, ChannelType, PermissionOverwriteType, InviteTargetType, VerificationLevel, DefaultMessageNotificationLevel, ForumDefaultReaction, ForumSortOrder } from '../generated/discord/index';
 import * as Internal from '../generated/internal/index';
 import { OpWrappers } from '../op_wrappers';
 import { GuildChannel, Thread, ThreadMember, guildChannelFromInternal, threadChannelFromInternal } from './channel';
 import type { AutoArchiveMinutes } from './channel';
+import { CustomEmoji, Sticker } from './emoji';
 import { VoiceState } from './events';
 import { Invite } from './invite';
 import { Ban, Member } from './member';
 import { Message } from './message';
-import { Permissions } from './permissions';
+import { Permissions, PermissionResolvable } from './permissions';
 import { User } from './user';
+import { Webhook } from './webhook';
+import { ScheduledEvent, ScheduledEventUser } from './scheduled_event';
 
 /**
  * @returns Botloader's discord user 
@@ -32,7 +35,80 @@ export function getGuild(): Promise<Guild> {
     })
 }
 
-function editGuild() { }
+export interface GuildImage {
+    /**
+     * The raw image data
+     * 
+     * You can for example get this using the http client: `await (await HttpClient.get(url)).readAll()`
+     */
+    data: Uint8Array;
+
+    /**
+     * Mime type of the image, one of "image/png", "image/jpeg", "image/gif" or "image/webp"
+     */
+    contentType: string;
+}
+
+export interface IEditGuild extends AuditLogExtras {
+    name?: string;
+
+    /**
+     * The new icon of the server, set to null to remove it
+     */
+    icon?: GuildImage | null;
+
+    /**
+     * The new banner of the server, set to null to remove it
+     * 
+     * Requires the server to have the banner feature
+     */
+    banner?: GuildImage | null;
+
+    /**
+     * The channel system messages (such as member joins and boosts) are sent to, set to null to disable them
+     */
+    systemChannelId?: string | null;
+
+    /**
+     * The voice channel inactive members are moved to, set to null to disable
+     */
+    afkChannelId?: string | null;
+
+    /**
+     * How long a member has to be inactive before being moved to the afk channel
+     */
+    afkTimeoutSeconds?: 60 | 300 | 900 | 1800 | 3600;
+
+    verificationLevel?: VerificationLevel;
+    defaultMessageNotifications?: DefaultMessageNotificationLevel;
+}
+
+/**
+ * Edit the settings of the server
+ * 
+ * Requires the bot to have the "ManageGuild" permission, every change is recorded in the guild log
+ */
+export async function editGuild(fields: IEditGuild): Promise<void> {
+    await OpWrappers.callAsyncOp({
+        kind: "discord_edit_guild",
+        arg: {
+            ...fields,
+            icon: toInternalGuildImage(fields.icon),
+            banner: toInternalGuildImage(fields.banner),
+        }
+    })
+}
+
+function toInternalGuildImage(image: GuildImage | null | undefined): Internal.IGuildImage | null | undefined {
+    if (!image) {
+        return image
+    }
+
+    return {
+        imageData: image.data,
+        contentType: image.contentType,
+    }
+}
 
 /**
  * @returns A list of the invites on your server
@@ -103,6 +179,32 @@ export interface CreateMessageFields {
     allowedMentions?: AllowedMentions;
 
     components?: IComponent[],
+
+    /**
+     * Files to upload with the message.
+     * 
+     * When editing a message this replaces the existing attachments.
+     * 
+     * The total size of all attachments is limited depending on your guild's plan (1MB without premium, 8MB with premium)
+     */
+    attachments?: MessageAttachment[],
+}
+
+export interface MessageAttachment {
+    /**
+     * Name of the file, including the extension (e.g. "transcript.txt")
+     */
+    filename: string;
+
+    /**
+     * Alt text for the attachment
+     */
+    description?: string;
+
+    /**
+     * Contents of the file, use `encodeText` to upload text
+     */
+    data: Uint8Array;
 }
 
 export interface InteractionCreateMessageFields extends CreateMessageFields {
@@ -164,21 +266,56 @@ export function toOpMessageFields(fields: CreateMessageFields): Internal.OpCreat
     return {
         ...fields,
         allowedMentions: allowedMentions!,
+        attachments: fields.attachments?.map(v => ({
+            filename: v.filename,
+            description: v.description,
+            data: v.data,
+        })),
     }
 }
 
 export type MentionParseTypes = "Everyone" | "Roles" | "Users";
 
-export async function createMessage(channelId: string, fields: CreateMessageFields): Promise<Message> {
+export interface CreateChannelMessageFields extends CreateMessageFields {
+    /**
+     * Attach a poll to the message, polls can't be combined with attachments.
+     * 
+     * Results are available through `Message.poll` on fetched messages, and votes are dispatched through the
+     * MESSAGE_POLL_VOTE_ADD and MESSAGE_POLL_VOTE_REMOVE events.
+     */
+    poll?: Internal.ICreatePoll;
+}
+
+export async function createMessage(channelId: string, fields: CreateChannelMessageFields): Promise<Message> {
+    const { poll, ...rest } = fields;
 
     return new Message(await OpWrappers.callAsyncOp({
         kind: "discord_create_message",
         arg: {
             channelId,
+            fields: toOpMessageFields(rest),
+            poll,
+        }
+    }));
+}
+
+/**
+ * Sends a direct message to a member of this server.
+ * 
+ * This has to be enabled in the server settings on the website, and is heavily ratelimited (2 messages per minute without premium).
+ * 
+ * Fails if the user is not a member of this server, or if the user has direct messages from server members turned off.
+ */
+export async function sendDirectMessage(userId: string, fields: CreateMessageFields): Promise<Message> {
+    return new Message(await OpWrappers.callAsyncOp({
+        kind: "discord_send_direct_message",
+        arg: {
+            userId,
             fields: toOpMessageFields(fields),
         }
     }));
 }
+
 export async function editMessage(channelId: string, messageId: string, fields: CreateMessageFields): Promise<Message> {
     return new Message(await OpWrappers.callAsyncOp({
         kind: "discord_edit_message",
@@ -226,9 +363,115 @@ export function getRoles(): Promise<Role[]> {
     return OpWrappers.getRoles();
 }
 
-async function createRole() { }
-async function editRole() { }
-async function deleteRole() { }
+export interface ICreateRole extends AuditLogExtras {
+    /**
+     * Name of the role, defaults to "new role"
+     */
+    name?: string;
+
+    /**
+     * RGB color value, defaults to 0 (no color)
+     */
+    color?: number;
+
+    /**
+     * Whether the role should be displayed separately in the member list
+     */
+    hoist?: boolean;
+
+    /**
+     * Whether the role can be mentioned by everyone
+     */
+    mentionable?: boolean;
+
+    /**
+     * Defaults to the permissions of the everyone role
+     */
+    permissions?: PermissionResolvable;
+
+    unicodeEmoji?: string;
+}
+
+/**
+ * Creates a new role, the role will be placed at the bottom of the role list.
+ *
+ * Use {@link updateRolePositions} to move it.
+ */
+export async function createRole(fields: ICreateRole): Promise<Role> {
+    const { permissions, ...rest } = fields;
+
+    return OpWrappers.callAsyncOp({
+        kind: "discord_create_role",
+        arg: {
+            ...rest,
+            permissionsRaw: permissions !== undefined ? Permissions.resolve(permissions).toString() : undefined,
+        }
+    })
+}
+
+/**
+ * All fields are optional, fields you don't set will not be changed.
+ */
+export interface IEditRole extends AuditLogExtras {
+    name?: string;
+
+    /**
+     * RGB color value, set to null to remove the color
+     */
+    color?: number | null;
+
+    hoist?: boolean;
+    mentionable?: boolean;
+    permissions?: PermissionResolvable;
+
+    /**
+     * Set to null to remove the emoji
+     */
+    unicodeEmoji?: string | null;
+}
+
+/**
+ * Edits a role, the role has to be below the bot's highest role.
+ */
+export async function editRole(roleId: string, fields: IEditRole): Promise<Role> {
+    const { permissions, ...rest } = fields;
+
+    return OpWrappers.callAsyncOp({
+        kind: "discord_edit_role",
+        arg: {
+            ...rest,
+            roleId,
+            permissionsRaw: permissions !== undefined ? Permissions.resolve(permissions).toString() : undefined,
+        }
+    })
+}
+
+/**
+ * Deletes a role, the role has to be below the bot's highest role.
+ */
+export async function deleteRole(roleId: string, extras?: AuditLogExtras): Promise<void> {
+    await OpWrappers.callAsyncOp({
+        kind: "discord_delete_role",
+        arg: [roleId, extras ?? {}],
+    })
+}
+
+export interface IRolePosition {
+    roleId: string;
+    position: number;
+}
+
+/**
+ * Moves one or more roles, the roles and their new positions have to be below the bot's highest role.
+ *
+ * @returns All the roles on the server with their updated positions
+ */
+export async function updateRolePositions(positions: IRolePosition[]): Promise<Role[]> {
+    return OpWrappers.callAsyncOp({
+        kind: "discord_update_role_positions",
+        arg: positions,
+    })
+}
 
 // Channel functions
 export async function getChannel(channelId: string): Promise<GuildChannel> {
@@ -258,6 +501,21 @@ export interface ICreateChannel {
     rateLimitPerUser?: number;
     topic?: string;
     userLimit?: number;
+
+    /**
+     * Tags that can be applied to threads in the forum, only valid when creating forum channels (max 20)
+     */
+    availableTags?: Internal.IForumTagFields[];
+
+    /**
+     * The emoji shown in the add reaction button on threads in the forum, only valid when creating forum channels
+     */
+    defaultReactionEmoji?: ForumDefaultReaction;
+
+    /**
+     * How threads in the forum are sorted by default, only valid when creating forum channels
+     */
+    defaultSortOrder?: ForumSortOrder;
 }
 
 export async function createChannel(fields: ICreateChannel): Promise<GuildChannel> {
@@ -287,6 +545,26 @@ export interface IEditChannel {
     topic?: string;
     userLimit?: number;
     videoQualityMode?: VideoQualityMode;
+
+    /**
+     * Replaces the tags available in a forum channel (max 20).
+     * 
+     * Tags with an id update the existing tag, tags without one are created 
+     * and existing tags left out are deleted.
+     * 
+     * See {@link TextChannel.createTag}, {@link TextChannel.editTag} and {@link TextChannel.deleteTag} for managing a single tag.
+     */
+    availableTags?: Internal.IForumTagFields[];
+
+    /**
+     * The emoji shown in the add reaction button on threads in a forum channel, set to null to remove it
+     */
+    defaultReactionEmoji?: ForumDefaultReaction | null;
+
+    /**
+     * How threads in a forum channel are sorted by default
+     */
+    defaultSortOrder?: ForumSortOrder;
 }
 
 export async function editChannel(channelId: string, fields: IEditChannel): Promise<GuildChannel> {
@@ -384,19 +662,419 @@ export async function deletePin(channelId: string, messageId: string): Promise<v
 }
 
 // Emoji functions
-async function getEmoji() { }
-async function getEmojis() { }
-async function createEmoji() { }
-async function editEmoji() { }
-async function deleteEmoji() { }
+export async function getEmoji(emojiId: string): Promise<CustomEmoji> {
+    return new CustomEmoji(await OpWrappers.callAsyncOp({
+        kind: "discord_get_emoji",
+        arg: emojiId,
+    }))
+}
 
+export async function getEmojis(): Promise<CustomEmoji[]> {
+    return (await OpWrappers.callAsyncOp({
+        kind: "discord_get_emojis",
+        arg: null,
+    })).map(v => new CustomEmoji(v))
+}
+
+export interface ICreateEmoji extends AuditLogExtras {
+    name: string;
+
+    /**
+     * The raw image data, max 256KiB
+     * 
+     * You can for example get this using the http client: `await (await HttpClient.get(url)).readAll()`
+     */
+    image: Uint8Array;
+
+    /**
+     * Mime type of the image, one of "image/png", "image/jpeg", "image/gif" or "image/webp"
+     */
+    contentType: string;
+
+    /**
+     * Roles allowed to use this emoji, defaults to everyone
+     */
+    roles?: string[];
+}
+
+export async function createEmoji(fields: ICreateEmoji): Promise<CustomEmoji> {
+    return new CustomEmoji(await OpWrappers.callAsyncOp({
+        kind: "discord_create_emoji",
+        arg: {
+            name: fields.name,
+            imageData: fields.image,
+            contentType: fields.contentType,
+            roles: fields.roles,
+            auditLogReason: fields.auditLogReason,
+        }
+    }))
+}
+
+export interface IEditEmoji extends AuditLogExtras {
+    name?: string;
+
+    /**
+     * Roles allowed to use this emoji, set to an empty array to allow everyone
+     */
+    roles?: string[];
+}
+
+export async function editEmoji(emojiId: string, fields: IEditEmoji): Promise<CustomEmoji> {
+    return new CustomEmoji(await OpWrappers.callAsyncOp({
+        kind: "discord_edit_emoji",
+        arg: {
+            ...fields,
+            emojiId,
+        }
+    }))
+}
+
+export async function deleteEmoji(emojiId: string, extras?: AuditLogExtras): Promise<void> {
+    await OpWrappers.callAsyncOp({
+        kind: "discord_delete_emoji",
+        arg: [emojiId, extras ?? {}],
+    })
+}
 
 // Sticker functions
-async function getSticker() { }
-async function getStickers() { }
-async function createSticker() { }
-async function editSticker() { }
-async function deleteSticker() { }
+export async function getSticker(stickerId: string): Promise<Sticker> {
+    return new Sticker(await OpWrappers.callAsyncOp({
+        kind: "discord_get_sticker",
+        arg: stickerId,
+    }))
+}
+
+export async function getStickers(): Promise<Sticker[]> {
+    return (await OpWrappers.callAsyncOp({
+        kind: "discord_get_stickers",
+        arg: null,
+    })).map(v => new Sticker(v))
+}
+
+export interface ICreateSticker extends AuditLogExtras {
+    name: string;
+    description: string;
+
+    /**
+     * Autocomplete/suggestion tags for the sticker (max 200 characters)
+     */
+    tags: string;
+
+    /**
+     * The raw png, apng or lottie json file, max 512KiB
+     * 
+     * You can for example get this using the http client: `await (await HttpClient.get(url)).readAll()`
+     */
+    file: Uint8Array;
+}
+
+export async function createSticker(fields: ICreateSticker): Promise<Sticker> {
+    return new Sticker(await OpWrappers.callAsyncOp({
+        kind: "discord_create_sticker",
+        arg: {
+            name: fields.name,
+            description: fields.description,
+            tags: fields.tags,
+            fileData: fields.file,
+            auditLogReason: fields.auditLogReason,
+        }
+    }))
+}
+
+export interface IEditSticker extends AuditLogExtras {
+    name?: string;
+    description?: string;
+    tags?: string;
+}
+
+export async function editSticker(stickerId: string, fields: IEditSticker): Promise<Sticker> {
+    return new Sticker(await OpWrappers.callAsyncOp({
+        kind: "discord_edit_sticker",
+        arg: {
+            ...fields,
+            stickerId,
+        }
+    }))
+}
+
+export async function deleteSticker(stickerId: string, extras?: AuditLogExtras): Promise<void> {
+    await OpWrappers.callAsyncOp({
+        kind: "discord_delete_sticker",
+        arg: [stickerId, extras ?? {}],
+    })
+}
+
+// Webhook functions
+export interface ICreateWebhook extends AuditLogExtras {
+    /**
+     * Name of the webhook (1-80 characters)
+     */
+    name: string;
+}
+
+/**
+ * Creates a webhook in the provided channel
+ * 
+ * The webhook token is never exposed to scripts, it's kept by botloader and used when you call {@link executeWebhook}
+ */
+export async function createWebhook(channelId: string, fields: ICreateWebhook): Promise<Webhook> {
+    return new Webhook(await OpWrappers.callAsyncOp({
+        kind: "discord_create_webhook",
+        arg: {
+            ...fields,
+            channelId,
+        }
+    }))
+}
+
+export async function getChannelWebhooks(channelId: string): Promise<Webhook[]> {
+    return (await OpWrappers.callAsyncOp({
+        kind: "discord_get_channel_webhooks",
+        arg: channelId,
+    })).map(v => new Webhook(v))
+}
+
+/**
+ * Deletes a webhook, only webhooks created through {@link createWebhook} on this guild can be deleted
+ */
+export async function deleteWebhook(webhookId: string, extras?: AuditLogExtras): Promise<void> {
+    await OpWrappers.callAsyncOp({
+        kind: "discord_delete_webhook",
+        arg: [webhookId, extras ?? {}],
+    })
+}
+
+export interface IExecuteWebhook extends CreateMessageFields {
+    /**
+     * Send the message to this thread, the thread has to be in the webhook's channel
+     */
+    threadId?: string;
+
+    /**
+     * Override the default username of the webhook
+     */
+    username?: string;
+
+    /**
+     * Override the default avatar of the webhook
+     */
+    avatarUrl?: string;
+
+    /**
+     * Wait for the message to be created, and return it
+     */
+    wait?: boolean;
+}
+
+/**
+ * Sends a message through a webhook in this guild
+ * 
+ * Only webhooks created through {@link createWebhook} on this guild can be executed
+ * 
+ * @returns The created message if `wait` was set, undefined otherwise
+ */
+export async function executeWebhook(webhookId: string, fields: IExecuteWebhook): Promise<Message | undefined> {
+    const { threadId, username, avatarUrl, wait, ...messageFields } = fields;
+
+    const resp = await OpWrappers.callAsyncOp({
+        kind: "discord_execute_webhook",
+        arg: {
+            webhookId,
+            threadId,
+            username,
+            avatarUrl,
+            wait: wait ?? false,
+            fields: toOpMessageFields(messageFields),
+        }
+    })
+
+    return resp ? new Message(resp) : undefined;
+}
+
+// Audit log functions
+export interface IGetAuditLog {
+    /**
+     * Only return entries for actions made by this user
+     */
+    userId?: string;
+    actionType?: AuditLogActionType;
+
+    /**
+     * Return entries before this entry id, used for pagination
+     */
+    before?: string;
+
+    /**
+     * Return entries after this entry id, used for pagination
+     */
+    after?: string;
+
+    /**
+     * Max number of entries to return (1-100, default 50)
+     */
+    limit?: number;
+}
+
+export interface IAuditLog {
+    entries: AuditLogEntry[];
+
+    /**
+     * Users referenced in the returned entries
+     */
+    users: User[];
+}
+
+/**
+ * Fetches entries from the guild's audit log, newest first
+ * 
+ * Requires the bot to have the "view audit log" permission
+ */
+export async function getAuditLog(options?: IGetAuditLog): Promise<IAuditLog> {
+    const resp = await OpWrappers.callAsyncOp({
+        kind: "discord_get_audit_log",
+        arg: options ?? {},
+    })
+
+    return {
+        entries: resp.entries,
+        users: resp.users.map(v => new User(v)),
+    }
+}
+
+// Scheduled event functions
+export async function getScheduledEvents(): Promise<ScheduledEvent[]> {
+    return (await OpWrappers.callAsyncOp({
+        kind: "discord_get_scheduled_events",
+        arg: null,
+    })).map(v => new ScheduledEvent(v))
+}
+
+export async function getScheduledEvent(eventId: string): Promise<ScheduledEvent> {
+    return new ScheduledEvent(await OpWrappers.callAsyncOp({
+        kind: "discord_get_scheduled_event",
+        arg: eventId,
+    }))
+}
+
+export interface ICreateScheduledEvent extends AuditLogExtras {
+    /**
+     * Name of the event (1-100 characters)
+     */
+    name: string;
+    entityType: ScheduledEventEntityType;
+
+    /**
+     * The voice or stage channel to host the event in, required for voice and stage instance events
+     */
+    channelId?: string;
+
+    /**
+     * Location of the event, required for external events
+     */
+    location?: string;
+
+    /**
+     * Unix timestamp in milliseconds, or a Date
+     */
+    scheduledStartTime: number | Date;
+
+    /**
+     * Unix timestamp in milliseconds, or a Date, required for external events
+     */
+    scheduledEndTime?: number | Date;
+    description?: string;
+}
+
+/**
+ * Creates a scheduled event in the server
+ * 
+ * You can combine this with scheduled tasks (see `Tasks.schedule`) to send reminders before the event starts
+ */
+export async function createScheduledEvent(fields: ICreateScheduledEvent): Promise<ScheduledEvent> {
+    return new ScheduledEvent(await OpWrappers.callAsyncOp({
+        kind: "discord_create_scheduled_event",
+        arg: {
+            ...fields,
+            scheduledStartTime: toTimestamp(fields.scheduledStartTime),
+            scheduledEndTime: fields.scheduledEndTime === undefined ? undefined : toTimestamp(fields.scheduledEndTime),
+        }
+    }))
+}
+
+export interface IEditScheduledEvent extends AuditLogExtras {
+    name?: string;
+    description?: string;
+    entityType?: ScheduledEventEntityType;
+    channelId?: string;
+    location?: string;
+    scheduledStartTime?: number | Date;
+    scheduledEndTime?: number | Date;
+
+    /**
+     * Scheduled events can be started (Active), ended (Completed) or cancelled (Cancelled)
+     */
+    status?: ScheduledEventStatus;
+}
+
+export async function editScheduledEvent(eventId: string, fields: IEditScheduledEvent): Promise<ScheduledEvent> {
+    return new ScheduledEvent(await OpWrappers.callAsyncOp({
+        kind: "discord_edit_scheduled_event",
+        arg: {
+            ...fields,
+            eventId,
+            scheduledStartTime: fields.scheduledStartTime === undefined ? undefined : toTimestamp(fields.scheduledStartTime),
+            scheduledEndTime: fields.scheduledEndTime === undefined ? undefined : toTimestamp(fields.scheduledEndTime),
+        }
+    }))
+}
+
+/**
+ * Cancels a scheduled event, shorthand for editing the status of it to "Cancelled"
+ */
+export async function cancelScheduledEvent(eventId: string, extras?: AuditLogExtras): Promise<ScheduledEvent> {
+    return await editScheduledEvent(eventId, { ...extras, status: "Cancelled" })
+}
+
+export async function deleteScheduledEvent(eventId: string): Promise<void> {
+    await OpWrappers.callAsyncOp({
+        kind: "discord_delete_scheduled_event",
+        arg: eventId,
+    })
+}
+
+export interface IGetScheduledEventUsers {
+    /**
+     * User id to return users after, used for pagination
+     */
+    after?: string;
+
+    /**
+     * User id to return users before, used for pagination
+     */
+    before?: string;
+
+    /**
+     * Max number of users to return (1-100, default 100)
+     */
+    limit?: number;
+}
+
+/**
+ * Returns the users interested in the provided event
+ */
+export async function getScheduledEventUsers(eventId: string, options?: IGetScheduledEventUsers): Promise<ScheduledEventUser[]> {
+    return (await OpWrappers.callAsyncOp({
+        kind: "discord_get_scheduled_event_users",
+        arg: {
+            ...options,
+            eventId,
+        }
+    })).map(v => new ScheduledEventUser(v))
+}
+
+function toTimestamp(v: number | Date): number {
+    return v instanceof Date ? v.getTime() : v;
+}
 
 export async function getMember(id: string): Promise<Member | undefined> {
     const member = (await OpWrappers.getMembers([id]))[0];
@@ -411,6 +1089,38 @@ export async function getMembers(ids: string[]): Promise<(Member | null)[]> {
     return (await OpWrappers.getMembers(ids)).map(v => v ? new Member(v) : null);
 }
 
+export interface SearchMembersOptions {
+    /**
+     * Max number of members to return, between 1 and 1000
+     * 
+     * @default 25
+     */
+    limit?: number;
+
+    /**
+     * Also match names containing the query, or containing its characters in order,
+     * instead of only names starting with it
+     * 
+     * Note that this only works for members in botloader's cache, if the cache may be missing
+     * members then the results are filled up using discord's search, which only matches by prefix
+     */
+    fuzzy?: boolean;
+}
+
+/**
+ * Search for members by their username, global name or nickname
+ */
+export async function searchMembers(query: string, options?: SearchMembersOptions): Promise<Member[]> {
+    return (await OpWrappers.callAsyncOp({
+        kind: "discord_search_members",
+        arg: {
+            query,
+            limit: options?.limit,
+            fuzzy: options?.fuzzy,
+        }
+    })).map(v => new Member(v))
+}
+
 /**
  * Fields that are not provided will be left unchanged.
  */
@@ -757,6 +1467,11 @@ export interface IEditThread {
      */
     channelId: string;
 
+    /**
+     * The tags applied to the thread, replacing the current ones (max 5).
+     * 
+     * Only available on threads in forum channels, pass an empty array to remove all tags.
+     */
     tagIds?: string[];
 
     /**
@@ -903,4 +1618,85 @@ export async function getPrivateArchivedThreads(options: IListThreads): Promise<
         members: resp.members.map(v => new ThreadMember(v)),
         threads: resp.threads.map(v => threadChannelFromInternal(v))
     }
-}
\ No newline at end of file
+}
+
+// Auto moderation functions
+export async function getAutoModerationRules(): Promise<AutoModerationRule[]> {
+    return await OpWrappers.callAsyncOp({
+        kind: "discord_get_auto_moderation_rules",
+        arg: null,
+    })
+}
+
+export async function getAutoModerationRule(ruleId: string): Promise<AutoModerationRule> {
+    return await OpWrappers.callAsyncOp({
+        kind: "discord_get_auto_moderation_rule",
+        arg: ruleId,
+    })
+}
+
+export interface ICreateAutoModerationRule extends AuditLogExtras {
+    name: string;
+    eventType: AutoModerationEventType;
+
+    /**
+     * Discord limits how many rules of each trigger type a server can have,
+     * e.g. only 1 Spam and 1 KeywordPreset rule
+     */
+    triggerType: AutoModerationTriggerType;
+
+    /**
+     * Required for Keyword, KeywordPreset and MentionSpam rules
+     */
+    triggerMetadata?: AutoModerationTriggerMetadata;
+
+    /**
+     * SendAlertMessage actions require a channelId and Timeout actions require durationSeconds
+     */
+    actions: AutoModerationAction[];
+    enabled?: boolean;
+
+    /**
+     * Role ids that should not be affected by this rule (max 20)
+     */
+    exemptRoles?: string[];
+
+    /**
+     * Channel ids that should not be affected by this rule (max 50)
+     */
+    exemptChannels?: string[];
+}
+
+export async function createAutoModerationRule(fields: ICreateAutoModerationRule): Promise<AutoModerationRule> {
+    return await OpWrappers.callAsyncOp({
+        kind: "discord_create_auto_moderation_rule",
+        arg: fields,
+    })
+}
+
+export interface IEditAutoModerationRule extends AuditLogExtras {
+    name?: string;
+    eventType?: AutoModerationEventType;
+    triggerMetadata?: AutoModerationTriggerMetadata;
+    actions?: AutoModerationAction[];
+    enabled?: boolean;
+    exemptRoles?: string[];
+    exemptChannels?: string[];
+}
+
+export async function editAutoModerationRule(ruleId: string, fields: IEditAutoModerationRule): Promise<AutoModerationRule> {
+    return await OpWrappers.callAsyncOp({
+        kind: "discord_edit_auto_moderation_rule",
+        arg: {
+            ...fields,
+            ruleId,
+        }
+    })
+}
+
+export async function deleteAutoModerationRule(ruleId: string, extras?: AuditLogExtras): Promise<void> {
+    await OpWrappers.callAsyncOp({
+        kind: "discord_delete_auto_moderation_rule",
+        arg: [ruleId, extras ?? {}],
+    })
+}
@@ -0,0 +1,92 @@
+import type { ICustomEmoji } from "../generated/internal/ICustomEmoji";
+import type { ISticker } from "../generated/internal/ISticker";
+import type { StickerFormatType } from "../generated/discord/StickerFormatType";
+import type { StickerType } from "../generated/discord/StickerType";
+import type { SendEmoji } from "../generated/discord/SendEmoji";
+import { User } from "./user";
+
+export class CustomEmoji {
+    animated: boolean;
+    available: boolean;
+    id: string;
+    managed: boolean;
+    name: string;
+    requireColons: boolean;
+
+    /**
+     * Roles allowed to use this emoji, if empty everyone can use it
+     */
+    roles: string[];
+
+    /**
+     * The user that created this emoji, only present if the bot has the "manage emojis and stickers" permission
+     */
+    user?: User;
+
+    /**
+     * @internal
+     */
+    constructor(json: ICustomEmoji) {
+        this.animated = json.animated;
+        this.available = json.available;
+        this.id = json.id;
+        this.managed = json.managed;
+        this.name = json.name;
+        this.requireColons = json.requireColons;
+        this.roles = json.roles;
+        this.user = json.user ? new User(json.user) : undefined;
+    }
+
+    /**
+     * @returns The emoji in a format you can put in message content
+     */
+    mention() {
+        return `<${this.animated ? "a" : ""}:${this.name}:${this.id}>`;
+    }
+
+    /**
+     * @returns The emoji in a format you can use with reactions
+     */
+    toSendEmoji(): SendEmoji {
+        return { id: this.id, name: this.name };
+    }
+
+    url() {
+        return `https://cdn.discordapp.com/emojis/${this.id}.${this.animated ? "gif" : "png"}`;
+    }
+}
+
+export class Sticker {
+    available: boolean;
+    description: string | null;
+    formatType: StickerFormatType;
+    guildId: string | null;
+    id: string;
+    kind: StickerType;
+    name: string;
+
+    /**
+     * Autocomplete/suggestion tags for the sticker
+     */
+    tags: string;
+
+    /**
+     * The user that uploaded this sticker, only present if the bot has the "manage emojis and stickers" permission
+     */
+    user?: User;
+
+    /**
+     * @internal
+     */
+    constructor(json: ISticker) {
+        this.available = json.available;
+        this.description = json.description;
+        this.formatType = json.formatType;
+        this.guildId = json.guildId;
+        this.id = json.id;
+        this.kind = json.kind;
+        this.name = json.name;
+        this.tags = json.tags;
+        this.user = json.user ? new User(json.user) : undefined;
+    }
+}
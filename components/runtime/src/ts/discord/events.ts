@@ -1,6 +1,6 @@
 import { Member } from "./member";
 import { User } from "./user";
-import { UserMention } from "./message";
+import { Message, UserMention } from "./message";
 
 import type { ReactionType } from "../generated/discord/ReactionType";
 import type { Attachment } from "../generated/discord/Attachment";
@@ -10,6 +10,9 @@ import type { MessageType } from "../generated/discord/MessageType";
 import type { IEventMessageUpdate } from "../generated/internal/EventMessageUpdate";
 import type { IEventMessageReactionAdd } from "../generated/internal/EventMessageReactionAdd";
 import type { IEventMemberRemove } from "../generated/internal/EventMemberRemove";
+import type { IEventBan } from "../generated/discord/IEventBan";
+import type { IEventMessagePollVote } from "../generated/discord/IEventMessagePollVote";
+import type { IEventMessageDelete } from "../generated/discord/IEventMessageDelete";
 import type { IEventInviteCreate } from "../generated/internal/IEventInviteCreate";
 import type { IEventInviteDelete } from "../generated/internal/IEventInviteDelete";
 import type { IInviteTargetUser } from "../generated/discord/IInviteTargetUser";
@@ -19,6 +22,14 @@ import type { IVoiceState } from "../generated/internal/IVoiceState";
 import { IEventThreadListSync } from "../generated/internal/IEventThreadListSync";
 import { Thread, ThreadMember, threadChannelFromInternal } from "./channel";
 import { IEventThreadMembersUpdate } from "../generated/internal/IEventThreadMembersUpdate";
+import type { IEventGuildEmojisUpdate } from "../generated/internal/IEventGuildEmojisUpdate";
+import type { IEventGuildStickersUpdate } from "../generated/internal/IEventGuildStickersUpdate";
+import { CustomEmoji, Sticker } from "./emoji";
+import type { IEventRoleUpdate } from "../generated/internal/IEventRoleUpdate";
+import type { IEventRoleDelete } from "../generated/internal/IEventRoleDelete";
+import type { IEventGuildUpdate } from "../generated/internal/IEventGuildUpdate";
+import type { Role } from "../generated/discord/Role";
+import type { Guild } from "../generated/discord/Guild";
 
 export class EventMessageReactionAdd {
     channelId: string;
@@ -56,6 +67,12 @@ export class EventMessageUpdate {
     timestamp?: number;
     tts?: boolean;
 
+    /**
+     * The message before it was updated
+     * 
+     * Only available if the message was in the message cache, which holds a limited number of recent messages per channel
+     */
+    previous?: Message;
 
     /** 
      * @internal 
@@ -76,6 +93,28 @@ export class EventMessageUpdate {
         this.pinned = json.pinned;
         this.timestamp = json.timestamp;
         this.tts = json.tts;
+        this.previous = json.previous ? new Message(json.previous) : undefined;
+    }
+}
+
+export class EventMessageDelete {
+    channelId: string;
+    id: string;
+
+    /**
+     * The message before it was deleted
+     * 
+     * Only available if the message was in the message cache, which holds a limited number of recent messages per channel
+     */
+    previous?: Message;
+
+    /** 
+     * @internal 
+     */
+    constructor(json: IEventMessageDelete) {
+        this.channelId = json.channelId;
+        this.id = json.id;
+        this.previous = json.previous ? new Message(json.previous) : undefined;
     }
 }
 
@@ -93,6 +132,44 @@ export class EventMemberRemove {
     }
 }
 
+export class EventBan {
+    /**
+     * The user that was banned or unbanned
+     */
+    user: User;
+
+    /** 
+     * @internal 
+     */
+    constructor(json: IEventBan) {
+        this.user = new User(json.user);
+    }
+}
+
+export class EventMessagePollVote {
+    /**
+     * The user that added or removed their vote
+     */
+    userId: string;
+    channelId: string;
+    messageId: string;
+
+    /**
+     * The id of the answer the vote was added to or removed from
+     */
+    answerId: number;
+
+    /** 
+     * @internal 
+     */
+    constructor(json: IEventMessagePollVote) {
+        this.userId = json.userId;
+        this.channelId = json.channelId;
+        this.messageId = json.messageId;
+        this.answerId = json.answerId;
+    }
+}
+
 export class EventInviteCreate {
     channelId: string;
     code: string;
@@ -224,4 +301,88 @@ export class EventThreadMembersUpdate {
         this.memberCount = json.memberCount
         this.removedMemberIds = json.removedMemberIds
     }
-}
\ No newline at end of file
+}
+export class EventGuildEmojisUpdate {
+    /**
+     * The full list of custom emojis on the server after the update
+     */
+    emojis: CustomEmoji[];
+
+    /** 
+     * @internal 
+     */
+    constructor(json: IEventGuildEmojisUpdate) {
+        this.emojis = json.emojis.map(v => new CustomEmoji(v))
+    }
+}
+
+export class EventGuildStickersUpdate {
+    /**
+     * The full list of stickers on the server after the update
+     */
+    stickers: Sticker[];
+
+    /** 
+     * @internal 
+     */
+    constructor(json: IEventGuildStickersUpdate) {
+        this.stickers = json.stickers.map(v => new Sticker(v))
+    }
+}
+
+export class EventRoleUpdate {
+    /**
+     * The role after the update
+     */
+    role: Role;
+
+    /**
+     * The role before the update, not available if it was not cached
+     */
+    oldRole?: Role;
+
+    /** 
+     * @internal 
+     */
+    constructor(json: IEventRoleUpdate) {
+        this.role = json.new;
+        this.oldRole = json.old ?? undefined;
+    }
+}
+
+export class EventRoleDelete {
+    roleId: string;
+
+    /**
+     * The role before it was deleted, not available if it was not cached
+     */
+    oldRole?: Role;
+
+    /** 
+     * @internal 
+     */
+    constructor(json: IEventRoleDelete) {
+        this.roleId = json.roleId;
+        this.oldRole = json.old ?? undefined;
+    }
+}
+
+export class EventGuildUpdate {
+    /**
+     * The server after the update
+     */
+    guild: Guild;
+
+    /**
+     * The server before the update, not available if it was not cached
+     */
+    oldGuild?: Guild;
+
+    /** 
+     * @internal 
+     */
+    constructor(json: IEventGuildUpdate) {
+        this.guild = json.new;
+        this.oldGuild = json.old ?? undefined;
+    }
+}
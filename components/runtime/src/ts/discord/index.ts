@@ -9,5 +9,8 @@ export * from './error';
 export * from './common';
 export * from './components';
 export * from './permissions';
+export * from './emoji';
+export * from './webhook';
+export * from './scheduled_event';
 export * from './snowflake';
 export * from '../generated/discord/index';
@@ -40,7 +40,7 @@ export class Interaction {
     /**
      * @deprecated use {@link ackWithMessage} 
      */
-    async sendCallbackWithMessage(fields: Internal.OpCreateMessageFields, flags?: InteractionMessageFlags) {
+    async sendCallbackWithMessage(fields: CreateMessageFields, flags?: InteractionMessageFlags) {
         this.ackWithMessage({
             ...fields,
             flags: flags,
@@ -68,8 +68,10 @@ export class Interaction {
             interactionToken: this.token,
             data: {
                 kind: "ChannelMessageWithSource",
-                fields: toOpMessageFields(fields),
-                flags: fields.flags || {},
+                response: {
+                    fields: toOpMessageFields(fields),
+                    flags: fields.flags || {},
+                },
             }
         })
     }
@@ -89,8 +91,10 @@ export class Interaction {
             interactionToken: this.token,
             data: {
                 kind: "DeferredChannelMessageWithSource",
-                fields: toOpMessageFields(fields ?? {}),
-                flags: fields?.flags ?? {},
+                response: {
+                    fields: toOpMessageFields(fields ?? {}),
+                    flags: fields?.flags ?? {},
+                },
             }
         })
     }
@@ -186,8 +190,10 @@ export class ComponentInteraction extends Interaction {
             interactionToken: this.token,
             data: {
                 kind: "UpdateMessage",
-                fields: toOpMessageFields(fields),
-                flags: fields.flags || {},
+                response: {
+                    fields: toOpMessageFields(fields),
+                    flags: fields.flags || {},
+                },
             }
         })
     }
@@ -225,9 +231,11 @@ export class ComponentInteraction extends Interaction {
             interactionToken: this.token,
             data: {
                 kind: "Modal",
-                title: modal.title,
-                customId: modal.customId,
-                components: modal.components,
+                response: {
+                    title: modal.title,
+                    customId: modal.customId,
+                    components: modal.components,
+                },
             }
         })
     }
@@ -297,8 +305,10 @@ export class ModalSubmitInteraction extends Interaction {
             interactionToken: this.token,
             data: {
                 kind: "UpdateMessage",
-                fields: toOpMessageFields(fields),
-                flags: fields.flags || {},
+                response: {
+                    fields: toOpMessageFields(fields),
+                    flags: fields.flags || {},
+                },
             }
         })
     }
@@ -12,6 +12,7 @@ import type { MessageReaction } from "../generated/discord/MessageReaction";
 import type { MessageReference } from "../generated/discord/MessageReference";
 import type { MessageType } from "../generated/discord/MessageType";
 import type { PartialMember } from "../generated/discord/PartialMember";
+import type { IPoll } from "../generated/discord/IPoll";
 
 import type { IMessage } from "../generated/internal/IMessage";
 import type { IUserMention } from "../generated/internal/UserMention";
@@ -43,6 +44,13 @@ export class Message {
     tts: boolean;
     webhookId: string | null;
 
+    /**
+     * The poll attached to this message, if any.
+     * 
+     * Note: this is not available on the `previous` message of update and delete events.
+     */
+    poll: IPoll | null;
+
     /**
      * @internal
      */
@@ -72,6 +80,7 @@ export class Message {
         this.timestamp = json.timestamp;
         this.tts = json.tts;
         this.webhookId = json.webhookId;
+        this.poll = json.poll;
     }
 
     hyperlink() {
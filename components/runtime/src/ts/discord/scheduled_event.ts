@@ -0,0 +1,86 @@
+import type { IScheduledEvent } from "../generated/internal/IScheduledEvent";
+import type { IScheduledEventUser } from "../generated/internal/IScheduledEventUser";
+import type { ScheduledEventEntityType } from "../generated/discord/ScheduledEventEntityType";
+import type { ScheduledEventStatus } from "../generated/discord/ScheduledEventStatus";
+import { Member } from "./member";
+import { User } from "./user";
+
+export class ScheduledEvent {
+    id: string;
+
+    /**
+     * The voice or stage channel the event is hosted in, null for external events
+     */
+    channelId: string | null;
+
+    /**
+     * The user that created this event
+     */
+    creator?: User;
+    creatorId: string | null;
+    description: string | null;
+    entityType: ScheduledEventEntityType;
+
+    /**
+     * Location of the event, only present for external events
+     */
+    location: string | null;
+
+    /**
+     * Cover image hash of the event
+     */
+    image: string | null;
+    name: string;
+
+    /**
+     * Unix timestamp in milliseconds of when the event starts
+     */
+    scheduledStartTime: number;
+
+    /**
+     * Unix timestamp in milliseconds of when the event ends, required for external events
+     */
+    scheduledEndTime: number | null;
+    status: ScheduledEventStatus;
+
+    /**
+     * Number of users interested in the event
+     */
+    userCount: number | null;
+
+    /**
+     * @internal
+     */
+    constructor(json: IScheduledEvent) {
+        this.id = json.id;
+        this.channelId = json.channelId;
+        this.creator = json.creator ? new User(json.creator) : undefined;
+        this.creatorId = json.creatorId;
+        this.description = json.description;
+        this.entityType = json.entityType;
+        this.location = json.location;
+        this.image = json.image;
+        this.name = json.name;
+        this.scheduledStartTime = json.scheduledStartTime;
+        this.scheduledEndTime = json.scheduledEndTime;
+        this.status = json.status;
+        this.userCount = json.userCount;
+    }
+}
+
+export class ScheduledEventUser {
+    user: User;
+
+    /**
+     * Only present if the user is still a member of the server
+     */
+    member?: Member;
+
+    /**
+     * @internal
+     */
+    constructor(json: IScheduledEventUser) {
+        this.user = new User(json.user);
+        this.member = json.member ? new Member(json.member) : undefined;
+    }
+}
@@ -0,0 +1,38 @@
+import type { IWebhook } from "../generated/internal/IWebhook";
+import type { WebhookType } from "../generated/discord/WebhookType";
+import { User } from "./user";
+
+export class Webhook {
+    id: string;
+    kind: WebhookType;
+    channelId: string;
+    name: string | null;
+
+    /**
+     * Avatar hash of the webhook
+     */
+    avatar: string | null;
+
+    /**
+     * The bot/OAuth2 application that created this webhook
+     */
+    applicationId: string | null;
+
+    /**
+     * The user that created this webhook, only present if the bot has the "manage webhooks" permission
+     */
+    user?: User;
+
+    /**
+     * @internal
+     */
+    constructor(json: IWebhook) {
+        this.id = json.id;
+        this.kind = json.kind;
+        this.channelId = json.channelId;
+        this.name = json.name;
+        this.avatar = json.avatar;
+        this.applicationId = json.applicationId;
+        this.user = json.user ? new User(json.user) : undefined;
+    }
+}
@@ -3,7 +3,10 @@ import {
     ComponentInteraction,
     ModalSubmitInteraction,
     EventMemberRemove,
+    EventBan,
+    EventMessagePollVote,
     EventMessageDelete,
+    EventMessageDeleteBulk,
     EventMessageReactionAdd,
     guildChannelFromInternal,
     EventMessageReactionRemove,
@@ -24,9 +27,20 @@ import {
     EventThreadListSync,
     EventThreadMembersUpdate,
     threadChannelFromInternal,
-    Thread
+    Thread,
+    EventGuildEmojisUpdate,
+    EventGuildStickersUpdate,
+    AuditLogEntry,
+    ScheduledEvent,
+    EventScheduledEventUser,
+    EventAutoModerationActionExecution,
+    EventRoleUpdate,
+    EventRoleDelete,
+    EventGuildUpdate,
+    Role,
 } from './discord/index';
 import * as Internal from './generated/internal/index';
+import type { IEventBan, IEventMessageDelete, IEventMessagePollVote } from './generated/discord/index';
 
 export namespace EventSystem {
 
@@ -62,6 +76,8 @@ export namespace EventSystem {
             handleComponentInteraction(data);
         } else if (evt.name === "BOTLOADER_COMMAND_INTERACTION_CREATE") {
             commandSystem.handleInteractionCreate(data);
+        } else if (evt.name === "BOTLOADER_AUTOCOMPLETE_INTERACTION_CREATE") {
+            commandSystem.handleAutocomplete(data);
         } else if (evt.name == "BOTLOADER_MODAL_SUBMIT_INTERACTION_CREATE") {
             handleModalSubmitInteraction(data);
         } else {
@@ -80,6 +96,11 @@ export namespace EventSystem {
          */
         BOTLOADER_COMMAND_INTERACTION_CREATE: Internal.CommandInteraction,
 
+        /**
+         * @internal
+         */
+        BOTLOADER_AUTOCOMPLETE_INTERACTION_CREATE: Internal.CommandInteraction,
+
 
         /**
         * @internal
@@ -104,11 +125,15 @@ export namespace EventSystem {
         MESSAGE_CREATE: Message,
         MESSAGE_UPDATE: EventMessageUpdate,
         MESSAGE_DELETE: EventMessageDelete,
+        MESSAGE_DELETE_BULK: EventMessageDeleteBulk,
 
         MEMBER_ADD: Member,
         MEMBER_UPDATE: Member,
         MEMBER_REMOVE: EventMemberRemove,
 
+        BAN_ADD: EventBan,
+        BAN_REMOVE: EventBan,
+
         VOICE_STATE_UPDATE: EventVoiceStateUpdate,
 
         MESSAGE_REACTION_ADD: EventMessageReactionAdd,
@@ -116,6 +141,9 @@ export namespace EventSystem {
         MESSAGE_REACTION_REMOVE_ALL: EventMessageReactionRemoveAll,
         MESSAGE_REACTION_REMOVE_ALL_EMOJI: EventMessageReactionRemoveAllEmoji,
 
+        MESSAGE_POLL_VOTE_ADD: EventMessagePollVote,
+        MESSAGE_POLL_VOTE_REMOVE: EventMessagePollVote,
+
         CHANNEL_CREATE: GuildChannel,
         CHANNEL_UPDATE: GuildChannel,
         CHANNEL_DELETE: GuildChannel,
@@ -129,6 +157,30 @@ export namespace EventSystem {
 
         INVITE_CREATE: EventInviteCreate,
         INVITE_DELETE: EventInviteDelete,
+
+        GUILD_EMOJIS_UPDATE: EventGuildEmojisUpdate,
+        GUILD_STICKERS_UPDATE: EventGuildStickersUpdate,
+
+        GUILD_AUDIT_LOG_ENTRY_CREATE: AuditLogEntry,
+
+        GUILD_SCHEDULED_EVENT_CREATE: ScheduledEvent,
+        GUILD_SCHEDULED_EVENT_UPDATE: ScheduledEvent,
+        GUILD_SCHEDULED_EVENT_DELETE: ScheduledEvent,
+        GUILD_SCHEDULED_EVENT_USER_ADD: EventScheduledEventUser,
+        GUILD_SCHEDULED_EVENT_USER_REMOVE: EventScheduledEventUser,
+
+        AUTO_MODERATION_ACTION_EXECUTION: EventAutoModerationActionExecution,
+
+        ROLE_CREATE: Role,
+        ROLE_UPDATE: EventRoleUpdate,
+        ROLE_DELETE: EventRoleDelete,
+
+        GUILD_UPDATE: EventGuildUpdate,
+
+        /**
+         * Only dispatched for buckets subscribed to through {@link Script.onStorageChanged}
+         */
+        STORAGE_CHANGED: Internal.StorageChangedEvent,
     }
 
 
@@ -267,6 +319,7 @@ export namespace EventSystem {
     } = {
         MESSAGE_CREATE: (v: Internal.IMessage) => new Message(v),
         MESSAGE_UPDATE: (v: Internal.IEventMessageUpdate) => new EventMessageUpdate(v),
+        MESSAGE_DELETE: (v: IEventMessageDelete) => new EventMessageDelete(v),
 
         MESSAGE_REACTION_ADD: (v: Internal.IEventMessageReactionAdd) => new EventMessageReactionAdd(v),
 
@@ -278,6 +331,12 @@ export namespace EventSystem {
         MEMBER_UPDATE: (v: Internal.IMember) => new Member(v),
         MEMBER_REMOVE: (v: Internal.IEventMemberRemove) => new EventMemberRemove(v),
 
+        BAN_ADD: (v: IEventBan) => new EventBan(v),
+        BAN_REMOVE: (v: IEventBan) => new EventBan(v),
+
+        MESSAGE_POLL_VOTE_ADD: (v: IEventMessagePollVote) => new EventMessagePollVote(v),
+        MESSAGE_POLL_VOTE_REMOVE: (v: IEventMessagePollVote) => new EventMessagePollVote(v),
+
         VOICE_STATE_UPDATE: (v: Internal.IEventVoiceStateUpdate) => new EventVoiceStateUpdate(v),
 
         THREAD_CREATE: (v: Internal.InternalGuildChannel) => threadChannelFromInternal(v),
@@ -289,5 +348,17 @@ export namespace EventSystem {
 
         INVITE_CREATE: (v: Internal.IEventInviteCreate) => new EventInviteCreate(v),
         INVITE_DELETE: (v: Internal.IEventInviteDelete) => new EventInviteDelete(v),
+
+        GUILD_EMOJIS_UPDATE: (v: Internal.IEventGuildEmojisUpdate) => new EventGuildEmojisUpdate(v),
+        GUILD_STICKERS_UPDATE: (v: Internal.IEventGuildStickersUpdate) => new EventGuildStickersUpdate(v),
+
+        GUILD_SCHEDULED_EVENT_CREATE: (v: Internal.IScheduledEvent) => new ScheduledEvent(v),
+        GUILD_SCHEDULED_EVENT_UPDATE: (v: Internal.IScheduledEvent) => new ScheduledEvent(v),
+        GUILD_SCHEDULED_EVENT_DELETE: (v: Internal.IScheduledEvent) => new ScheduledEvent(v),
+
+        ROLE_UPDATE: (v: Internal.IEventRoleUpdate) => new EventRoleUpdate(v),
+        ROLE_DELETE: (v: Internal.IEventRoleDelete) => new EventRoleDelete(v),
+
+        GUILD_UPDATE: (v: Internal.IEventGuildUpdate) => new EventGuildUpdate(v),
     }
 }
@@ -0,0 +1,58 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AuditLogActionType =
+  | "GuildUpdate"
+  | "ChannelCreate"
+  | "ChannelUpdate"
+  | "ChannelDelete"
+  | "ChannelOverwriteCreate"
+  | "ChannelOverwriteUpdate"
+  | "ChannelOverwriteDelete"
+  | "MemberKick"
+  | "MemberPrune"
+  | "MemberBanAdd"
+  | "MemberBanRemove"
+  | "MemberUpdate"
+  | "MemberRoleUpdate"
+  | "MemberMove"
+  | "MemberDisconnect"
+  | "BotAdd"
+  | "RoleCreate"
+  | "RoleUpdate"
+  | "RoleDelete"
+  | "InviteCreate"
+  | "InviteUpdate"
+  | "InviteDelete"
+  | "WebhookCreate"
+  | "WebhookUpdate"
+  | "WebhookDelete"
+  | "EmojiCreate"
+  | "EmojiUpdate"
+  | "EmojiDelete"
+  | "MessageDelete"
+  | "MessageBulkDelete"
+  | "MessagePin"
+  | "MessageUnpin"
+  | "IntegrationCreate"
+  | "IntegrationUpdate"
+  | "IntegrationDelete"
+  | "StageInstanceCreate"
+  | "StageInstanceUpdate"
+  | "StageInstanceDelete"
+  | "StickerCreate"
+  | "StickerUpdate"
+  | "StickerDelete"
+  | "GuildScheduledEventCreate"
+  | "GuildScheduledEventUpdate"
+  | "GuildScheduledEventDelete"
+  | "ThreadCreate"
+  | "ThreadUpdate"
+  | "ThreadDelete"
+  | "ApplicationCommandPermissionUpdate"
+  | "AutoModerationRuleCreate"
+  | "AutoModerationRuleUpdate"
+  | "AutoModerationRuleDelete"
+  | "AutoModerationBlockMessage"
+  | "AutoModerationFlagToChannel"
+  | "AutoModerationUserCommunicationDisabled"
+  | { "Unknown": number };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AuditLogActionType } from "./AuditLogActionType";
+import type { AuditLogChange } from "./AuditLogChange";
+import type { AuditLogEntryInfo } from "./AuditLogEntryInfo";
+
+export interface AuditLogEntry {
+  id: string;
+  actionType: AuditLogActionType;
+  changes: Array<AuditLogChange>;
+  options: AuditLogEntryInfo | null;
+  reason: string | null;
+  targetId: string | null;
+  userId: string | null;
+}
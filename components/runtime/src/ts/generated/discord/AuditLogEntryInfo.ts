@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface AuditLogEntryInfo {
+  channelId: string | null;
+  count: string | null;
+  deleteMemberDays: string | null;
+  id: string | null;
+  kind: string | null;
+  membersRemoved: string | null;
+  messageId: string | null;
+  roleName: string | null;
+}
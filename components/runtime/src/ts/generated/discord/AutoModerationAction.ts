@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AutoModerationActionMetadata } from "./AutoModerationActionMetadata";
+import type { AutoModerationActionType } from "./AutoModerationActionType";
+
+export interface AutoModerationAction {
+  kind: AutoModerationActionType;
+  metadata?: AutoModerationActionMetadata;
+}
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AutoModerationAction } from "./AutoModerationAction";
+import type { AutoModerationEventType } from "./AutoModerationEventType";
+import type { AutoModerationTriggerMetadata } from "./AutoModerationTriggerMetadata";
+import type { AutoModerationTriggerType } from "./AutoModerationTriggerType";
+
+export interface AutoModerationRule {
+  id: string;
+  name: string;
+  creatorId: string;
+  enabled: boolean;
+  eventType: AutoModerationEventType;
+  triggerType: AutoModerationTriggerType;
+  triggerMetadata: AutoModerationTriggerMetadata;
+  actions: Array<AutoModerationAction>;
+  exemptRoles: Array<string>;
+  exemptChannels: Array<string>;
+}
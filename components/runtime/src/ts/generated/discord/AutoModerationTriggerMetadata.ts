@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AutoModerationKeywordPresetType } from "./AutoModerationKeywordPresetType";
+
+export interface AutoModerationTriggerMetadata {
+  keywordFilter?: Array<string>;
+  regexPatterns?: Array<string>;
+  presets?: Array<AutoModerationKeywordPresetType>;
+  allowList?: Array<string>;
+  mentionTotalLimit?: number;
+  mentionRaidProtectionEnabled?: boolean;
+}
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AutoModerationTriggerType =
+  | "Keyword"
+  | "Spam"
+  | "KeywordPreset"
+  | "MentionSpam"
+  | "MemberProfile"
+  | { "Unknown": number };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AutoModerationAction } from "./AutoModerationAction";
+import type { AutoModerationTriggerType } from "./AutoModerationTriggerType";
+
+export interface EventAutoModerationActionExecution {
+  action: AutoModerationAction;
+  ruleId: string;
+  ruleTriggerType: AutoModerationTriggerType;
+  userId: string;
+  channelId: string | null;
+  messageId: string | null;
+  alertSystemMessageId: string | null;
+  content: string;
+  matchedKeyword: string | null;
+  matchedContent: string | null;
+}
@@ -1,6 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export interface EventMessageDelete {
+export interface EventMessageDeleteBulk {
   channelId: string;
-  id: string;
+  ids: Array<string>;
 }
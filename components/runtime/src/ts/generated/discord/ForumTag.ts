@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface ForumTag {
+  id: string;
+  name: string;
+  moderated: boolean;
+  emojiId: string | null;
+  emojiName: string | null;
+}
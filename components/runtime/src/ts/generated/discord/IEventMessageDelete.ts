@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IMessage } from "../internal/IMessage";
+
+export interface IEventMessageDelete {
+  channelId: string;
+  id: string;
+  previous: IMessage | null;
+}
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PollAnswer } from "./PollAnswer";
+import type { PollMedia } from "./PollMedia";
+import type { PollResults } from "./PollResults";
+
+export interface IPoll {
+  question: PollMedia;
+  answers: Array<PollAnswer>;
+  expiry: number | null;
+  allowMultiselect: boolean;
+  results: PollResults | null;
+}
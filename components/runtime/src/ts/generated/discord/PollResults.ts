@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PollAnswerCount } from "./PollAnswerCount";
+
+export interface PollResults {
+  /**
+   * Whether the votes have been precisely counted, if false the counts are approximate
+   */
+  isFinalized: boolean;
+  answerCounts: Array<PollAnswerCount>;
+}
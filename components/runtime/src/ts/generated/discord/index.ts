@@ -1,6 +1,18 @@
 // generated index file using gen-index.bash
 export * from './Attachment'
+export * from './AuditLogActionType'
+export * from './AuditLogChange'
+export * from './AuditLogEntryInfo'
+export * from './AuditLogEntry'
 export * from './AuditLogExtras'
+export * from './AutoModerationActionMetadata'
+export * from './AutoModerationAction'
+export * from './AutoModerationActionType'
+export * from './AutoModerationEventType'
+export * from './AutoModerationKeywordPresetType'
+export * from './AutoModerationRule'
+export * from './AutoModerationTriggerMetadata'
+export * from './AutoModerationTriggerType'
 export * from './ButtonStyle'
 export * from './ChannelMention'
 export * from './ChannelType'
@@ -14,21 +26,30 @@ export * from './EmbedProvider'
 export * from './EmbedThumbnail'
 export * from './Embed'
 export * from './EmbedVideo'
-export * from './EventMessageDelete'
+export * from './EventAutoModerationActionExecution'
+export * from './EventMessageDeleteBulk'
 export * from './EventMessageReactionRemoveAllEmoji'
 export * from './EventMessageReactionRemoveAll'
 export * from './EventMessageReactionRemove'
+export * from './EventScheduledEventUser'
 export * from './ExplicitContentFilter'
+export * from './ForumDefaultReaction'
+export * from './ForumSortOrder'
+export * from './ForumTag'
 export * from './Guild'
 export * from './IActionRow'
 export * from './IButton'
 export * from './IComponent'
+export * from './IEventBan'
+export * from './IEventMessageDelete'
+export * from './IEventMessagePollVote'
 export * from './IEventThreadDelete'
 export * from './IInviteChannel'
 export * from './IInviteGuild'
 export * from './IInviteTargetUser'
 export * from './InviteTargetType'
 export * from './IPermissionOverwrite'
+export * from './IPoll'
 export * from './ISelectMenuOption'
 export * from './ISelectMenu'
 export * from './ITextInput'
@@ -44,13 +65,22 @@ export * from './MfaLevel'
 export * from './NsfwLevel'
 export * from './PartialMember'
 export * from './PermissionOverwriteType'
+export * from './PollAnswerCount'
+export * from './PollAnswer'
+export * from './PollMedia'
+export * from './PollResults'
 export * from './PremiumTier'
 export * from './ReactionType'
 export * from './RoleTags'
 export * from './Role'
+export * from './ScheduledEventEntityType'
+export * from './ScheduledEventStatus'
 export * from './SendEmoji'
+export * from './StickerFormatType'
+export * from './StickerType'
 export * from './SystemChannelFlags'
 export * from './TextInputStyle'
 export * from './ThreadMetadata'
 export * from './VerificationLevel'
 export * from './VideoQualityMode'
+export * from './WebhookType'
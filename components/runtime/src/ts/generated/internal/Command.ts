@@ -9,4 +9,8 @@ export interface Command {
   group?: string;
   subGroup?: string;
   kind: CommandType;
+  defaultMemberPermissions?: string;
+  nsfw?: boolean;
+  nameLocalizations?: Record<string, string>;
+  descriptionLocalizations?: Record<string, string>;
 }
@@ -5,4 +5,6 @@ export interface CommandGroup {
   name: string;
   description: string;
   subGroups: Array<CommandSubGroup>;
+  defaultMemberPermissions?: string;
+  nsfw?: boolean;
 }
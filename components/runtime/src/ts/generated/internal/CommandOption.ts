@@ -8,4 +8,6 @@ export interface CommandOption {
   kind: CommandOptionType;
   required: boolean;
   extraOptions: ExtraCommandOptions;
+  nameLocalizations?: Record<string, string>;
+  descriptionLocalizations?: Record<string, string>;
 }
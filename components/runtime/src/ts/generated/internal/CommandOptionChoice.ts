@@ -4,4 +4,5 @@ import type { CommandOptionChoiceValue } from "./CommandOptionChoiceValue";
 export interface CommandOptionChoice {
   name: string;
   value: CommandOptionChoiceValue;
+  nameLocalizations?: Record<string, string>;
 }
@@ -1,7 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ICreatePoll } from "./ICreatePoll";
 import type { OpCreateMessageFields } from "./CreateMessageFields";
 
 export interface OpCreateChannelMessage {
   channelId: string;
   fields: OpCreateMessageFields;
+  poll?: ICreatePoll;
 }
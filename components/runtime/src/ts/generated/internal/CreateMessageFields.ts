@@ -2,10 +2,12 @@
 import type { AllowedMentions } from "./AllowedMentions";
 import type { Embed } from "../discord/Embed";
 import type { IComponent } from "../discord/IComponent";
+import type { OpCreateMessageAttachment } from "./CreateMessageAttachment";
 
 export interface OpCreateMessageFields {
   content?: string;
   embeds?: Array<Embed>;
   allowedMentions?: AllowedMentions;
   components?: Array<IComponent>;
+  attachments?: Array<OpCreateMessageAttachment>;
 }
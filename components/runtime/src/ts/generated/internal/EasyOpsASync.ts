@@ -1,18 +1,39 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AuditLogExtras } from "../discord/AuditLogExtras";
+import type { ICreateAutoModerationRule } from "./ICreateAutoModerationRule";
+import type { ICreateEmoji } from "./ICreateEmoji";
 import type { ICreateForumThread } from "./ICreateForumThread";
+import type { ICreateRole } from "./ICreateRole";
+import type { ICreateScheduledEvent } from "./ICreateScheduledEvent";
+import type { ICreateSticker } from "./ICreateSticker";
 import type { ICreateThread } from "./ICreateThread";
 import type { ICreateThreadFromMessage } from "./ICreateThreadFromMessage";
+import type { ICreateWebhook } from "./ICreateWebhook";
+import type { IEditAutoModerationRule } from "./IEditAutoModerationRule";
+import type { IEditEmoji } from "./IEditEmoji";
+import type { IEditGuild } from "./IEditGuild";
+import type { IEditRole } from "./IEditRole";
+import type { IEditScheduledEvent } from "./IEditScheduledEvent";
+import type { IEditSticker } from "./IEditSticker";
+import type { IExecuteWebhook } from "./IExecuteWebhook";
+import type { IGetAuditLog } from "./IGetAuditLog";
+import type { IGetScheduledEventUsers } from "./IGetScheduledEventUsers";
 import type { IListThreadMembersRequest } from "./IListThreadMembersRequest";
 import type { IListThreadsRequest } from "./IListThreadsRequest";
+import type { ISearchMembers } from "./ISearchMembers";
+import type { IUpdateRolePosition } from "./IUpdateRolePosition";
 import type { IUpdateThread } from "./IUpdateThread";
 import type { OpCreateChannelMessage } from "./CreateChannelMessage";
 import type { OpDeleteMessage } from "./DeleteMessage";
 import type { OpDeleteMessagesBulk } from "./DeleteMessagesBulk";
 import type { OpEditChannelMessage } from "./EditChannelMessage";
 import type { OpGetMessages } from "./GetMessages";
+import type { OpSendDirectMessage } from "./SendDirectMessage";
 
 export type EasyOpsASync =
   | { "kind": "discord_get_guild"; "arg": null }
+  | { "kind": "discord_edit_guild"; "arg": IEditGuild }
+  | { "kind": "discord_search_members"; "arg": ISearchMembers }
   | { "kind": "discord_get_message"; "arg": [string, string] }
   | { "kind": "discord_get_messages"; "arg": OpGetMessages }
   | { "kind": "discord_create_message"; "arg": OpCreateChannelMessage }
@@ -20,6 +41,7 @@ export type EasyOpsASync =
   | { "kind": "discord_crosspost_message"; "arg": [string, string] }
   | { "kind": "discord_delete_message"; "arg": OpDeleteMessage }
   | { "kind": "discord_bulk_delete_messages"; "arg": OpDeleteMessagesBulk }
+  | { "kind": "discord_send_direct_message"; "arg": OpSendDirectMessage }
   | {
     "kind": "discord_start_thread_from_message";
     "arg": ICreateThreadFromMessage;
@@ -38,4 +60,49 @@ export type EasyOpsASync =
     "kind": "discord_list_private_archived_threads";
     "arg": IListThreadsRequest;
   }
-  | { "kind": "discord_edit_thread"; "arg": IUpdateThread };
+  | { "kind": "discord_edit_thread"; "arg": IUpdateThread }
+  | { "kind": "discord_create_role"; "arg": ICreateRole }
+  | { "kind": "discord_edit_role"; "arg": IEditRole }
+  | { "kind": "discord_delete_role"; "arg": [string, AuditLogExtras] }
+  | {
+    "kind": "discord_update_role_positions";
+    "arg": Array<IUpdateRolePosition>;
+  }
+  | { "kind": "discord_get_emojis"; "arg": null }
+  | { "kind": "discord_get_emoji"; "arg": string }
+  | { "kind": "discord_create_emoji"; "arg": ICreateEmoji }
+  | { "kind": "discord_edit_emoji"; "arg": IEditEmoji }
+  | { "kind": "discord_delete_emoji"; "arg": [string, AuditLogExtras] }
+  | { "kind": "discord_get_stickers"; "arg": null }
+  | { "kind": "discord_get_sticker"; "arg": string }
+  | { "kind": "discord_create_sticker"; "arg": ICreateSticker }
+  | { "kind": "discord_edit_sticker"; "arg": IEditSticker }
+  | { "kind": "discord_delete_sticker"; "arg": [string, AuditLogExtras] }
+  | { "kind": "discord_create_webhook"; "arg": ICreateWebhook }
+  | { "kind": "discord_get_channel_webhooks"; "arg": string }
+  | { "kind": "discord_delete_webhook"; "arg": [string, AuditLogExtras] }
+  | { "kind": "discord_execute_webhook"; "arg": IExecuteWebhook }
+  | { "kind": "discord_get_audit_log"; "arg": IGetAuditLog }
+  | { "kind": "discord_get_scheduled_events"; "arg": null }
+  | { "kind": "discord_get_scheduled_event"; "arg": string }
+  | { "kind": "discord_create_scheduled_event"; "arg": ICreateScheduledEvent }
+  | { "kind": "discord_edit_scheduled_event"; "arg": IEditScheduledEvent }
+  | { "kind": "discord_delete_scheduled_event"; "arg": string }
+  | {
+    "kind": "discord_get_scheduled_event_users";
+    "arg": IGetScheduledEventUsers;
+  }
+  | { "kind": "discord_get_auto_moderation_rules"; "arg": null }
+  | { "kind": "discord_get_auto_moderation_rule"; "arg": string }
+  | {
+    "kind": "discord_create_auto_moderation_rule";
+    "arg": ICreateAutoModerationRule;
+  }
+  | {
+    "kind": "discord_edit_auto_moderation_rule";
+    "arg": IEditAutoModerationRule;
+  }
+  | {
+    "kind": "discord_delete_auto_moderation_rule";
+    "arg": [string, AuditLogExtras];
+  };
@@ -1,13 +1,24 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AutoModerationRule } from "../discord/AutoModerationRule";
 import type { Guild } from "../discord/Guild";
+import type { IAuditLog } from "./IAuditLog";
+import type { ICustomEmoji } from "./ICustomEmoji";
 import type { IForumThreadResponse } from "./IForumThreadResponse";
+import type { IMember } from "./Member";
 import type { IMessage } from "./IMessage";
+import type { IScheduledEvent } from "./IScheduledEvent";
+import type { IScheduledEventUser } from "./IScheduledEventUser";
+import type { ISticker } from "./ISticker";
 import type { IThreadMember } from "./ThreadMember";
 import type { IThreadsListing } from "./IThreadsListing";
+import type { IWebhook } from "./IWebhook";
 import type { InternalGuildChannel } from "./GuildChannel";
+import type { Role } from "../discord/Role";
 
 export interface EasyOpsReturnTypesASync {
   discord_get_guild: Guild;
+  discord_edit_guild: null;
+  discord_search_members: Array<IMember>;
   discord_get_message: IMessage;
   discord_get_messages: Array<IMessage>;
   discord_create_message: IMessage;
@@ -15,6 +26,7 @@ export interface EasyOpsReturnTypesASync {
   discord_crosspost_message: null;
   discord_delete_message: null;
   discord_bulk_delete_messages: null;
+  discord_send_direct_message: IMessage;
   discord_start_thread_from_message: InternalGuildChannel;
   discord_start_thread_without_message: InternalGuildChannel;
   discord_start_forum_thread: IForumThreadResponse;
@@ -25,4 +37,34 @@ export interface EasyOpsReturnTypesASync {
   discord_list_public_archived_threads: IThreadsListing;
   discord_list_private_archived_threads: IThreadsListing;
   discord_edit_thread: InternalGuildChannel;
+  discord_create_role: Role;
+  discord_edit_role: Role;
+  discord_delete_role: null;
+  discord_update_role_positions: Array<Role>;
+  discord_get_emojis: Array<ICustomEmoji>;
+  discord_get_emoji: ICustomEmoji;
+  discord_create_emoji: ICustomEmoji;
+  discord_edit_emoji: ICustomEmoji;
+  discord_delete_emoji: null;
+  discord_get_stickers: Array<ISticker>;
+  discord_get_sticker: ISticker;
+  discord_create_sticker: ISticker;
+  discord_edit_sticker: ISticker;
+  discord_delete_sticker: null;
+  discord_create_webhook: IWebhook;
+  discord_get_channel_webhooks: Array<IWebhook>;
+  discord_delete_webhook: null;
+  discord_execute_webhook: IMessage | null;
+  discord_get_audit_log: IAuditLog;
+  discord_get_scheduled_events: Array<IScheduledEvent>;
+  discord_get_scheduled_event: IScheduledEvent;
+  discord_create_scheduled_event: IScheduledEvent;
+  discord_edit_scheduled_event: IScheduledEvent;
+  discord_delete_scheduled_event: null;
+  discord_get_scheduled_event_users: Array<IScheduledEventUser>;
+  discord_get_auto_moderation_rules: Array<AutoModerationRule>;
+  discord_get_auto_moderation_rule: AutoModerationRule;
+  discord_create_auto_moderation_rule: AutoModerationRule;
+  discord_edit_auto_moderation_rule: AutoModerationRule;
+  discord_delete_auto_moderation_rule: null;
 }
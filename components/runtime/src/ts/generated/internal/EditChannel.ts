@@ -1,4 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ForumDefaultReaction } from "../discord/ForumDefaultReaction";
+import type { ForumSortOrder } from "../discord/ForumSortOrder";
+import type { IForumTagFields } from "./IForumTagFields";
 import type { IPermissionOverwrite } from "../discord/IPermissionOverwrite";
 import type { VideoQualityMode } from "../discord/VideoQualityMode";
 
@@ -13,4 +16,7 @@ export interface IEditChannel {
   topic?: string;
   userLimit?: number;
   videoQualityMode?: VideoQualityMode;
+  availableTags?: Array<IForumTagFields>;
+  defaultReactionEmoji?: ForumDefaultReaction | null;
+  defaultSortOrder?: ForumSortOrder;
 }
@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Attachment } from "../discord/Attachment";
 import type { Embed } from "../discord/Embed";
+import type { IMessage } from "./IMessage";
 import type { IUser } from "./IUser";
 import type { IUserMention } from "./UserMention";
 import type { MessageType } from "../discord/MessageType";
@@ -21,4 +22,5 @@ export interface IEventMessageUpdate {
   pinned?: boolean;
   timestamp?: number;
   tts?: boolean;
+  previous: IMessage | null;
 }
@@ -7,4 +7,5 @@ export interface ExtraCommandOptions {
   maxValue?: number;
   channelTypes?: Array<ChannelType>;
   choices?: Array<CommandOptionChoice>;
+  autocomplete?: boolean;
 }
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AuditLogEntry } from "../discord/AuditLogEntry";
+import type { IUser } from "./IUser";
+
+export interface IAuditLog {
+  entries: Array<AuditLogEntry>;
+  users: Array<IUser>;
+}
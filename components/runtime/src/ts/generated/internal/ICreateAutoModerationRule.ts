@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AutoModerationAction } from "../discord/AutoModerationAction";
+import type { AutoModerationEventType } from "../discord/AutoModerationEventType";
+import type { AutoModerationTriggerMetadata } from "../discord/AutoModerationTriggerMetadata";
+import type { AutoModerationTriggerType } from "../discord/AutoModerationTriggerType";
+
+export interface ICreateAutoModerationRule {
+  name: string;
+  eventType: AutoModerationEventType;
+  triggerType: AutoModerationTriggerType;
+  triggerMetadata?: AutoModerationTriggerMetadata;
+  actions: Array<AutoModerationAction>;
+  enabled?: boolean;
+  exemptRoles?: Array<string>;
+  exemptChannels?: Array<string>;
+  auditLogReason?: string;
+}
@@ -1,5 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { ChannelType } from "../discord/ChannelType";
+import type { ForumDefaultReaction } from "../discord/ForumDefaultReaction";
+import type { ForumSortOrder } from "../discord/ForumSortOrder";
+import type { IForumTagFields } from "./IForumTagFields";
 import type { IPermissionOverwrite } from "../discord/IPermissionOverwrite";
 
 export interface ICreateChannel {
@@ -13,4 +16,7 @@ export interface ICreateChannel {
   rateLimitPerUser?: number;
   topic?: string;
   userLimit?: number;
+  availableTags?: Array<IForumTagFields>;
+  defaultReactionEmoji?: ForumDefaultReaction;
+  defaultSortOrder?: ForumSortOrder;
 }
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface ICreateEmoji {
+  name: string;
+  imageData: Uint8Array;
+  contentType: string;
+  roles?: Array<string>;
+  auditLogReason?: string;
+}
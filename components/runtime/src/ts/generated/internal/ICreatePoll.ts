@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ICreatePollAnswer } from "./ICreatePollAnswer";
+
+export interface ICreatePoll {
+  question: string;
+  answers: Array<ICreatePollAnswer>;
+  durationHours?: number;
+  allowMultiselect?: boolean;
+}
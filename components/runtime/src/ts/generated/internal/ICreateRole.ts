@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface ICreateRole {
+  name?: string;
+  color?: number;
+  hoist?: boolean;
+  mentionable?: boolean;
+  permissionsRaw?: string;
+  unicodeEmoji?: string;
+  auditLogReason?: string;
+}
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ScheduledEventEntityType } from "../discord/ScheduledEventEntityType";
+
+export interface ICreateScheduledEvent {
+  name: string;
+  entityType: ScheduledEventEntityType;
+  channelId?: string;
+  location?: string;
+  scheduledStartTime: number;
+  scheduledEndTime?: number;
+  description?: string;
+  auditLogReason?: string;
+}
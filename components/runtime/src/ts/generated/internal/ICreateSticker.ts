@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface ICreateSticker {
+  name: string;
+  description: string;
+  tags: string;
+  fileData: Uint8Array;
+  auditLogReason?: string;
+}
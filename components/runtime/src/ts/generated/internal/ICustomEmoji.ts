@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IUser } from "./IUser";
+
+export interface ICustomEmoji {
+  animated: boolean;
+  available: boolean;
+  id: string;
+  managed: boolean;
+  name: string;
+  requireColons: boolean;
+  roles: Array<string>;
+  user: IUser | null;
+}
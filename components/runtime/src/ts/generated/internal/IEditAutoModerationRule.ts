@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AutoModerationAction } from "../discord/AutoModerationAction";
+import type { AutoModerationEventType } from "../discord/AutoModerationEventType";
+import type { AutoModerationTriggerMetadata } from "../discord/AutoModerationTriggerMetadata";
+
+export interface IEditAutoModerationRule {
+  ruleId: string;
+  name?: string;
+  eventType?: AutoModerationEventType;
+  triggerMetadata?: AutoModerationTriggerMetadata;
+  actions?: Array<AutoModerationAction>;
+  enabled?: boolean;
+  exemptRoles?: Array<string>;
+  exemptChannels?: Array<string>;
+  auditLogReason?: string;
+}
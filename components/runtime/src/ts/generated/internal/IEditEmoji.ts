@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface IEditEmoji {
+  emojiId: string;
+  name?: string;
+  roles?: Array<string>;
+  auditLogReason?: string;
+}
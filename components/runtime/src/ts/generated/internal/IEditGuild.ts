@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DefaultMessageNotificationLevel } from "../discord/DefaultMessageNotificationLevel";
+import type { IGuildImage } from "./IGuildImage";
+import type { VerificationLevel } from "../discord/VerificationLevel";
+
+export interface IEditGuild {
+  name?: string;
+  icon?: IGuildImage | null;
+  banner?: IGuildImage | null;
+  systemChannelId?: string | null;
+  afkChannelId?: string | null;
+  afkTimeoutSeconds?: number;
+  verificationLevel?: VerificationLevel;
+  defaultMessageNotifications?: DefaultMessageNotificationLevel;
+  auditLogReason?: string;
+}
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface IEditRole {
+  roleId: string;
+  name?: string;
+  color?: number | null;
+  hoist?: boolean;
+  mentionable?: boolean;
+  permissionsRaw?: string;
+  unicodeEmoji?: string | null;
+  auditLogReason?: string;
+}
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ScheduledEventEntityType } from "../discord/ScheduledEventEntityType";
+import type { ScheduledEventStatus } from "../discord/ScheduledEventStatus";
+
+export interface IEditScheduledEvent {
+  eventId: string;
+  name?: string;
+  description?: string;
+  entityType?: ScheduledEventEntityType;
+  channelId?: string;
+  location?: string;
+  scheduledStartTime?: number;
+  scheduledEndTime?: number;
+  status?: ScheduledEventStatus;
+  auditLogReason?: string;
+}
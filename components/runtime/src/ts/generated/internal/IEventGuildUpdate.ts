@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Guild } from "../discord/Guild";
+
+export interface IEventGuildUpdate {
+  new: Guild;
+  old: Guild | null;
+}
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpCreateMessageFields } from "./CreateMessageFields";
+
+export interface IExecuteWebhook {
+  webhookId: string;
+  threadId?: string;
+  wait: boolean;
+  username?: string;
+  avatarUrl?: string;
+  fields: OpCreateMessageFields;
+}
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AuditLogActionType } from "../discord/AuditLogActionType";
+
+export interface IGetAuditLog {
+  userId?: string;
+  actionType?: AuditLogActionType;
+  before?: string;
+  after?: string;
+  limit?: number;
+}
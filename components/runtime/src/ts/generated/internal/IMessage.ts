@@ -11,6 +11,7 @@ import type { MessageFlags } from "../discord/MessageFlags";
 import type { MessageReaction } from "../discord/MessageReaction";
 import type { MessageReference } from "../discord/MessageReference";
 import type { MessageType } from "../discord/MessageType";
+import type { IPoll } from "../discord/IPoll";
 import type { PartialMember } from "../discord/PartialMember";
 
 export interface IMessage {
@@ -39,4 +40,8 @@ export interface IMessage {
   timestamp: number;
   tts: boolean;
   webhookId: string | null;
+  /**
+   * Not available on the previous message of update and delete events
+   */
+  poll: IPoll | null;
 }
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IUser } from "./IUser";
+import type { ScheduledEventEntityType } from "../discord/ScheduledEventEntityType";
+import type { ScheduledEventStatus } from "../discord/ScheduledEventStatus";
+
+export interface IScheduledEvent {
+  id: string;
+  channelId: string | null;
+  creator: IUser | null;
+  creatorId: string | null;
+  description: string | null;
+  entityType: ScheduledEventEntityType;
+  location: string | null;
+  image: string | null;
+  name: string;
+  scheduledStartTime: number;
+  scheduledEndTime: number | null;
+  status: ScheduledEventStatus;
+  userCount: number | null;
+}
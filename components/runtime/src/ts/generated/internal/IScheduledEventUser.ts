@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IMember } from "./Member";
+import type { IUser } from "./IUser";
+
+export interface IScheduledEventUser {
+  user: IUser;
+  member: IMember | null;
+}
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IUser } from "./IUser";
+import type { StickerFormatType } from "../discord/StickerFormatType";
+import type { StickerType } from "../discord/StickerType";
+
+export interface ISticker {
+  available: boolean;
+  description: string | null;
+  formatType: StickerFormatType;
+  guildId: string | null;
+  id: string;
+  kind: StickerType;
+  name: string;
+  tags: string;
+  user: IUser | null;
+}
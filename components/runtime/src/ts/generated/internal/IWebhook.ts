@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IUser } from "./IUser";
+import type { WebhookType } from "../discord/WebhookType";
+
+export interface IWebhook {
+  id: string;
+  kind: WebhookType;
+  channelId: string;
+  name: string | null;
+  avatar: string | null;
+  applicationId: string | null;
+  user: IUser | null;
+}
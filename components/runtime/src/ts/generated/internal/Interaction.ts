@@ -5,5 +5,6 @@ import type { MessageComponentInteraction } from "./MessageComponentInteraction"
 
 export type Interaction =
   | { "kind": "Command" } & CommandInteraction
+  | { "kind": "Autocomplete" } & CommandInteraction
   | { "kind": "MessageComponent" } & MessageComponentInteraction
   | { "kind": "ModalSubmit" } & IModalInteraction;
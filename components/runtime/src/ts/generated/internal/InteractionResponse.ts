@@ -1,11 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IAutocompleteCallbackData } from "./IAutocompleteCallbackData";
 import type { IModalCallbackData } from "./IModalCallbackData";
 import type { InteractionCallbackData } from "./InteractionCallbackData";
 
 export type InteractionResponse =
   | { "kind": "Pong" }
-  | { "kind": "ChannelMessageWithSource" } & InteractionCallbackData
-  | { "kind": "DeferredChannelMessageWithSource" } & InteractionCallbackData
+  | { "kind": "ChannelMessageWithSource", "response": InteractionCallbackData }
+  | { "kind": "DeferredChannelMessageWithSource", "response": InteractionCallbackData }
   | { "kind": "DeferredUpdateMessage" }
-  | { "kind": "UpdateMessage" } & InteractionCallbackData
-  | { "kind": "Modal" } & IModalCallbackData;
+  | { "kind": "UpdateMessage", "response": InteractionCallbackData }
+  | { "kind": "Modal", "response": IModalCallbackData }
+  | { "kind": "Autocomplete", "response": IAutocompleteCallbackData };
@@ -3,6 +3,7 @@ import type { ISelfThreadMember } from "./ISelfThreadMember";
 import type { ThreadMetadata } from "../discord/ThreadMetadata";
 
 export interface IPublicThread {
+  appliedTags: Array<string>;
   defaultAutoArchiveDurationMinutes: number | null;
   id: string;
   kind: "PublicThread";
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpCreateMessageFields } from "./CreateMessageFields";
+
+export interface OpSendDirectMessage {
+  userId: string;
+  fields: OpCreateMessageFields;
+}
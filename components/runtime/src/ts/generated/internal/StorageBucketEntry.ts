@@ -7,4 +7,5 @@ export interface OpStorageBucketEntry {
   key: string;
   value: OpStorageBucketValue;
   expiresAt: number | null;
+  cursor: string;
 }
@@ -1,9 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpStorageBucketKeyRange } from "./StorageBucketKeyRange";
 
 export interface OpStorageBucketList {
   bucketName: string;
   keyPattern?: string;
   after?: string;
   limit?: number;
+  cursor?: string;
+  reverse?: boolean;
+  range?: OpStorageBucketKeyRange;
   pluginId: string | null;
 }
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpStorageBucketEntry } from "./StorageBucketEntry";
+
+export interface OpStorageBucketNeighbors {
+  rank: number;
+  before: Array<OpStorageBucketEntry>;
+  entry: OpStorageBucketEntry;
+  after: Array<OpStorageBucketEntry>;
+}
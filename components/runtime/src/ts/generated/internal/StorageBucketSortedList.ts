@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpStorageBucketKeyRange } from "./StorageBucketKeyRange";
 import type { OpStorageBucketListOrder } from "./StorageBucketListOrder";
 
 export interface OpStorageBucketSortedList {
@@ -6,5 +7,7 @@ export interface OpStorageBucketSortedList {
   pluginId: string | null;
   offset?: number;
   limit?: number;
+  cursor?: string;
+  range?: OpStorageBucketKeyRange;
   order: OpStorageBucketListOrder;
 }
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpStorageBucketListOrder } from "./StorageBucketListOrder";
+
+export interface OpStorageBucketSortedNeighbors {
+  bucketName: string;
+  pluginId: string | null;
+  key: string;
+  order: OpStorageBucketListOrder;
+  before?: number;
+  after?: number;
+}
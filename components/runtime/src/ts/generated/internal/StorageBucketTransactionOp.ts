@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpStorageBucketEntryId } from "./StorageBucketEntryId";
+import type { OpStorageBucketIncr } from "./StorageBucketIncr";
+import type { OpStorageBucketSetIf } from "./StorageBucketSetIf";
+import type { OpStorageBucketSetValue } from "./StorageBucketSetValue";
+
+export type OpStorageBucketTransactionOp =
+  | { "kind": "Set", "op": OpStorageBucketSetValue }
+  | { "kind": "SetIf", "op": OpStorageBucketSetIf }
+  | { "kind": "Incr", "op": OpStorageBucketIncr }
+  | { "kind": "Del", "op": OpStorageBucketEntryId };
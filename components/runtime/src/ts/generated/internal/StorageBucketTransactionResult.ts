@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpStorageBucketEntry } from "./StorageBucketEntry";
+
+export type OpStorageBucketTransactionResult = {
+  "kind": "Committed";
+  entries: Array<OpStorageBucketEntry | null>;
+} | { "kind": "ConditionFailed"; index: number };
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type OpStorageBucketValue = { "json": any } | { "double": number };
+export type OpStorageBucketValue = { "json": any } | { "double": number } | { "bytes": Uint8Array };
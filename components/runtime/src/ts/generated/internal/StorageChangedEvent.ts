@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpStorageValueMeta } from "./StorageValueMeta";
+
+export interface StorageChangedEvent {
+  pluginId: string | null;
+  bucketName: string;
+  key: string;
+  /**
+   * Empty if the key didn't exist before
+   */
+  old: OpStorageValueMeta | null;
+  /**
+   * Empty if the key was deleted
+   */
+  new: OpStorageValueMeta | null;
+  /**
+   * How many STORAGE_CHANGED handlers deep the change was made, 0 if it wasn't made from one
+   */
+  depth: number;
+}
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpStorageValueKind } from "./StorageValueKind";
+
+/**
+ * Describes a value without including it, the values themselves can be up to 1MB
+ */
+export interface OpStorageValueMeta {
+  kind: OpStorageValueKind;
+  /**
+   * The value itself for number values
+   */
+  number: number | null;
+  /**
+   * Size of the value in bytes, json values are measured serialized
+   */
+  size: number;
+  expiresAt: number | null;
+}
@@ -1,7 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ForumDefaultReaction } from "../discord/ForumDefaultReaction";
+import type { ForumSortOrder } from "../discord/ForumSortOrder";
+import type { ForumTag } from "../discord/ForumTag";
 import type { IPermissionOverwrite } from "../discord/IPermissionOverwrite";
 
 export interface ITextChannel {
+  availableTags: Array<ForumTag>;
+  defaultReactionEmoji: ForumDefaultReaction | null;
+  defaultSortOrder: ForumSortOrder | null;
   id: string;
   kind: "Text" | "News" | "Forum" | "GuildDirectory";
   lastPinTimestamp: number | null;
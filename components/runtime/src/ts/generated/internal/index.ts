@@ -20,6 +20,7 @@ export * from './ConsoleLogMessage'
 export * from './CreateBanFields'
 export * from './CreateChannelMessage'
 export * from './CreateFollowUpMessage'
+export * from './CreateMessageAttachment'
 export * from './CreateMessageFields'
 export * from './CreateScheduledTask'
 export * from './DeleteMessagesBulk'
@@ -36,17 +37,44 @@ export * from './GetGuildTasksFilter'
 export * from './GetMessages'
 export * from './GetReactions'
 export * from './GuildChannel'
+export * from './IAuditLog'
+export * from './IAutocompleteCallbackData'
+export * from './ICreateAutoModerationRule'
 export * from './ICreateChannel'
+export * from './ICreateEmoji'
 export * from './ICreateForumThread'
 export * from './ICreateInviteFields'
+export * from './ICreatePollAnswer'
+export * from './ICreatePoll'
+export * from './ICreateRole'
+export * from './ICreateScheduledEvent'
+export * from './ICreateSticker'
 export * from './ICreateThreadFromMessage'
 export * from './ICreateThread'
+export * from './ICreateWebhook'
+export * from './ICustomEmoji'
+export * from './IEditAutoModerationRule'
+export * from './IEditEmoji'
+export * from './IEditGuild'
+export * from './IEditRole'
+export * from './IEditScheduledEvent'
+export * from './IEditSticker'
+export * from './IEventGuildEmojisUpdate'
+export * from './IEventGuildStickersUpdate'
+export * from './IEventGuildUpdate'
 export * from './IEventInviteCreate'
 export * from './IEventInviteDelete'
+export * from './IEventRoleDelete'
+export * from './IEventRoleUpdate'
 export * from './IEventThreadListSync'
 export * from './IEventThreadMembersUpdate'
 export * from './IEventVoiceStateUpdate'
+export * from './IExecuteWebhook'
+export * from './IForumTagFields'
 export * from './IForumThreadResponse'
+export * from './IGetAuditLog'
+export * from './IGetScheduledEventUsers'
+export * from './IGuildImage'
 export * from './IInvite'
 export * from './IListThreadMembersRequest'
 export * from './IListThreadsRequest'
@@ -63,12 +91,18 @@ export * from './Interaction'
 export * from './IntervalTimerEvent'
 export * from './IntervalTimer'
 export * from './IntervalType'
+export * from './IScheduledEvent'
+export * from './IScheduledEventUser'
+export * from './ISearchMembers'
 export * from './ISelfThreadMember'
+export * from './ISticker'
 export * from './IThreadsListing'
+export * from './IUpdateRolePosition'
 export * from './IUpdateThread'
 export * from './IUserFlags'
 export * from './IUser'
 export * from './IVoiceState'
+export * from './IWebhook'
 export * from './Member'
 export * from './MentionParseTypes'
 export * from './MessageComponentInteraction'
@@ -80,17 +114,28 @@ export * from './ScheduledTask'
 export * from './ScopeSelector'
 export * from './ScriptMeta'
 export * from './ScriptTaskBucketId'
+export * from './SendDirectMessage'
 export * from './StorageBucketEntryId'
 export * from './StorageBucketEntry'
 export * from './StorageBucketIncr'
+export * from './StorageBucketKeyRange'
 export * from './StorageBucketListOrder'
 export * from './StorageBucketList'
+export * from './StorageBucketNeighbors'
 export * from './StorageBucketSetCondition'
 export * from './StorageBucketSetIf'
 export * from './StorageBucketSetValue'
 export * from './StorageBucketSortedList'
+export * from './StorageBucketSortedNeighbors'
+export * from './StorageBucketSortedRank'
+export * from './StorageBucketSubscribe'
+export * from './StorageBucketTransactionOp'
+export * from './StorageBucketTransactionResult'
 export * from './StorageBucket'
 export * from './StorageBucketValue'
+export * from './StorageChangedEvent'
+export * from './StorageValueKind'
+export * from './StorageValueMeta'
 export * from './TextChannel'
 export * from './ThreadMember'
 export * from './UnknownChannel'
@@ -1,7 +1,13 @@
-import { console as _console } from '../core_util';
+import { console as _console, setTimeout as _setTimeout, clearTimeout as _clearTimeout, setInterval as _setInterval, clearInterval as _clearInterval, sleep as _sleep } from '../core_util';
 
 declare global {
     const console: {
         log: typeof _console.log,
     }
+
+    const setTimeout: typeof _setTimeout;
+    const clearTimeout: typeof _clearTimeout;
+    const setInterval: typeof _setInterval;
+    const clearInterval: typeof _clearInterval;
+    const sleep: typeof _sleep;
 } 
\ No newline at end of file
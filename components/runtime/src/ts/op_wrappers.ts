@@ -9,6 +9,7 @@ import { VoiceState } from "./discord/events";
 
 const {
     op_bl_http_request_send,
+    op_bl_timer_wait,
     op_bl_schedule_task,
     op_bl_del_task,
     op_bl_del_task_by_key,
@@ -62,7 +63,10 @@ const {
     op_botloader_bucket_storage_count,
     op_botloader_bucket_storage_incr,
     op_botloader_bucket_storage_sorted_list,
+    op_botloader_bucket_storage_sorted_rank,
+    op_botloader_bucket_storage_sorted_neighbors,
     op_botloader_bucket_storage_set_if,
+    op_botloader_bucket_storage_transaction,
     op_discord_create_ban,
     op_discord_get_ban,
     op_discord_get_bans,
@@ -104,6 +108,20 @@ export namespace OpWrappers {
         }
     }
 
+    export namespace timers {
+        export function createTimer(delayMs: number, repeat: boolean): number {
+            return Deno.core.ops.op_bl_timer_create(delayMs, repeat)
+        }
+
+        export function waitTimer(rid: number): Promise<boolean> {
+            return op_bl_timer_wait(rid)
+        }
+
+        export function cancelTimer(rid: number) {
+            Deno.core.ops.op_bl_timer_cancel(rid)
+        }
+    }
+
     export namespace tasks {
         export function scheduleTask(data: Internal.CreateScheduledTask): Promise<Internal.ScheduledTask> {
             return op_bl_schedule_task(data)
@@ -146,6 +164,12 @@ export namespace OpWrappers {
         );
     }
 
+    export function consoleLogError(args: Internal.ConsoleLogMessage) {
+        Deno.core.ops.op_botloader_log_error(
+            args
+        );
+    }
+
     export async function callAsyncOp<T extends Internal.EasyOpsASync>(call: T): Promise<Internal.EasyOpsReturnTypesASync[T["kind"]]> {
         return await op_easyops_async(call)
     }
@@ -438,6 +462,30 @@ export namespace OpWrappers {
         return await op_botloader_bucket_storage_sorted_list(opts);
     }
 
+    export async function bucketStorageSortedRank(opts: Internal.OpStorageBucketSortedRank): Promise<number | null> {
+        return await op_botloader_bucket_storage_sorted_rank(opts);
+    }
+
+    export async function bucketStorageSortedNeighbors(opts: Internal.OpStorageBucketSortedNeighbors): Promise<Internal.OpStorageBucketNeighbors | null> {
+        return await op_botloader_bucket_storage_sorted_neighbors(opts);
+    }
+
+    export function bucketStorageSubscribe(opts: Internal.OpStorageBucketSubscribe) {
+        Deno.core.ops.op_botloader_bucket_storage_subscribe(opts);
+    }
+
+    export function storageChangedHandlerEnter(depth: number) {
+        Deno.core.ops.op_botloader_storage_changed_handler_enter(depth);
+    }
+
+    export function storageChangedHandlerExit(depth: number) {
+        Deno.core.ops.op_botloader_storage_changed_handler_exit(depth);
+    }
+
+    export async function bucketStorageTransaction(ops: Internal.OpStorageBucketTransactionOp[]): Promise<Internal.OpStorageBucketTransactionResult> {
+        return await op_botloader_bucket_storage_transaction(ops);
+    }
+
     // Bans
     export async function createBan(userId: string, extras: Internal.CreateBanFields): Promise<void> {
         return await op_discord_create_ban(userId, extras);
@@ -8,7 +8,7 @@ import { EventSystem } from "./eventsystem";
 import { OpWrappers } from "./op_wrappers";
 import { Storage } from "./storage";
 import { Tasks } from "./scheduled_tasks";
-import { ComponentInteraction, SelectMenuInteraction, ModalSubmitInteraction } from "./discord/index";
+import { ComponentInteraction, SelectMenuInteraction, ModalSubmitInteraction, Permissions, PermissionResolvable } from "./discord/index";
 
 /**
  * The script class is the main way you interact with botloader and discord.
@@ -122,6 +122,21 @@ export class Script {
         return bucket;
     }
 
+    /**
+     * Creates a new binary storage bucket for persistent storage.
+     * 
+     * This is the same as {@link createStorageJson} except that this bucket stores `Uint8Array` values,
+     * use it for images, compressed data and other binary formats instead of base64 encoding them into json.
+     * 
+     * See {@link createStorageJson} for more general info on storage buckets
+     */
+    createStorageBytes(namespace: string) {
+        let bucket = new Storage.BytesBucket(namespace, this.storagePluginId());
+        this.storageBuckets.push(bucket);
+
+        return bucket;
+    }
+
     /**
      * Create a new persistent variable.
      * 
@@ -150,6 +165,63 @@ export class Script {
         return new Storage.NumberVar(namespace, key, this.storagePluginId());
     }
 
+    /**
+     * Create a new persistent variable holding binary data.
+     * 
+     * This is built on top of storage buckets so you can think of it as a single entry inside a storage bucket.
+     * 
+     * @param key Key for the variable inside the storage bucket, the same key in another script will refer to the same entry
+     * @param options Additional options
+     * @returns 
+     */
+    createStorageVarBytes(key: string, options?: StorageVarExtraOptions) {
+        const namespace = options?.namespace ?? "bl:vars_bytes";
+        return new Storage.BytesVar(namespace, key, this.storagePluginId());
+    }
+
+    /**
+     * Run the callback whenever an entry in the bucket is changed, by this or any other script or plugin in the server.
+     * 
+     * The event includes metadata about the old and new value but not the values themselves, fetch the entry if you need it.
+     * Entries removed through {@link Storage.Bucket.deleteAll} trigger an event for each deleted entry, up to 25 per call.
+     * 
+     * Writing to the same bucket from the callback will trigger the callback again, to stop loops like this events
+     * caused by handlers triggering each other more than 10 times in a row are dropped and logged.
+     * Events for a bucket are also paused for a while if too many of them are dispatched in a short time.
+     * 
+     * @param bucket The bucket to watch
+     * @param cb The callback to run when an entry changes
+     * @param options Only watch keys starting with `keyPrefix`
+     * 
+     * @example ```ts
+     * const scores = script.createStorageNumber("scores");
+     * script.onStorageChanged(scores, (evt) => {
+     *     console.log(`${evt.key} changed from ${evt.old?.number} to ${evt.new?.number}`);
+     * });
+     * ```
+     */
+    onStorageChanged(bucket: Storage.Bucket<unknown>, cb: (evt: Internal.StorageChangedEvent) => any, options?: { keyPrefix?: string }) {
+        const keyPrefix = options?.keyPrefix ?? "";
+
+        OpWrappers.bucketStorageSubscribe({
+            bucketName: bucket.name,
+            pluginId: bucket.pluginId,
+            keyPrefix: keyPrefix || undefined,
+        });
+
+        this.events.on("STORAGE_CHANGED", async (evt) => {
+            if (evt.bucketName === bucket.name && evt.pluginId === bucket.pluginId && evt.key.startsWith(keyPrefix)) {
+                // writes made while this runs are marked as coming from this handler for the loop detection
+                OpWrappers.storageChangedHandlerEnter(evt.depth);
+                try {
+                    await cb(evt);
+                } finally {
+                    OpWrappers.storageChangedHandlerExit(evt.depth);
+                }
+            }
+        });
+    }
+
 
     /**
      * Register a scheduled task handler for the provided namespace.
@@ -266,16 +338,21 @@ export class Script {
     }
 
     on(eventType: "MESSAGE_DELETE", cb: (evt: EventSystem.EventTypes["MESSAGE_DELETE"]) => void): void;
+    on(eventType: "MESSAGE_DELETE_BULK", cb: (evt: EventSystem.EventTypes["MESSAGE_DELETE_BULK"]) => void): void;
     on(eventType: "MESSAGE_UPDATE", cb: (evt: EventSystem.EventTypes["MESSAGE_UPDATE"]) => void): void;
     on(eventType: "MESSAGE_CREATE", cb: (evt: EventSystem.EventTypes["MESSAGE_CREATE"]) => void): void;
     on(eventType: "MEMBER_ADD", cb: (evt: EventSystem.EventTypes["MEMBER_ADD"]) => void): void;
     on(eventType: "MEMBER_UPDATE", cb: (evt: EventSystem.EventTypes["MEMBER_UPDATE"]) => void): void;
     on(eventType: "MEMBER_REMOVE", cb: (evt: EventSystem.EventTypes["MEMBER_REMOVE"]) => void): void;
+    on(eventType: "BAN_ADD", cb: (evt: EventSystem.EventTypes["BAN_ADD"]) => void): void;
+    on(eventType: "BAN_REMOVE", cb: (evt: EventSystem.EventTypes["BAN_REMOVE"]) => void): void;
     on(eventType: "VOICE_STATE_UPDATE", cb: (evt: EventSystem.EventTypes["VOICE_STATE_UPDATE"]) => void): void;
     on(eventType: "MESSAGE_REACTION_ADD", cb: (evt: EventSystem.EventTypes["MESSAGE_REACTION_ADD"]) => void): void;
     on(eventType: "MESSAGE_REACTION_REMOVE", cb: (evt: EventSystem.EventTypes["MESSAGE_REACTION_REMOVE"]) => void): void;
     on(eventType: "MESSAGE_REACTION_REMOVE_ALL", cb: (evt: EventSystem.EventTypes["MESSAGE_REACTION_REMOVE_ALL"]) => void): void;
     on(eventType: "MESSAGE_REACTION_REMOVE_ALL_EMOJI", cb: (evt: EventSystem.EventTypes["MESSAGE_REACTION_REMOVE_ALL_EMOJI"]) => void): void;
+    on(eventType: "MESSAGE_POLL_VOTE_ADD", cb: (evt: EventSystem.EventTypes["MESSAGE_POLL_VOTE_ADD"]) => void): void;
+    on(eventType: "MESSAGE_POLL_VOTE_REMOVE", cb: (evt: EventSystem.EventTypes["MESSAGE_POLL_VOTE_REMOVE"]) => void): void;
     on(eventType: "CHANNEL_CREATE", cb: (evt: EventSystem.EventTypes["CHANNEL_CREATE"]) => void): void;
     on(eventType: "CHANNEL_UPDATE", cb: (evt: EventSystem.EventTypes["CHANNEL_UPDATE"]) => void): void;
     on(eventType: "CHANNEL_DELETE", cb: (evt: EventSystem.EventTypes["CHANNEL_DELETE"]) => void): void;
@@ -287,6 +364,20 @@ export class Script {
     on(eventType: "THREAD_MEMBERS_UPDATE", cb: (evt: EventSystem.EventTypes["THREAD_MEMBERS_UPDATE"]) => void): void;
     on(eventType: "INVITE_CREATE", cb: (evt: EventSystem.EventTypes["INVITE_CREATE"]) => void): void;
     on(eventType: "INVITE_DELETE", cb: (evt: EventSystem.EventTypes["INVITE_DELETE"]) => void): void;
+    on(eventType: "GUILD_EMOJIS_UPDATE", cb: (evt: EventSystem.EventTypes["GUILD_EMOJIS_UPDATE"]) => void): void;
+    on(eventType: "GUILD_STICKERS_UPDATE", cb: (evt: EventSystem.EventTypes["GUILD_STICKERS_UPDATE"]) => void): void;
+    on(eventType: "GUILD_AUDIT_LOG_ENTRY_CREATE", cb: (evt: EventSystem.EventTypes["GUILD_AUDIT_LOG_ENTRY_CREATE"]) => void): void;
+    on(eventType: "GUILD_SCHEDULED_EVENT_CREATE", cb: (evt: EventSystem.EventTypes["GUILD_SCHEDULED_EVENT_CREATE"]) => void): void;
+    on(eventType: "GUILD_SCHEDULED_EVENT_UPDATE", cb: (evt: EventSystem.EventTypes["GUILD_SCHEDULED_EVENT_UPDATE"]) => void): void;
+    on(eventType: "GUILD_SCHEDULED_EVENT_DELETE", cb: (evt: EventSystem.EventTypes["GUILD_SCHEDULED_EVENT_DELETE"]) => void): void;
+    on(eventType: "GUILD_SCHEDULED_EVENT_USER_ADD", cb: (evt: EventSystem.EventTypes["GUILD_SCHEDULED_EVENT_USER_ADD"]) => void): void;
+    on(eventType: "GUILD_SCHEDULED_EVENT_USER_REMOVE", cb: (evt: EventSystem.EventTypes["GUILD_SCHEDULED_EVENT_USER_REMOVE"]) => void): void;
+    on(eventType: "AUTO_MODERATION_ACTION_EXECUTION", cb: (evt: EventSystem.EventTypes["AUTO_MODERATION_ACTION_EXECUTION"]) => void): void;
+    on(eventType: "ROLE_CREATE", cb: (evt: EventSystem.EventTypes["ROLE_CREATE"]) => void): void;
+    on(eventType: "ROLE_UPDATE", cb: (evt: EventSystem.EventTypes["ROLE_UPDATE"]) => void): void;
+    on(eventType: "ROLE_DELETE", cb: (evt: EventSystem.EventTypes["ROLE_DELETE"]) => void): void;
+    on(eventType: "GUILD_UPDATE", cb: (evt: EventSystem.EventTypes["GUILD_UPDATE"]) => void): void;
+    on(eventType: "STORAGE_CHANGED", cb: (evt: EventSystem.EventTypes["STORAGE_CHANGED"]) => void): void;
 
     /**
      * Register a general event handler such as for arbitrary discord events like when a new message is sent in the server (MESSAGE_CREATE)
@@ -347,7 +438,13 @@ export class Script {
                         description: entry.description,
                         kind: entry.kind,
                         required: entry.required || false,
-                        extraOptions: entry.extraOptions,
+                        nameLocalizations: entry.nameLocalizations as Record<string, string> | undefined,
+                        descriptionLocalizations: entry.descriptionLocalizations as Record<string, string> | undefined,
+                        extraOptions: {
+                            ...entry.extraOptions,
+                            // the provider itself stays in the script, discord only needs to know it's enabled
+                            autocomplete: "autocomplete" in entry.extraOptions && entry.extraOptions.autocomplete ? true : undefined,
+                        },
                     })
                 }
             }
@@ -370,6 +467,10 @@ export class Script {
                 kind: cmd.kind,
                 group,
                 subGroup,
+                defaultMemberPermissions: resolvePermissions(cmd.defaultMemberPermissions),
+                nsfw: cmd.nsfw,
+                nameLocalizations: cmd.nameLocalizations as Record<string, string> | undefined,
+                descriptionLocalizations: cmd.descriptionLocalizations as Record<string, string> | undefined,
             }
         });
 
@@ -388,6 +489,8 @@ export class Script {
                             name: cmd.group.parent.name,
                             description: cmd.group.description,
                             subGroups: [],
+                            defaultMemberPermissions: resolvePermissions(cmd.group.parent.defaultMemberPermissions),
+                            nsfw: cmd.group.parent.nsfw,
                         }
                         groups.push(parent);
                     }
@@ -414,6 +517,8 @@ export class Script {
                         description: cmd.group.description,
                         // we cannot have both commands and subgroups, unless im mistaken?
                         subGroups: [],
+                        defaultMemberPermissions: resolvePermissions(cmd.group.defaultMemberPermissions),
+                        nsfw: cmd.group.nsfw,
                     })
                 }
             }
@@ -423,6 +528,10 @@ export class Script {
     }
 }
 
+function resolvePermissions(permissions?: PermissionResolvable): string | undefined {
+    return permissions !== undefined ? Permissions.resolve(permissions).toString() : undefined;
+}
+
 interface IntervalTimerListener {
     timer: Internal.IntervalTimer,
     callback: () => any,
@@ -14,14 +14,49 @@ export namespace Storage {
         ttl?: number,
     }
 
-    export interface ListOptions {
+    export interface KeyRangeOptions {
+        /**
+         * Only return entries whose key starts with this prefix.
+         * 
+         * Unlike `keyPattern` this uses the index, making it fast even on large buckets.
+         * 
+         * Can't be combined with `start` and `end`.
+         */
+        keyPrefix?: string,
+
+        /**
+         * Only return entries whose key is greater than or equal to this key.
+         * 
+         * Keys are compared by their bytes, so `"B"` comes before `"a"`.
+         */
+        start?: string,
+
+        /**
+         * Only return entries whose key is less than this key (exclusive).
+         */
+        end?: string,
+    }
+
+    export interface ListOptions extends KeyRangeOptions {
         /**
          * Only return entires after this key.
          * 
-         * Use this to paginate through results, using the key of the last entry in the list call. 
+         * @deprecated use `cursor` instead
          */
         after?: string,
 
+        /**
+         * Continue listing after the entry this cursor was taken from.
+         * 
+         * Use this to paginate through results, using the `cursor` of the last entry in the list call.
+         */
+        cursor?: string,
+
+        /**
+         * Iterate the keys in descending order instead
+         */
+        reverse?: boolean,
+
         /**
          * Number of entries to return, max 100.
          * 
@@ -43,12 +78,20 @@ export namespace Storage {
         keyPattern?: string
     }
 
-    export interface SortedListOptions {
+    export interface SortedListOptions extends KeyRangeOptions {
         /**
-         * How many entries to skip, useful for paginating through the list
+         * How many entries to skip
          */
         offset?: number,
 
+        /**
+         * Continue listing after the entry this cursor was taken from.
+         * 
+         * Use this to paginate through the list using the `cursor` of the last entry in the previous call,
+         * this is a lot faster than `offset` on large buckets.
+         */
+        cursor?: string,
+
         /**
          * Number of entries to return, max 100.
          * 
@@ -57,6 +100,41 @@ export namespace Storage {
         limit?: number,
     }
 
+    export interface NeighborsOptions {
+        /**
+         * Number of entries ranked above the key to return, max 50.
+         * 
+         * Defaults to 5.
+         */
+        before?: number,
+
+        /**
+         * Number of entries ranked below the key to return, max 50.
+         * 
+         * Defaults to 5.
+         */
+        after?: number,
+    }
+
+    export interface Neighbors<T> {
+        /**
+         * The 1-based rank of the entry
+         */
+        rank: number,
+
+        /**
+         * Entries ranked above the entry, in sorted order
+         */
+        before: Entry<T>[],
+
+        entry: Entry<T>,
+
+        /**
+         * Entries ranked below the entry, in sorted order
+         */
+        after: Entry<T>[],
+    }
+
     export interface Entry<T> {
         /**
          * This entry belongs to the specified plugin
@@ -80,6 +158,19 @@ export namespace Storage {
          * If a ttl was set, when this entry expires
          */
         expiresAt?: Date,
+
+        /**
+         * Opaque position of this entry, pass it as the `cursor` option to continue listing after this entry
+         */
+        cursor: string,
+    }
+
+    function keyRangeFromOptions(options: KeyRangeOptions): Internal.OpStorageBucketKeyRange {
+        return {
+            keyPrefix: options.keyPrefix,
+            start: options.start,
+            end: options.end,
+        }
     }
 
     /**
@@ -122,6 +213,7 @@ export namespace Storage {
                 key: entry.key,
                 value: val,
                 expiresAt: entry.expiresAt ? new Date(entry.expiresAt) : undefined,
+                cursor: entry.cursor,
             }
         }
 
@@ -133,6 +225,20 @@ export namespace Storage {
             }
         }
 
+        /**
+         * @internal
+         */
+        transactionValue(v: T): Internal.OpStorageBucketValue {
+            return this.intoInternalValue(v);
+        }
+
+        /**
+         * @internal
+         */
+        transactionEntry(entry: Internal.OpStorageBucketEntry | null): Entry<T> | undefined {
+            return this.entryFromInternalOptional(entry);
+        }
+
         /**
          * Store a value at the provided key in the bucket, this will overwrite the previous value stored there, if any.
          * 
@@ -220,7 +326,7 @@ export namespace Storage {
 
 
         /**
-         * Retrieve a list of entries from the database, you can use `after` to paginate through all the items in the bucket.
+         * Retrieve a list of entries from the database, you can use `cursor` to paginate through all the items in the bucket.
          * 
          * @param options Pagination options
          * @returns A list of entries
@@ -232,6 +338,9 @@ export namespace Storage {
                 after: options.after,
                 keyPattern: options.keyPattern,
                 limit: options.limit,
+                cursor: options.cursor,
+                reverse: options.reverse,
+                range: keyRangeFromOptions(options),
             });
 
             return res.map(v => this.entryFromInternal(v));
@@ -304,11 +413,63 @@ export namespace Storage {
                 bucketName: this.name,
                 limit: options?.limit,
                 offset: options?.offset,
+                cursor: options?.cursor,
+                range: options ? keyRangeFromOptions(options) : undefined,
                 order: order,
             });
 
             return res.map(v => this.entryFromInternal(v));
         }
+
+        /**
+         * Returns the 1-based rank of the entry at key in the sorted order, ties are broken by the time they were last updated.
+         * 
+         * Note: the entries ranked above the key are counted, so this gets slower the further down the key is ranked.
+         * 
+         * @param key The key whose rank to return
+         * @param order The order the bucket is ranked by, `Descending` gives the highest value rank 1
+         * @returns The rank, or undefined if there is no entry at key
+         */
+        async rank(key: string, order: "Ascending" | "Descending"): Promise<number | undefined> {
+            const res = await OpWrappers.bucketStorageSortedRank({
+                pluginId: this.pluginId,
+                bucketName: this.name,
+                key: key,
+                order: order,
+            });
+
+            return res ?? undefined;
+        }
+
+        /**
+         * Returns the entry at key along with the entries ranked right above and below it, useful for showing a users position on a leaderboard
+         * 
+         * @param key The key to return the neighbors of
+         * @param order The order the bucket is ranked by
+         * @param options How many entries to return on each side
+         * @returns The neighbors, or undefined if there is no entry at key
+         */
+        async neighbors(key: string, order: "Ascending" | "Descending", options?: NeighborsOptions): Promise<Neighbors<number> | undefined> {
+            const res = await OpWrappers.bucketStorageSortedNeighbors({
+                pluginId: this.pluginId,
+                bucketName: this.name,
+                key: key,
+                order: order,
+                before: options?.before,
+                after: options?.after,
+            });
+
+            if (!res) {
+                return undefined;
+            }
+
+            return {
+                rank: res.rank,
+                before: res.before.map(v => this.entryFromInternal(v)),
+                entry: this.entryFromInternal(res.entry),
+                after: res.after.map(v => this.entryFromInternal(v)),
+            };
+        }
     }
 
     /**
@@ -334,6 +495,158 @@ export namespace Storage {
         }
     }
 
+    /**
+     * A Bucket holding binary data
+     * 
+     * Use this for things like images or compressed data instead of storing them as base64 strings in a {@link JsonBucket},
+     * values are limited to 1MB and count towards the storage limit of the server like any other value.
+     * 
+     * {@see} {@link Bucket} for more info on buckets.
+     */
+    export class BytesBucket extends Bucket<Uint8Array>{
+        protected intoInternalValue(v: Uint8Array): Internal.OpStorageBucketValue {
+            return {
+                bytes: v,
+            }
+        }
+
+        protected fromInternalValue(v: Internal.OpStorageBucketValue): Uint8Array | undefined {
+            if ('bytes' in v) {
+                return v.bytes;
+            }
+
+            return undefined
+        }
+    }
+
+    export type TransactionResult = {
+        committed: true,
+
+        /**
+         * The resulting entry of each operation in the order they were added,
+         * undefined for deletes of keys that didn't exist
+         */
+        entries: (Entry<any> | undefined)[],
+    } | {
+        committed: false,
+
+        /**
+         * Index of the conditional set whose condition failed
+         */
+        failedIndex: number,
+    };
+
+    /**
+     * A list of storage operations that are applied atomically, either all of them are applied or none of them are.
+     * 
+     * The operations can span multiple buckets, a transaction can hold at most 25 operations.
+     * 
+     * @example
+     * ```ts
+     * const res = await new Storage.Transaction()
+     *     .incr(balances, fromUserId, -amount)
+     *     .incr(balances, toUserId, amount)
+     *     .setIf(transfers, transferId, { from: fromUserId, to: toUserId, amount }, "IfNotExists")
+     *     .commit();
+     * 
+     * if (!res.committed) {
+     *     // the transfer was already processed, nothing was changed
+     * }
+     * ```
+     */
+    export class Transaction {
+        private ops: Internal.OpStorageBucketTransactionOp[] = [];
+        private buckets: Bucket<any>[] = [];
+
+        /**
+         * Store a value at the provided key, see {@link Bucket.set}
+         */
+        set<T>(bucket: Bucket<T>, key: string, value: T, options?: SetValueOptions) {
+            return this.push(bucket, {
+                kind: "Set",
+                op: {
+                    pluginId: bucket.pluginId,
+                    bucketName: bucket.name,
+                    key,
+                    value: bucket.transactionValue(value),
+                    ttl: options?.ttl,
+                },
+            });
+        }
+
+        /**
+         * Store a value at the provided key conditionally, see {@link Bucket.setIf}
+         * 
+         * If the condition fails the whole transaction is cancelled and nothing is applied.
+         */
+        setIf<T>(bucket: Bucket<T>, key: string, value: T, cond: "IfExists" | "IfNotExists", options?: SetValueOptions) {
+            return this.push(bucket, {
+                kind: "SetIf",
+                op: {
+                    pluginId: bucket.pluginId,
+                    bucketName: bucket.name,
+                    key,
+                    value: bucket.transactionValue(value),
+                    ttl: options?.ttl,
+                    cond,
+                },
+            });
+        }
+
+        /**
+         * Increment the value stored at the provided key, see {@link NumberBucket.incr}
+         */
+        incr(bucket: NumberBucket, key: string, amount: number) {
+            return this.push(bucket, {
+                kind: "Incr",
+                op: {
+                    pluginId: bucket.pluginId,
+                    bucketName: bucket.name,
+                    key,
+                    amount,
+                },
+            });
+        }
+
+        /**
+         * Delete the provided key, see {@link Bucket.delete}
+         */
+        delete(bucket: Bucket<any>, key: string) {
+            return this.push(bucket, {
+                kind: "Del",
+                op: {
+                    pluginId: bucket.pluginId,
+                    bucketName: bucket.name,
+                    key,
+                },
+            });
+        }
+
+        /**
+         * Applies all the operations atomically
+         */
+        async commit(): Promise<TransactionResult> {
+            const res = await OpWrappers.bucketStorageTransaction(this.ops);
+            if (res.kind === "ConditionFailed") {
+                return {
+                    committed: false,
+                    failedIndex: res.index,
+                }
+            }
+
+            return {
+                committed: true,
+                entries: res.entries.map((v, i) => this.buckets[i].transactionEntry(v)),
+            }
+        }
+
+        private push(bucket: Bucket<any>, op: Internal.OpStorageBucketTransactionOp) {
+            this.ops.push(op);
+            this.buckets.push(bucket);
+            return this;
+        }
+    }
+
     /**
      * A single persistent variable.
      * 
@@ -437,4 +750,19 @@ export namespace Storage {
             this.bucket = bucket;
         }
     }
+
+    export class BytesVar extends Var<Uint8Array> {
+        bucket: BytesBucket;
+
+        /**
+         * This constructor is unstable, you should use the related script methods.
+         * 
+         * @internal
+         */
+        constructor(namespace: string, key: string, pluginId: string | null) {
+            const bucket = new BytesBucket(namespace, pluginId);
+            super(bucket, key);
+            this.bucket = bucket;
+        }
+    }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use runtime_models::internal::script::ScriptMeta;
+use runtime_models::internal::{script::ScriptMeta, storage::StorageChangedEvent};
 use serde::{Deserialize, Serialize};
 use stores::config::{PremiumSlotTier, Script};
 use twilight_model::id::{marker::GuildMarker, Id};
@@ -60,6 +60,7 @@ pub enum WorkerMessage {
     GuildLog(guild_logger::LogEntry),
     Hello(u64),
     Metric(String, MetricEvent, HashMap<String, String>),
+    StorageChanged(StorageChangedEvent),
 }
 
 impl WorkerMessage {
@@ -74,6 +75,7 @@ impl WorkerMessage {
             WorkerMessage::GuildLog(_) => "GuildLog",
             WorkerMessage::Hello(_) => "Hello",
             WorkerMessage::Metric(_, _, _) => "Metric",
+            WorkerMessage::StorageChanged(_) => "StorageChanged",
         }
     }
 }
@@ -0,0 +1,9 @@
+-- Tokens for webhooks created through scripts, these are never exposed to the scripts themselves
+CREATE TABLE IF NOT EXISTS guild_webhooks (
+    guild_id bigint NOT NULL,
+    webhook_id bigint NOT NULL,
+    channel_id bigint NOT NULL,
+    token text NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (guild_id, webhook_id)
+);
@@ -0,0 +1,2 @@
+-- Per guild opt-in for scripts sending direct messages to members
+ALTER TABLE guild_meta_configs ADD COLUMN IF NOT EXISTS allow_direct_messages boolean NOT NULL DEFAULT false;
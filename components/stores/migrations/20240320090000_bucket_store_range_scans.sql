@@ -0,0 +1,9 @@
+-- Byte-wise key ordering for cursor pagination and key range scans,
+-- independent of the database collation
+CREATE INDEX bucket_store_key_c_idx ON bucket_store(guild_id, plugin_id, bucket, (key COLLATE "C"));
+
+-- Sorted listings are paginated on (value_float, updated_at, key)
+DROP INDEX bucket_store_float_idx;
+
+CREATE INDEX bucket_store_float_idx ON bucket_store(guild_id, plugin_id, bucket, value_float, updated_at, (key COLLATE "C"))
+WHERE (value_float IS NOT NULL);
@@ -0,0 +1,2 @@
+-- binary values, existing rows keep their json or float value
+ALTER TABLE bucket_store ADD COLUMN value_bytes bytea;
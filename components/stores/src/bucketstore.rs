@@ -1,6 +1,8 @@
 use std::time::Duration;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use twilight_model::id::{marker::GuildMarker, Id};
 
@@ -33,7 +35,7 @@ pub trait BucketStore: Send + Sync {
         key: String,
         value: StoreValue,
         ttl: Option<Duration>,
-    ) -> StoreResult<Entry>;
+    ) -> StoreResult<EntryWrite>;
 
     #[allow(clippy::too_many_arguments)]
     async fn set_if(
@@ -45,7 +47,7 @@ pub trait BucketStore: Send + Sync {
         value: StoreValue,
         ttl: Option<Duration>,
         cond: SetCondition,
-    ) -> StoreResult<Option<Entry>>;
+    ) -> StoreResult<Option<EntryWrite>>;
 
     async fn del(
         &self,
@@ -63,14 +65,21 @@ pub trait BucketStore: Send + Sync {
         key_pattern: String,
     ) -> StoreResult<u64>;
 
-    async fn get_many(
+    /// Same as [`BucketStore::del_many`] but returns the keys and meta of the deleted entries
+    async fn del_many_meta(
         &self,
         guild_id: Id<GuildMarker>,
         plugin_id: Option<u64>,
         bucket: String,
         key_pattern: String,
-        after: String,
-        limit: u32,
+    ) -> StoreResult<Vec<DeletedEntry>>;
+
+    async fn get_many(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        query: ListQuery,
     ) -> StoreResult<Vec<Entry>>;
 
     async fn count(
@@ -91,31 +100,200 @@ pub trait BucketStore: Send + Sync {
         bucket: String,
         key: String,
         incr_by: f64,
-    ) -> StoreResult<Entry>;
+    ) -> StoreResult<EntryWrite>;
 
     async fn sorted_entries(
         &self,
         guild_id: Id<GuildMarker>,
         plugin_id: Option<u64>,
         bucket: String,
-        order: SortedOrder,
-        offset: u32,
-        limit: u32,
+        query: SortedQuery,
     ) -> StoreResult<Vec<Entry>>;
 
+    /// Returns the 1-based rank of the key in the sorted order, None if the key doesn't exist or
+    /// doesn't hold a number
+    ///
+    /// The entries ranked above the key are counted, so the cost grows with the rank
+    async fn sorted_rank(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+        order: SortedOrder,
+    ) -> StoreResult<Option<u64>>;
+
+    /// Returns the entry at key along with up to `before` entries ranked above it and
+    /// up to `after` entries ranked below it, None if the key doesn't exist or doesn't hold a number
+    ///
+    /// This ranks the entry the same way as [`BucketStore::sorted_rank`]
+    #[allow(clippy::too_many_arguments)]
+    async fn sorted_neighbors(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+        order: SortedOrder,
+        before: u32,
+        after: u32,
+    ) -> StoreResult<Option<SortedNeighbors>>;
+
+    /// Applies all the operations atomically, if the condition of a conditional set
+    /// fails then nothing is applied
+    async fn transaction(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ops: Vec<TransactionOp>,
+    ) -> StoreResult<TransactionResult>;
+
     async fn delete_guild_bucket_store_data(&self, id: Id<GuildMarker>) -> StoreResult<()>;
 }
 
+pub enum TransactionOp {
+    Set {
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+        value: StoreValue,
+        ttl: Option<Duration>,
+        cond: Option<SetCondition>,
+    },
+    Incr {
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+        incr_by: f64,
+    },
+    Del {
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+    },
+}
+
+#[derive(Debug)]
+pub enum TransactionResult {
+    /// The result of each operation, for deletes the entry is the deleted one without a previous
+    /// entry, None for deletes of keys that didn't exist
+    Committed(Vec<Option<EntryWrite>>),
+    /// The condition of the set at this index failed, nothing was applied
+    ConditionFailed(usize),
+}
+
 pub enum SetCondition {
     IfNotExists,
     IfExists,
 }
 
+#[derive(Clone, Copy)]
 pub enum SortedOrder {
     Ascending,
     Descending,
 }
 
+impl SortedOrder {
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SortedNeighbors {
+    /// The 1-based rank of the entry
+    pub rank: u64,
+    /// Entries ranked above the entry, in sorted order
+    pub before: Vec<Entry>,
+    pub entry: Entry,
+    /// Entries ranked below the entry, in sorted order
+    pub after: Vec<Entry>,
+}
+
+/// Restricts a listing to the keys within the range, start is inclusive and end is exclusive.
+///
+/// Keys are compared byte-wise, so ranges are not affected by the database collation.
+#[derive(Debug, Clone, Default)]
+pub struct KeyRange {
+    pub start: Option<String>,
+    pub end: Option<String>,
+}
+
+impl KeyRange {
+    /// Range holding all the keys starting with the prefix
+    pub fn prefix(prefix: String) -> Self {
+        Self {
+            end: prefix_range_end(&prefix),
+            start: Some(prefix),
+        }
+    }
+}
+
+// returns the smallest string that is greater than all strings starting with the prefix,
+// or None if there is no such string
+fn prefix_range_end(prefix: &str) -> Option<String> {
+    let mut chars = prefix.chars().collect::<Vec<_>>();
+    while let Some(last) = chars.pop() {
+        let next = match last as u32 + 1 {
+            // skip the surrogate range
+            0xD800 => Some('\u{E000}'),
+            v => char::from_u32(v),
+        };
+
+        if let Some(next) = next {
+            chars.push(next);
+            return Some(chars.into_iter().collect());
+        }
+    }
+
+    None
+}
+
+pub struct ListQuery {
+    pub key_pattern: String,
+    pub range: KeyRange,
+
+    /// Continue after this key, in the direction of the iteration
+    pub after: Option<String>,
+    pub reverse: bool,
+    pub limit: u32,
+}
+
+pub struct SortedQuery {
+    pub order: SortedOrder,
+    pub range: KeyRange,
+
+    /// Continue after this entry, in the direction of the iteration
+    pub after: Option<EntryCursor>,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+/// The position of an entry in a listing, encoded as an opaque string when handed out to scripts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryCursor {
+    #[serde(rename = "k")]
+    pub key: String,
+    #[serde(rename = "v")]
+    pub value_float: Option<f64>,
+    #[serde(rename = "u")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EntryCursor {
+    pub fn encode(&self) -> String {
+        let serialized = serde_json::to_vec(self).unwrap_or_default();
+        base64::encode_config(serialized, base64::URL_SAFE_NO_PAD)
+    }
+
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let decoded = base64::decode_config(cursor, base64::URL_SAFE_NO_PAD).ok()?;
+        serde_json::from_slice(&decoded).ok()
+    }
+}
+
 #[derive(Debug)]
 pub struct Entry {
     pub bucket: String,
@@ -123,10 +301,82 @@ pub struct Entry {
     pub plugin_id: Option<u64>,
     pub value: StoreValue,
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl Entry {
+    pub fn cursor(&self) -> EntryCursor {
+        EntryCursor {
+            key: self.key.clone(),
+            value_float: match self.value {
+                StoreValue::Float(v) => Some(v),
+                _ => None,
+            },
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// The entry after a write, along with what was there before it
+///
+/// The previous entry is read in the same statement as the write
+#[derive(Debug)]
+pub struct EntryWrite {
+    pub entry: Entry,
+    pub previous: Option<EntryMeta>,
+}
+
+/// Describes an entry without holding its value
+#[derive(Debug, Clone)]
+pub struct EntryMeta {
+    pub kind: StoreValueKind,
+    /// The value itself for float values
+    pub number: Option<f64>,
+    /// Size of the value in bytes, json values are measured serialized
+    pub size: u64,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<&Entry> for EntryMeta {
+    fn from(v: &Entry) -> Self {
+        let (kind, number, size) = match &v.value {
+            StoreValue::Json(json) => (
+                StoreValueKind::Json,
+                None,
+                serde_json::to_vec(json)
+                    .map(|s| s.len())
+                    .unwrap_or_default(),
+            ),
+            StoreValue::Float(f) => (StoreValueKind::Float, Some(*f), 8),
+            StoreValue::Bytes(b) => (StoreValueKind::Bytes, None, b.len()),
+        };
+
+        Self {
+            kind,
+            number,
+            size: size as u64,
+            expires_at: v.expires_at,
+        }
+    }
+}
+
+/// An entry removed by [`BucketStore::del_many_meta`], without its value
+#[derive(Debug, Clone)]
+pub struct DeletedEntry {
+    pub key: String,
+    pub meta: EntryMeta,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum StoreValueKind {
+    Json,
+    Float,
+    Bytes,
 }
 
 #[derive(Debug)]
 pub enum StoreValue {
     Json(serde_json::Value),
     Float(f64),
+    Bytes(Vec<u8>),
 }
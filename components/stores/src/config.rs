@@ -9,7 +9,7 @@ use common::{
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use twilight_model::id::{
-    marker::{ChannelMarker, GuildMarker, UserMarker},
+    marker::{ChannelMarker, GuildMarker, UserMarker, WebhookMarker},
     Id,
 };
 use uuid::Uuid;
@@ -179,6 +179,18 @@ pub trait ConfigStore: Send + Sync {
 
     async fn create_image(&self, create: CreateImage) -> ConfigStoreResult<Uuid>;
     async fn soft_delete_image(&self, id: Uuid) -> ConfigStoreResult<Uuid>;
+
+    async fn set_guild_webhook(&self, webhook: GuildWebhook) -> ConfigStoreResult<()>;
+    async fn get_guild_webhook(
+        &self,
+        guild_id: Id<GuildMarker>,
+        webhook_id: Id<WebhookMarker>,
+    ) -> ConfigStoreResult<Option<GuildWebhook>>;
+    async fn del_guild_webhook(
+        &self,
+        guild_id: Id<GuildMarker>,
+        webhook_id: Id<WebhookMarker>,
+    ) -> ConfigStoreResult<()>;
 }
 
 /// Struct you get back from the store
@@ -235,6 +247,8 @@ pub struct IntervalTimerContrib {
 pub struct GuildMetaConfig {
     pub guild_id: Id<GuildMarker>,
     pub error_channel_id: Option<Id<ChannelMarker>>,
+    /// Whether scripts are allowed to send direct messages to members of the guild
+    pub allow_direct_messages: bool,
 }
 
 impl GuildMetaConfig {
@@ -242,10 +256,20 @@ impl GuildMetaConfig {
         Self {
             guild_id,
             error_channel_id: None,
+            allow_direct_messages: false,
         }
     }
 }
 
+/// A webhook created by a guild's scripts, the token is kept here and never handed to the scripts
+#[derive(Debug, Clone)]
+pub struct GuildWebhook {
+    pub guild_id: Id<GuildMarker>,
+    pub webhook_id: Id<WebhookMarker>,
+    pub channel_id: Id<ChannelMarker>,
+    pub token: String,
+}
+
 /// A joined guild, we we store all guidls were connected to in the store
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JoinedGuild {
@@ -307,6 +331,22 @@ impl PremiumSlotTier {
             (PremiumSlotTier::Premium, PremiumSlotTier::Lite)
         )
     }
+
+    /// Returns the highest tier out of the provided slots, none if there are no slots
+    pub fn highest(slots: &[PremiumSlot]) -> Option<PremiumSlotTier> {
+        let mut highest_tier = Option::<PremiumSlotTier>::None;
+        for slot in slots {
+            if let Some(current_highest) = highest_tier {
+                if slot.tier.is_higher_than(current_highest) {
+                    highest_tier = Some(slot.tier);
+                }
+            } else {
+                highest_tier = Some(slot.tier);
+            }
+        }
+
+        highest_tier
+    }
 }
 
 pub struct User {
@@ -1,7 +1,7 @@
 use crate::config::{
     ConfigStore, ConfigStoreError, ConfigStoreResult, CreateImage, CreatePlugin, CreateScript,
-    CreateUpdatePluginImage, CreateUpdatePremiumSlotBySource, GuildMetaConfig, JoinedGuild,
-    PremiumSlot, Script, ScriptContributes, UpdatePluginMeta, UpdateScript,
+    CreateUpdatePluginImage, CreateUpdatePremiumSlotBySource, GuildMetaConfig, GuildWebhook,
+    JoinedGuild, PremiumSlot, Script, ScriptContributes, UpdatePluginMeta, UpdateScript,
 };
 use async_trait::async_trait;
 use common::{
@@ -9,7 +9,7 @@ use common::{
     user::UserMeta,
 };
 use twilight_model::id::{
-    marker::{GuildMarker, UserMarker},
+    marker::{GuildMarker, UserMarker, WebhookMarker},
     Id,
 };
 use uuid::Uuid;
@@ -256,4 +256,24 @@ impl ConfigStore for ReadOnlyConfigStore {
     async fn delete_plugin_image(&self, _plugin_id: u64, _image_id: Uuid) -> ConfigStoreResult<()> {
         todo!()
     }
+
+    async fn set_guild_webhook(&self, _webhook: GuildWebhook) -> ConfigStoreResult<()> {
+        todo!()
+    }
+
+    async fn get_guild_webhook(
+        &self,
+        _guild_id: Id<GuildMarker>,
+        _webhook_id: Id<WebhookMarker>,
+    ) -> ConfigStoreResult<Option<GuildWebhook>> {
+        Ok(None)
+    }
+
+    async fn del_guild_webhook(
+        &self,
+        _guild_id: Id<GuildMarker>,
+        _webhook_id: Id<WebhookMarker>,
+    ) -> ConfigStoreResult<()> {
+        todo!()
+    }
 }
@@ -1,79 +1,76 @@
 use std::time::Duration;
 
-use crate::bucketstore::{Entry, SetCondition, SortedOrder, StoreError, StoreResult, StoreValue};
+use crate::bucketstore::{
+    DeletedEntry, Entry, EntryMeta, EntryWrite, KeyRange, ListQuery, SetCondition, SortedNeighbors,
+    SortedOrder, SortedQuery, StoreError, StoreResult, StoreValue, StoreValueKind, TransactionOp,
+    TransactionResult,
+};
 
 use super::Postgres;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use sqlx::PgConnection;
 use tracing::error;
 use twilight_model::id::{marker::GuildMarker, Id};
 
-#[async_trait]
-impl crate::bucketstore::BucketStore for Postgres {
-    async fn get(
-        &self,
-        guild_id: Id<GuildMarker>,
-        plugin_id: Option<u64>,
-        bucket: String,
-        key: String,
-    ) -> StoreResult<Option<Entry>> {
-        let res = sqlx::query_as!(
-            DbEntry,
-            "SELECT guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
-             value_json, value_float FROM bucket_store WHERE guild_id = $1 AND plugin_id = $2 AND \
-             bucket = $3 AND key = $4 AND (expires_at IS NULL OR expires_at > now());",
-            guild_id.get() as i64,
-            plugin_id.unwrap_or(0) as i64,
-            bucket,
-            key,
-        )
-        .fetch_optional(&self.pool)
-        .await?;
-
-        Ok(res.map(Into::into))
-    }
-
-    async fn set(
-        &self,
+impl Postgres {
+    async fn inner_bucket_set(
+        conn: &mut PgConnection,
         guild_id: Id<GuildMarker>,
         plugin_id: Option<u64>,
         bucket: String,
         key: String,
         value: StoreValue,
         ttl: Option<Duration>,
-    ) -> StoreResult<Entry> {
+    ) -> StoreResult<EntryWrite> {
         let expires_at = ttl.and_then(|ttl| {
             chrono::Duration::from_std(ttl)
                 .map(|dur| Utc::now() + dur)
                 .ok()
         });
 
-        let (val_num, val_json) = match value {
-            StoreValue::Json(json) => (None, Some(json)),
-            StoreValue::Float(n) => (Some(n), None),
+        let (val_num, val_json, val_bytes) = match value {
+            StoreValue::Json(json) => (None, Some(json), None),
+            StoreValue::Float(n) => (Some(n), None, None),
+            StoreValue::Bytes(b) => (None, None, Some(b)),
         };
 
+        // the previous entry is read in the same statement so it matches what was overwritten
         let res = sqlx::query_as!(
-            DbEntry,
-            "INSERT INTO bucket_store
-                     (guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
-             value_json, value_float)
+            DbEntryWrite,
+            "WITH previous AS (
+                        SELECT value_json, value_float, octet_length(value_bytes) AS value_bytes_len,
+                        expires_at FROM bucket_store
+                        WHERE guild_id = $1 AND plugin_id = $2 AND bucket = $3 AND key = $4 AND
+                        (expires_at IS NULL OR expires_at > now())
+                     ), written AS (
+                     INSERT INTO bucket_store
+                     (guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at,
+                     value_json, value_float, value_bytes)
                      VALUES
-                     ($1,         $2,        $3,   $4,     now(),    now(),      $5,            \
-             $6,  $7)
+                     ($1, $2, $3, $4, now(), now(), $5, $6, $7, $8)
                      ON CONFLICT (guild_id, plugin_id, bucket, key) DO UPDATE SET
                      created_at = CASE
-                        WHEN bucket_store.expires_at IS NOT NULL AND bucket_store.expires_at < \
-             now()
+                        WHEN bucket_store.expires_at IS NOT NULL AND bucket_store.expires_at < now()
                         THEN now()
                         ELSE bucket_store.created_at
                         END,
                      updated_at = now(),
                      expires_at = excluded.expires_at,
                      value_json = excluded.value_json,
-                     value_float = excluded.value_float
-                     RETURNING guild_id, plugin_id, bucket, key, created_at, updated_at, \
-             expires_at, value_json, value_float;",
+                     value_float = excluded.value_float,
+                     value_bytes = excluded.value_bytes
+                     RETURNING guild_id, plugin_id, bucket, key, created_at, updated_at,
+                     expires_at, value_json, value_float, value_bytes
+                     )
+                     SELECT written.guild_id, written.plugin_id, written.bucket, written.key,
+                     written.created_at, written.updated_at, written.expires_at, written.value_json,
+                     written.value_float, written.value_bytes,
+                     previous.value_json AS previous_value_json,
+                     previous.value_float AS previous_value_float,
+                     previous.value_bytes_len AS previous_value_bytes_len,
+                     previous.expires_at AS previous_expires_at
+                     FROM written LEFT JOIN previous ON true;",
             guild_id.get() as i64,
             plugin_id.unwrap_or(0) as i64,
             bucket,
@@ -81,14 +78,17 @@ impl crate::bucketstore::BucketStore for Postgres {
             expires_at,
             val_json,
             val_num,
+            val_bytes,
         )
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *conn)
         .await?;
 
         Ok(res.into())
     }
-    async fn set_if(
-        &self,
+
+    #[allow(clippy::too_many_arguments)]
+    async fn inner_bucket_set_if(
+        conn: &mut PgConnection,
         guild_id: Id<GuildMarker>,
         plugin_id: Option<u64>,
         bucket: String,
@@ -96,31 +96,48 @@ impl crate::bucketstore::BucketStore for Postgres {
         value: StoreValue,
         ttl: Option<Duration>,
         cond: SetCondition,
-    ) -> StoreResult<Option<Entry>> {
+    ) -> StoreResult<Option<EntryWrite>> {
         let expires_at = ttl.and_then(|ttl| {
             chrono::Duration::from_std(ttl)
                 .map(|dur| Utc::now() + dur)
                 .ok()
         });
 
-        let (val_num, val_json) = match value {
-            StoreValue::Json(json) => (None, Some(json)),
-            StoreValue::Float(n) => (Some(n), None),
+        let (val_num, val_json, val_bytes) = match value {
+            StoreValue::Json(json) => (None, Some(json), None),
+            StoreValue::Float(n) => (Some(n), None, None),
+            StoreValue::Bytes(b) => (None, None, Some(b)),
         };
 
         let res = match cond {
             SetCondition::IfExists => {
                 sqlx::query_as!(
-                    DbEntry,
-                    "UPDATE bucket_store SET
+                    DbEntryWrite,
+                    "WITH previous AS (
+                        SELECT value_json, value_float, octet_length(value_bytes) AS value_bytes_len,
+                        expires_at FROM bucket_store
+                        WHERE guild_id = $1 AND plugin_id = $2 AND bucket = $3 AND key = $4 AND
+                        (expires_at IS NULL OR expires_at > now())
+                     ), written AS (
+                     UPDATE bucket_store SET
                      updated_at = now(),
                      expires_at = $5,
                      value_json = $6,
-                     value_float = $7
+                     value_float = $7,
+                     value_bytes = $8
                      WHERE guild_id = $1 AND plugin_id = $2 AND bucket = $3 AND key = $4 AND
                      (expires_at IS NULL OR expires_at > now())
-                     RETURNING guild_id, plugin_id, bucket, key, created_at, updated_at, \
-                     expires_at, value_json, value_float;",
+                     RETURNING guild_id, plugin_id, bucket, key, created_at, updated_at,
+                     expires_at, value_json, value_float, value_bytes
+                     )
+                     SELECT written.guild_id, written.plugin_id, written.bucket, written.key,
+                     written.created_at, written.updated_at, written.expires_at, written.value_json,
+                     written.value_float, written.value_bytes,
+                     previous.value_json AS previous_value_json,
+                     previous.value_float AS previous_value_float,
+                     previous.value_bytes_len AS previous_value_bytes_len,
+                     previous.expires_at AS previous_expires_at
+                     FROM written LEFT JOIN previous ON true;",
                     guild_id.get() as i64,
                     plugin_id.unwrap_or(0) as i64,
                     bucket,
@@ -128,27 +145,30 @@ impl crate::bucketstore::BucketStore for Postgres {
                     expires_at,
                     val_json,
                     val_num,
+                    val_bytes,
                 )
-                .fetch_optional(&self.pool)
-                .await
+                .fetch_optional(&mut *conn)
+                .await?
+                .map(Into::into)
             }
             SetCondition::IfNotExists => {
                 sqlx::query_as!(
                     DbEntry,
                     "INSERT INTO bucket_store
                     (guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
-                     value_json, value_float)
+                     value_json, value_float, value_bytes)
                     VALUES
-                    ($1, $2, $3, $4, now(), now(), $5, $6, $7)
+                    ($1, $2, $3, $4, now(), now(), $5, $6, $7, $8)
                     ON CONFLICT (guild_id, plugin_id, bucket, key) DO UPDATE SET
                     created_at = now(),
                     updated_at = now(),
                     expires_at = excluded.expires_at,
                     value_json = excluded.value_json,
-                    value_float = excluded.value_float WHERE
+                    value_float = excluded.value_float,
+                    value_bytes = excluded.value_bytes WHERE
                     (bucket_store.expires_at IS NOT NULL AND bucket_store.expires_at < now())
                     RETURNING guild_id, plugin_id, bucket, key, created_at, updated_at, \
-                     expires_at, value_json, value_float;",
+                     expires_at, value_json, value_float, value_bytes;",
                     guild_id.get() as i64,
                     plugin_id.unwrap_or(0) as i64,
                     bucket,
@@ -156,17 +176,23 @@ impl crate::bucketstore::BucketStore for Postgres {
                     expires_at,
                     val_json,
                     val_num,
+                    val_bytes,
                 )
-                .fetch_optional(&self.pool)
-                .await
+                .fetch_optional(&mut *conn)
+                .await?
+                // there's nothing live to overwrite if this succeeded
+                .map(|entry| EntryWrite {
+                    entry: entry.into(),
+                    previous: None,
+                })
             }
-        }?;
+        };
 
-        Ok(res.map(Into::into))
+        Ok(res)
     }
 
-    async fn del(
-        &self,
+    async fn inner_bucket_del(
+        conn: &mut PgConnection,
         guild_id: Id<GuildMarker>,
         plugin_id: Option<u64>,
         bucket: String,
@@ -176,18 +202,314 @@ impl crate::bucketstore::BucketStore for Postgres {
             DbEntry,
             "DELETE FROM bucket_store WHERE guild_id = $1 AND plugin_id = $2 AND bucket = $3 AND \
              key = $4 AND (expires_at IS NULL OR expires_at > now()) RETURNING guild_id, \
-             plugin_id, bucket, key, created_at, updated_at, expires_at, value_json, value_float;",
+             plugin_id, bucket, key, created_at, updated_at, expires_at, value_json, value_float, value_bytes;",
             guild_id.get() as i64,
             plugin_id.unwrap_or(0) as i64,
             bucket,
             key,
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *conn)
         .await?;
 
         Ok(res.map(Into::into))
     }
 
+    async fn inner_bucket_incr(
+        conn: &mut PgConnection,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+        incr_by: f64,
+    ) -> StoreResult<EntryWrite> {
+        let res = sqlx::query_as!(
+            DbEntryWrite,
+            "WITH previous AS (
+                        SELECT value_json, value_float, octet_length(value_bytes) AS value_bytes_len,
+                        expires_at FROM bucket_store
+                        WHERE guild_id = $1 AND plugin_id = $2 AND bucket = $3 AND key = $4 AND
+                        (expires_at IS NULL OR expires_at > now())
+                     ), written AS (
+                     INSERT INTO bucket_store
+                     (guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at,
+                     value_json, value_float, value_bytes)
+                     VALUES
+                     ($1, $2, $3, $4, now(), now(), null, null, $5, null)
+                     ON CONFLICT (guild_id, plugin_id, bucket, key) DO UPDATE SET
+                     created_at = CASE
+                        WHEN bucket_store.expires_at IS NOT NULL AND bucket_store.expires_at < now()
+                        THEN now()
+                        ELSE bucket_store.created_at
+                        END,
+                     updated_at = now(),
+                     expires_at = excluded.expires_at,
+                     value_json = excluded.value_json,
+                     value_bytes = excluded.value_bytes,
+                     value_float = CASE
+                        WHEN bucket_store.expires_at IS NOT NULL AND bucket_store.expires_at < now()
+                        THEN excluded.value_float
+                        ELSE excluded.value_float + bucket_store.value_float
+                        END
+                     RETURNING guild_id, plugin_id, bucket, key, created_at, updated_at,
+                     expires_at, value_json, value_float, value_bytes
+                     )
+                     SELECT written.guild_id, written.plugin_id, written.bucket, written.key,
+                     written.created_at, written.updated_at, written.expires_at, written.value_json,
+                     written.value_float, written.value_bytes,
+                     previous.value_json AS previous_value_json,
+                     previous.value_float AS previous_value_float,
+                     previous.value_bytes_len AS previous_value_bytes_len,
+                     previous.expires_at AS previous_expires_at
+                     FROM written LEFT JOIN previous ON true;",
+            guild_id.get() as i64,
+            plugin_id.unwrap_or(0) as i64,
+            bucket,
+            key,
+            incr_by,
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(res.into())
+    }
+
+    async fn inner_bucket_get(
+        conn: &mut PgConnection,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+    ) -> StoreResult<Option<Entry>> {
+        let res = sqlx::query_as!(
+            DbEntry,
+            "SELECT guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
+             value_json, value_float, value_bytes FROM bucket_store WHERE guild_id = $1 AND plugin_id = $2 AND \
+             bucket = $3 AND key = $4 AND (expires_at IS NULL OR expires_at > now());",
+            guild_id.get() as i64,
+            plugin_id.unwrap_or(0) as i64,
+            bucket,
+            key,
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        Ok(res.map(Into::into))
+    }
+
+    async fn inner_sorted_entries(
+        conn: &mut PgConnection,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        query: SortedQuery,
+    ) -> StoreResult<Vec<Entry>> {
+        let (after_value, after_updated_at, after_key) = match query.after {
+            Some(cursor) => (
+                cursor.value_float,
+                Some(cursor.updated_at),
+                Some(cursor.key),
+            ),
+            None => (None, None, None),
+        };
+
+        let res = match query.order {
+            SortedOrder::Ascending => {
+                sqlx::query_as!(
+                    DbEntry,
+                    "SELECT guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
+                     value_json, value_float, value_bytes FROM bucket_store WHERE guild_id = $1 AND plugin_id \
+                     = $2 AND bucket = $3 AND ($4::TEXT IS NULL OR key COLLATE \"C\" >= $4) AND \
+                     ($5::TEXT IS NULL OR key COLLATE \"C\" < $5) AND ($6::FLOAT8 IS NULL OR \
+                     (value_float, updated_at, key COLLATE \"C\") > ($6, $7, $8)) AND (expires_at \
+                     IS NULL OR expires_at > now()) ORDER BY value_float ASC, updated_at ASC, key \
+                     COLLATE \"C\" ASC LIMIT $9 OFFSET $10;",
+                    guild_id.get() as i64,
+                    plugin_id.unwrap_or(0) as i64,
+                    bucket,
+                    query.range.start,
+                    query.range.end,
+                    after_value,
+                    after_updated_at,
+                    after_key,
+                    query.limit as i64,
+                    query.offset as i64,
+                )
+                .fetch_all(&mut *conn)
+                .await
+            }
+            SortedOrder::Descending => {
+                sqlx::query_as!(
+                    DbEntry,
+                    "SELECT guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
+                     value_json, value_float, value_bytes FROM bucket_store WHERE guild_id = $1 AND plugin_id \
+                     = $2 AND bucket = $3 AND ($4::TEXT IS NULL OR key COLLATE \"C\" >= $4) AND \
+                     ($5::TEXT IS NULL OR key COLLATE \"C\" < $5) AND ($6::FLOAT8 IS NULL OR \
+                     (value_float, updated_at, key COLLATE \"C\") < ($6, $7, $8)) AND (expires_at \
+                     IS NULL OR expires_at > now()) ORDER BY value_float DESC, updated_at DESC, key \
+                     COLLATE \"C\" DESC LIMIT $9 OFFSET $10;",
+                    guild_id.get() as i64,
+                    plugin_id.unwrap_or(0) as i64,
+                    bucket,
+                    query.range.start,
+                    query.range.end,
+                    after_value,
+                    after_updated_at,
+                    after_key,
+                    query.limit as i64,
+                    query.offset as i64,
+                )
+                .fetch_all(&mut *conn)
+                .await
+            }
+        }?;
+
+        Ok(res.into_iter().map(Into::into).collect())
+    }
+
+    /// Ranks an already fetched entry, None if it doesn't hold a number
+    ///
+    /// This counts the entries ranked above it so the cost grows with the rank
+    async fn inner_sorted_rank(
+        conn: &mut PgConnection,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        entry: &Entry,
+        order: SortedOrder,
+    ) -> StoreResult<Option<u64>> {
+        let StoreValue::Float(value) = entry.value else {
+            return Ok(None);
+        };
+
+        // count the entries ranked above, this walks bucket_store_float_idx
+        let res = match order {
+            SortedOrder::Ascending => {
+                sqlx::query!(
+                    "SELECT count(*) FROM bucket_store WHERE guild_id = $1 AND plugin_id = $2 AND \
+                     bucket = $3 AND value_float IS NOT NULL AND (value_float, updated_at, key \
+                     COLLATE \"C\") < ($4, $5, $6) AND (expires_at IS NULL OR expires_at > now());",
+                    guild_id.get() as i64,
+                    plugin_id.unwrap_or(0) as i64,
+                    bucket,
+                    value,
+                    entry.updated_at,
+                    &entry.key,
+                )
+                .fetch_one(&mut *conn)
+                .await?
+                .count
+            }
+            SortedOrder::Descending => {
+                sqlx::query!(
+                    "SELECT count(*) FROM bucket_store WHERE guild_id = $1 AND plugin_id = $2 AND \
+                     bucket = $3 AND value_float IS NOT NULL AND (value_float, updated_at, key \
+                     COLLATE \"C\") > ($4, $5, $6) AND (expires_at IS NULL OR expires_at > now());",
+                    guild_id.get() as i64,
+                    plugin_id.unwrap_or(0) as i64,
+                    bucket,
+                    value,
+                    entry.updated_at,
+                    &entry.key,
+                )
+                .fetch_one(&mut *conn)
+                .await?
+                .count
+            }
+        };
+
+        Ok(Some(res.unwrap_or_default() as u64 + 1))
+    }
+
+    /// Starts a read only transaction where all queries see the same snapshot
+    async fn begin_snapshot(&self) -> StoreResult<sqlx::Transaction<'static, sqlx::Postgres>> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(&mut *tx)
+            .await?;
+
+        Ok(tx)
+    }
+}
+
+#[async_trait]
+impl crate::bucketstore::BucketStore for Postgres {
+    async fn get(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+    ) -> StoreResult<Option<Entry>> {
+        Self::inner_bucket_get(
+            &mut *self.pool.acquire().await?,
+            guild_id,
+            plugin_id,
+            bucket,
+            key,
+        )
+        .await
+    }
+
+    async fn set(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+        value: StoreValue,
+        ttl: Option<Duration>,
+    ) -> StoreResult<EntryWrite> {
+        Self::inner_bucket_set(
+            &mut *self.pool.acquire().await?,
+            guild_id,
+            plugin_id,
+            bucket,
+            key,
+            value,
+            ttl,
+        )
+        .await
+    }
+    async fn set_if(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+        value: StoreValue,
+        ttl: Option<Duration>,
+        cond: SetCondition,
+    ) -> StoreResult<Option<EntryWrite>> {
+        Self::inner_bucket_set_if(
+            &mut *self.pool.acquire().await?,
+            guild_id,
+            plugin_id,
+            bucket,
+            key,
+            value,
+            ttl,
+            cond,
+        )
+        .await
+    }
+
+    async fn del(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+    ) -> StoreResult<Option<Entry>> {
+        Self::inner_bucket_del(
+            &mut *self.pool.acquire().await?,
+            guild_id,
+            plugin_id,
+            bucket,
+            key,
+        )
+        .await
+    }
+
     async fn del_many(
         &self,
         guild_id: Id<GuildMarker>,
@@ -209,27 +531,24 @@ impl crate::bucketstore::BucketStore for Postgres {
         Ok(res.rows_affected())
     }
 
-    async fn get_many(
+    async fn del_many_meta(
         &self,
         guild_id: Id<GuildMarker>,
         plugin_id: Option<u64>,
         bucket: String,
         key_pattern: String,
-        after: String,
-        limit: u32,
-    ) -> StoreResult<Vec<Entry>> {
+    ) -> StoreResult<Vec<DeletedEntry>> {
+        // only the sizes of the values are returned, these can be up to 1MB each
         let res = sqlx::query_as!(
-            DbEntry,
-            "SELECT guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
-             value_json, value_float FROM bucket_store WHERE guild_id = $1 AND plugin_id = $2 AND \
-             bucket = $3 AND key ILIKE $4 AND key > $5 AND (expires_at IS NULL OR expires_at > \
-             now()) ORDER BY (guild_id, bucket, key) LIMIT $6;",
+            DbDeletedEntry,
+            "DELETE FROM bucket_store WHERE guild_id = $1 AND plugin_id = $2 AND bucket = $3 AND \
+             key ILIKE $4 AND (expires_at IS NULL OR expires_at > now()) RETURNING key, \
+             expires_at, value_float, octet_length(value_json::text) AS value_json_len, \
+             octet_length(value_bytes) AS value_bytes_len;",
             guild_id.get() as i64,
             plugin_id.unwrap_or(0) as i64,
             bucket,
             key_pattern,
-            after,
-            limit as i64,
         )
         .fetch_all(&self.pool)
         .await?;
@@ -237,6 +556,58 @@ impl crate::bucketstore::BucketStore for Postgres {
         Ok(res.into_iter().map(Into::into).collect())
     }
 
+    async fn get_many(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        query: ListQuery,
+    ) -> StoreResult<Vec<Entry>> {
+        let res = if query.reverse {
+            sqlx::query_as!(
+                DbEntry,
+                "SELECT guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
+                 value_json, value_float, value_bytes FROM bucket_store WHERE guild_id = $1 AND plugin_id = $2 \
+                 AND bucket = $3 AND key ILIKE $4 AND ($5::TEXT IS NULL OR key COLLATE \"C\" >= \
+                 $5) AND ($6::TEXT IS NULL OR key COLLATE \"C\" < $6) AND ($7::TEXT IS NULL OR key \
+                 COLLATE \"C\" < $7) AND (expires_at IS NULL OR expires_at > now()) ORDER BY key \
+                 COLLATE \"C\" DESC LIMIT $8;",
+                guild_id.get() as i64,
+                plugin_id.unwrap_or(0) as i64,
+                bucket,
+                query.key_pattern,
+                query.range.start,
+                query.range.end,
+                query.after,
+                query.limit as i64,
+            )
+            .fetch_all(&self.pool)
+            .await
+        } else {
+            sqlx::query_as!(
+                DbEntry,
+                "SELECT guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
+                 value_json, value_float, value_bytes FROM bucket_store WHERE guild_id = $1 AND plugin_id = $2 \
+                 AND bucket = $3 AND key ILIKE $4 AND ($5::TEXT IS NULL OR key COLLATE \"C\" >= \
+                 $5) AND ($6::TEXT IS NULL OR key COLLATE \"C\" < $6) AND ($7::TEXT IS NULL OR key \
+                 COLLATE \"C\" > $7) AND (expires_at IS NULL OR expires_at > now()) ORDER BY key \
+                 COLLATE \"C\" ASC LIMIT $8;",
+                guild_id.get() as i64,
+                plugin_id.unwrap_or(0) as i64,
+                bucket,
+                query.key_pattern,
+                query.range.start,
+                query.range.end,
+                query.after,
+                query.limit as i64,
+            )
+            .fetch_all(&self.pool)
+            .await
+        }?;
+
+        Ok(res.into_iter().map(Into::into).collect())
+    }
+
     async fn count(
         &self,
         guild_id: Id<GuildMarker>,
@@ -278,86 +649,201 @@ impl crate::bucketstore::BucketStore for Postgres {
         bucket: String,
         key: String,
         incr_by: f64,
-    ) -> StoreResult<Entry> {
-        let res = sqlx::query_as!(
-            DbEntry,
-            "INSERT INTO bucket_store
-         (guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, value_json, \
-             value_float)
-         VALUES
-         ($1, $2, $3, $4, now(), now(), null, null, $5)
-         ON CONFLICT (guild_id, plugin_id, bucket, key) DO UPDATE SET
-         created_at = CASE
-            WHEN bucket_store.expires_at IS NOT NULL AND bucket_store.expires_at < now()
-            THEN now()
-            ELSE bucket_store.created_at
-            END,
-         updated_at = now(),
-         expires_at = excluded.expires_at,
-         value_json = excluded.value_json,
-         value_float = CASE
-            WHEN bucket_store.expires_at IS NOT NULL AND bucket_store.expires_at < now()
-            THEN excluded.value_float
-            ELSE excluded.value_float + bucket_store.value_float
-            END
-         RETURNING guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
-             value_json, value_float;",
-            guild_id.get() as i64,
-            plugin_id.unwrap_or(0) as i64,
+    ) -> StoreResult<EntryWrite> {
+        Self::inner_bucket_incr(
+            &mut *self.pool.acquire().await?,
+            guild_id,
+            plugin_id,
             bucket,
             key,
             incr_by,
         )
-        .fetch_one(&self.pool)
-        .await?;
-
-        Ok(res.into())
+        .await
     }
     async fn sorted_entries(
         &self,
         guild_id: Id<GuildMarker>,
         plugin_id: Option<u64>,
         bucket: String,
-        order: SortedOrder,
-        offset: u32,
-        limit: u32,
+        query: SortedQuery,
     ) -> StoreResult<Vec<Entry>> {
-        let res = match order {
-            SortedOrder::Ascending => {
-                sqlx::query_as!(
-                    DbEntry,
-                    "SELECT guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
-                     value_json, value_float FROM bucket_store WHERE guild_id = $1 AND plugin_id \
-                     = $2 AND bucket = $3 AND (expires_at IS NULL OR expires_at > now()) ORDER BY \
-                     value_float ASC, updated_at ASC LIMIT $4 OFFSET $5;",
-                    guild_id.get() as i64,
-                    plugin_id.unwrap_or(0) as i64,
+        Self::inner_sorted_entries(
+            &mut *self.pool.acquire().await?,
+            guild_id,
+            plugin_id,
+            bucket,
+            query,
+        )
+        .await
+    }
+
+    async fn sorted_rank(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+        order: SortedOrder,
+    ) -> StoreResult<Option<u64>> {
+        let mut tx = self.begin_snapshot().await?;
+
+        let Some(entry) =
+            Self::inner_bucket_get(&mut tx, guild_id, plugin_id, bucket.clone(), key).await?
+        else {
+            return Ok(None);
+        };
+
+        let rank =
+            Self::inner_sorted_rank(&mut tx, guild_id, plugin_id, bucket, &entry, order).await?;
+
+        tx.commit().await?;
+        Ok(rank)
+    }
+
+    async fn sorted_neighbors(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin_id: Option<u64>,
+        bucket: String,
+        key: String,
+        order: SortedOrder,
+        before: u32,
+        after: u32,
+    ) -> StoreResult<Option<SortedNeighbors>> {
+        // all the queries run on the same snapshot so the rank matches the neighbors
+        let mut tx = self.begin_snapshot().await?;
+
+        let Some(entry) =
+            Self::inner_bucket_get(&mut tx, guild_id, plugin_id, bucket.clone(), key).await?
+        else {
+            return Ok(None);
+        };
+
+        let Some(rank) =
+            Self::inner_sorted_rank(&mut tx, guild_id, plugin_id, bucket.clone(), &entry, order)
+                .await?
+        else {
+            return Ok(None);
+        };
+
+        // walk the index in both directions starting at the entry
+        let mut entries_before = if before > 0 {
+            Self::inner_sorted_entries(
+                &mut tx,
+                guild_id,
+                plugin_id,
+                bucket.clone(),
+                SortedQuery {
+                    order: order.reverse(),
+                    range: KeyRange::default(),
+                    after: Some(entry.cursor()),
+                    offset: 0,
+                    limit: before,
+                },
+            )
+            .await?
+        } else {
+            Vec::new()
+        };
+        entries_before.reverse();
+
+        let entries_after = if after > 0 {
+            Self::inner_sorted_entries(
+                &mut tx,
+                guild_id,
+                plugin_id,
+                bucket,
+                SortedQuery {
+                    order,
+                    range: KeyRange::default(),
+                    after: Some(entry.cursor()),
+                    offset: 0,
+                    limit: after,
+                },
+            )
+            .await?
+        } else {
+            Vec::new()
+        };
+
+        tx.commit().await?;
+
+        Ok(Some(SortedNeighbors {
+            rank,
+            before: entries_before,
+            entry,
+            after: entries_after,
+        }))
+    }
+
+    async fn transaction(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ops: Vec<TransactionOp>,
+    ) -> StoreResult<TransactionResult> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut results = Vec::with_capacity(ops.len());
+        for (i, op) in ops.into_iter().enumerate() {
+            let res = match op {
+                TransactionOp::Set {
+                    plugin_id,
                     bucket,
-                    limit as i64,
-                    offset as i64,
-                )
-                .fetch_all(&self.pool)
-                .await
-            }
-            SortedOrder::Descending => {
-                sqlx::query_as!(
-                    DbEntry,
-                    "SELECT guild_id, plugin_id, bucket, key, created_at, updated_at, expires_at, \
-                     value_json, value_float FROM bucket_store WHERE guild_id = $1 AND plugin_id \
-                     = $2 AND bucket = $3 AND (expires_at IS NULL OR expires_at > now()) ORDER BY \
-                     value_float DESC, updated_at DESC LIMIT $4 OFFSET $5;",
-                    guild_id.get() as i64,
-                    plugin_id.unwrap_or(0) as i64,
+                    key,
+                    value,
+                    ttl,
+                    cond: None,
+                } => Some(
+                    Self::inner_bucket_set(&mut tx, guild_id, plugin_id, bucket, key, value, ttl)
+                        .await?,
+                ),
+                TransactionOp::Set {
+                    plugin_id,
                     bucket,
-                    limit as i64,
-                    offset as i64,
-                )
-                .fetch_all(&self.pool)
-                .await
-            }
-        }?;
+                    key,
+                    value,
+                    ttl,
+                    cond: Some(cond),
+                } => {
+                    let res = Self::inner_bucket_set_if(
+                        &mut tx, guild_id, plugin_id, bucket, key, value, ttl, cond,
+                    )
+                    .await?;
 
-        Ok(res.into_iter().map(Into::into).collect())
+                    if res.is_none() {
+                        // dropping the transaction rolls it back
+                        return Ok(TransactionResult::ConditionFailed(i));
+                    }
+
+                    res
+                }
+                TransactionOp::Incr {
+                    plugin_id,
+                    bucket,
+                    key,
+                    incr_by,
+                } => Some(
+                    Self::inner_bucket_incr(&mut tx, guild_id, plugin_id, bucket, key, incr_by)
+                        .await?,
+                ),
+                TransactionOp::Del {
+                    plugin_id,
+                    bucket,
+                    key,
+                } => Self::inner_bucket_del(&mut tx, guild_id, plugin_id, bucket, key)
+                    .await?
+                    .map(|entry| EntryWrite {
+                        entry,
+                        previous: None,
+                    }),
+            };
+
+            results.push(res);
+        }
+
+        tx.commit().await?;
+
+        Ok(TransactionResult::Committed(results))
     }
 
     async fn delete_guild_bucket_store_data(&self, id: Id<GuildMarker>) -> StoreResult<()> {
@@ -383,6 +869,7 @@ pub struct DbEntry {
     expires_at: Option<DateTime<Utc>>,
     value_json: Option<serde_json::Value>,
     value_float: Option<f64>,
+    value_bytes: Option<Vec<u8>>,
 }
 
 impl From<DbEntry> for Entry {
@@ -392,18 +879,113 @@ impl From<DbEntry> for Entry {
             plugin_id: (v.plugin_id > 0).then_some(v.plugin_id as u64),
             key: v.key,
             expires_at: v.expires_at,
+            updated_at: v.updated_at,
             value: if let Some(fv) = v.value_float {
                 StoreValue::Float(fv)
             } else if let Some(sv) = v.value_json {
                 StoreValue::Json(sv)
+            } else if let Some(bv) = v.value_bytes {
+                StoreValue::Bytes(bv)
             } else {
-                error!("got neither float nor json value from db");
+                error!("got neither float, json nor bytes value from db");
                 StoreValue::Json(serde_json::Value::Null)
             },
         }
     }
 }
 
+/// A [`DbEntry`] after a write along with the entry it replaced, the previous columns are
+/// all null if there was no entry before
+#[allow(dead_code)]
+pub struct DbEntryWrite {
+    guild_id: i64,
+    plugin_id: i64,
+    bucket: String,
+    key: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
+    value_json: Option<serde_json::Value>,
+    value_float: Option<f64>,
+    value_bytes: Option<Vec<u8>>,
+    previous_value_json: Option<serde_json::Value>,
+    previous_value_float: Option<f64>,
+    previous_value_bytes_len: Option<i32>,
+    previous_expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<DbEntryWrite> for EntryWrite {
+    fn from(v: DbEntryWrite) -> Self {
+        let previous_kind = if let Some(fv) = v.previous_value_float {
+            Some((StoreValueKind::Float, Some(fv), 8))
+        } else if let Some(sv) = &v.previous_value_json {
+            let size = serde_json::to_vec(sv).map(|s| s.len()).unwrap_or_default();
+            Some((StoreValueKind::Json, None, size as u64))
+        } else {
+            v.previous_value_bytes_len
+                .map(|len| (StoreValueKind::Bytes, None, len as u64))
+        };
+
+        Self {
+            previous: previous_kind.map(|(kind, number, size)| EntryMeta {
+                kind,
+                number,
+                size,
+                expires_at: v.previous_expires_at,
+            }),
+            entry: DbEntry {
+                guild_id: v.guild_id,
+                plugin_id: v.plugin_id,
+                bucket: v.bucket,
+                key: v.key,
+                created_at: v.created_at,
+                updated_at: v.updated_at,
+                expires_at: v.expires_at,
+                value_json: v.value_json,
+                value_float: v.value_float,
+                value_bytes: v.value_bytes,
+            }
+            .into(),
+        }
+    }
+}
+
+pub struct DbDeletedEntry {
+    key: String,
+    expires_at: Option<DateTime<Utc>>,
+    value_float: Option<f64>,
+    value_json_len: Option<i32>,
+    value_bytes_len: Option<i32>,
+}
+
+impl From<DbDeletedEntry> for DeletedEntry {
+    fn from(v: DbDeletedEntry) -> Self {
+        // json sizes are measured on postgres' text representation here, which can differ
+        // slightly from the compact serialization used elsewhere
+        let (kind, number, size) = if let Some(fv) = v.value_float {
+            (StoreValueKind::Float, Some(fv), 8)
+        } else if let Some(len) = v.value_json_len {
+            (StoreValueKind::Json, None, len as u64)
+        } else {
+            (
+                StoreValueKind::Bytes,
+                None,
+                v.value_bytes_len.unwrap_or_default() as u64,
+            )
+        };
+
+        Self {
+            key: v.key,
+            meta: EntryMeta {
+                kind,
+                number,
+                size,
+                expires_at: v.expires_at,
+            },
+        }
+    }
+}
+
 impl From<sqlx::Error> for StoreError {
     fn from(err: sqlx::Error) -> Self {
         Self::Other(Box::new(err))
@@ -7,16 +7,16 @@ use common::{
 };
 use sqlx::{postgres::types::PgInterval, PgConnection};
 use twilight_model::id::{
-    marker::{GuildMarker, UserMarker},
+    marker::{GuildMarker, UserMarker, WebhookMarker},
     Id,
 };
 use uuid::Uuid;
 
 use crate::config::{
     ConfigStoreError, ConfigStoreResult, CreateImage, CreatePlugin, CreateScript,
-    CreateUpdatePluginImage, CreateUpdatePremiumSlotBySource, GuildMetaConfig, JoinedGuild,
-    PremiumSlot, PremiumSlotState, PremiumSlotTier, Script, ScriptContributes, UpdatePluginMeta,
-    UpdateScript,
+    CreateUpdatePluginImage, CreateUpdatePremiumSlotBySource, GuildMetaConfig, GuildWebhook,
+    JoinedGuild, PremiumSlot, PremiumSlotState, PremiumSlotTier, Script, ScriptContributes,
+    UpdatePluginMeta, UpdateScript,
 };
 
 const GUILD_SCRIPT_COUNT_LIMIT: i64 = 100;
@@ -329,7 +329,7 @@ impl crate::config::ConfigStore for Postgres {
     ) -> ConfigStoreResult<Option<GuildMetaConfig>> {
         match sqlx::query_as!(
             DbGuildMetaConfig,
-            "SELECT guild_id, error_channel_id FROM guild_meta_configs
+            "SELECT guild_id, error_channel_id, allow_direct_messages FROM guild_meta_configs
         WHERE guild_id = $1;",
             guild_id.get() as i64,
         )
@@ -348,14 +348,17 @@ impl crate::config::ConfigStore for Postgres {
     ) -> ConfigStoreResult<GuildMetaConfig> {
         let db_conf = sqlx::query_as!(
             DbGuildMetaConfig,
-            "INSERT INTO guild_meta_configs (guild_id, error_channel_id) VALUES ($1, $2)
+            "INSERT INTO guild_meta_configs (guild_id, error_channel_id, allow_direct_messages) \
+             VALUES ($1, $2, $3)
             ON CONFLICT (guild_id) DO UPDATE SET
-            error_channel_id = $2
-            RETURNING guild_id, error_channel_id;",
+            error_channel_id = $2,
+            allow_direct_messages = $3
+            RETURNING guild_id, error_channel_id, allow_direct_messages;",
             conf.guild_id.get() as i64,
             conf.error_channel_id
                 .map(|e| e.get() as i64)
                 .unwrap_or_default(),
+            conf.allow_direct_messages,
         )
         .fetch_one(&self.pool)
         .await?;
@@ -471,6 +474,13 @@ impl crate::config::ConfigStore for Postgres {
             .execute(&self.pool)
             .await?;
 
+        sqlx::query!(
+            "DELETE FROM guild_webhooks WHERE guild_id = $1;",
+            id.get() as i64
+        )
+        .execute(&self.pool)
+        .await?;
+
         // TODO: should we delete guild scripts as well?
 
         Ok(())
@@ -1028,6 +1038,58 @@ ORDER BY id ASC"#,
     async fn soft_delete_image(&self, _id: Uuid) -> ConfigStoreResult<Uuid> {
         todo!()
     }
+
+    async fn set_guild_webhook(&self, webhook: GuildWebhook) -> ConfigStoreResult<()> {
+        sqlx::query!(
+            "INSERT INTO guild_webhooks (guild_id, webhook_id, channel_id, token) VALUES ($1, $2, \
+             $3, $4)
+            ON CONFLICT (guild_id, webhook_id) DO UPDATE SET
+            channel_id = excluded.channel_id,
+            token = excluded.token;",
+            webhook.guild_id.get() as i64,
+            webhook.webhook_id.get() as i64,
+            webhook.channel_id.get() as i64,
+            webhook.token,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_guild_webhook(
+        &self,
+        guild_id: Id<GuildMarker>,
+        webhook_id: Id<WebhookMarker>,
+    ) -> ConfigStoreResult<Option<GuildWebhook>> {
+        let res = sqlx::query_as!(
+            DbGuildWebhook,
+            "SELECT guild_id, webhook_id, channel_id, token FROM guild_webhooks WHERE guild_id = \
+             $1 AND webhook_id = $2;",
+            guild_id.get() as i64,
+            webhook_id.get() as i64,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(res.map(Into::into))
+    }
+
+    async fn del_guild_webhook(
+        &self,
+        guild_id: Id<GuildMarker>,
+        webhook_id: Id<WebhookMarker>,
+    ) -> ConfigStoreResult<()> {
+        sqlx::query!(
+            "DELETE FROM guild_webhooks WHERE guild_id = $1 AND webhook_id = $2;",
+            guild_id.get() as i64,
+            webhook_id.get() as i64,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
 
 #[allow(dead_code)]
@@ -1069,6 +1131,7 @@ impl From<DbScript> for Script {
 struct DbGuildMetaConfig {
     pub guild_id: i64,
     pub error_channel_id: i64,
+    pub allow_direct_messages: bool,
 }
 
 impl From<DbGuildMetaConfig> for GuildMetaConfig {
@@ -1080,6 +1143,25 @@ impl From<DbGuildMetaConfig> for GuildMetaConfig {
             } else {
                 None
             },
+            allow_direct_messages: mc.allow_direct_messages,
+        }
+    }
+}
+
+struct DbGuildWebhook {
+    guild_id: i64,
+    webhook_id: i64,
+    channel_id: i64,
+    token: String,
+}
+
+impl From<DbGuildWebhook> for GuildWebhook {
+    fn from(v: DbGuildWebhook) -> Self {
+        Self {
+            guild_id: Id::new(v.guild_id as u64),
+            webhook_id: Id::new(v.webhook_id as u64),
+            channel_id: Id::new(v.channel_id as u64),
+            token: v.token,
         }
     }
 }
@@ -1,8 +1,13 @@
+use std::collections::HashMap;
+
 use lazy_static::lazy_static;
 use regex::Regex;
 use runtime_models::internal::{
     interaction::CommandType,
-    script::{Command, CommandGroup, CommandOption, CommandSubGroup},
+    script::{
+        Command, CommandGroup, CommandOption, CommandOptionChoice, CommandOptionType,
+        CommandSubGroup,
+    },
 };
 
 use crate::{ValidationContext, Validator};
@@ -20,6 +25,26 @@ impl Validator for Command {
             );
         }
 
+        if let Some(localizations) = &self.name_localizations {
+            check_localizations(ctx, "name_localizations", localizations, check_name_field);
+        }
+
+        if let Some(localizations) = &self.description_localizations {
+            if matches!(self.kind, CommandType::Chat) {
+                check_localizations(
+                    ctx,
+                    "description_localizations",
+                    localizations,
+                    check_description_field,
+                );
+            } else if !localizations.is_empty() {
+                ctx.push_error(
+                    "description_localizations",
+                    "has to be empty for user and message commands".to_string(),
+                );
+            }
+        }
+
         if let Some(group) = &self.group {
             check_name_field(ctx, "group", group);
         }
@@ -28,6 +53,25 @@ impl Validator for Command {
             check_name_field(ctx, "sub_group", group);
         }
 
+        if let Some(permissions) = &self.default_member_permissions {
+            check_permissions_field(ctx, "default_member_permissions", permissions);
+
+            if self.group.is_some() {
+                ctx.push_error(
+                    "default_member_permissions",
+                    "can only be set on top level commands, set it on the group instead"
+                        .to_string(),
+                );
+            }
+        }
+
+        if self.nsfw.is_some() && self.group.is_some() {
+            ctx.push_error(
+                "nsfw",
+                "can only be set on top level commands, set it on the group instead".to_string(),
+            );
+        }
+
         if self.options.len() > 25 {
             ctx.push_error("options", "max 25 options".to_string());
         }
@@ -51,6 +95,60 @@ impl Validator for CommandOption {
     fn validate(&self, ctx: &mut ValidationContext) {
         check_name_field(ctx, "name", &self.name);
         check_description_field(ctx, "description", &self.description);
+
+        if let Some(localizations) = &self.name_localizations {
+            check_localizations(ctx, "name_localizations", localizations, check_name_field);
+        }
+
+        if let Some(localizations) = &self.description_localizations {
+            check_localizations(
+                ctx,
+                "description_localizations",
+                localizations,
+                check_description_field,
+            );
+        }
+
+        for choice in self.extra_options.choices.iter().flatten() {
+            ctx.push_field("choices".to_string());
+            choice.validate(ctx);
+            ctx.pop_field();
+        }
+
+        if self.extra_options.autocomplete == Some(true) {
+            if !matches!(
+                self.kind,
+                CommandOptionType::String | CommandOptionType::Integer | CommandOptionType::Number
+            ) {
+                ctx.push_error(
+                    "autocomplete",
+                    "only supported on string, integer and number options".to_string(),
+                );
+            }
+
+            if self
+                .extra_options
+                .choices
+                .as_ref()
+                .is_some_and(|choices| !choices.is_empty())
+            {
+                ctx.push_error("autocomplete", "can't be combined with choices".to_string());
+            }
+        }
+    }
+}
+
+impl Validator for CommandOptionChoice {
+    fn validate(&self, ctx: &mut ValidationContext) {
+        // choice names follow the same length rules as descriptions
+        if let Some(localizations) = &self.name_localizations {
+            check_localizations(
+                ctx,
+                "name_localizations",
+                localizations,
+                check_description_field,
+            );
+        }
     }
 }
 
@@ -59,6 +157,10 @@ impl Validator for CommandGroup {
         check_name_field(ctx, "name", &self.name);
         check_description_field(ctx, "description", &self.description);
 
+        if let Some(permissions) = &self.default_member_permissions {
+            check_permissions_field(ctx, "default_member_permissions", permissions);
+        }
+
         for sub_group in &self.sub_groups {
             ctx.push_field("sub_groups".to_string());
             sub_group.validate(ctx);
@@ -103,3 +205,115 @@ fn check_description_field(ctx: &mut ValidationContext, field: &str, value: &str
         ctx.push_error(field, "can be max 100 characters long".to_string());
     }
 }
+
+fn check_permissions_field(ctx: &mut ValidationContext, field: &str, value: &str) {
+    if value.parse::<u64>().is_err() {
+        ctx.push_error(field, "has to be a valid permissions bitfield".to_string());
+    }
+}
+
+// https://discord.com/developers/docs/reference#locales
+const DISCORD_LOCALES: &[&str] = &[
+    "id", "da", "de", "en-GB", "en-US", "es-ES", "es-419", "fr", "hr", "it", "lt", "hu", "nl",
+    "no", "pl", "pt-BR", "ro", "fi", "sv-SE", "vi", "tr", "cs", "el", "bg", "ru", "uk", "hi", "th",
+    "zh-CN", "ja", "zh-TW", "ko",
+];
+
+fn check_localizations(
+    ctx: &mut ValidationContext,
+    field: &str,
+    localizations: &HashMap<String, String>,
+    check_value: fn(&mut ValidationContext, &str, &str),
+) {
+    for (locale, value) in localizations {
+        if !DISCORD_LOCALES.contains(&locale.as_str()) {
+            ctx.push_error(field, format!("unknown locale `{locale}`"));
+        }
+
+        check_value(ctx, field, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use runtime_models::internal::script::{
+        CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType,
+        ExtraCommandOptions,
+    };
+
+    fn option(kind: CommandOptionType, extra_options: ExtraCommandOptions) -> CommandOption {
+        CommandOption {
+            name: "what".to_string(),
+            description: "what to game".to_string(),
+            kind,
+            required: false,
+            extra_options,
+            name_localizations: None,
+            description_localizations: None,
+        }
+    }
+
+    fn autocomplete(choices: Option<Vec<CommandOptionChoice>>) -> ExtraCommandOptions {
+        ExtraCommandOptions {
+            min_value: None,
+            max_value: None,
+            channel_types: None,
+            choices,
+            autocomplete: Some(true),
+        }
+    }
+
+    fn error_fields(option: &CommandOption) -> Vec<String> {
+        match crate::validate(option) {
+            Ok(()) => Vec::new(),
+            Err(errs) => errs.into_iter().map(|e| e.field).collect(),
+        }
+    }
+
+    #[test]
+    fn test_autocomplete_option_types() {
+        for kind in [
+            CommandOptionType::String,
+            CommandOptionType::Integer,
+            CommandOptionType::Number,
+        ] {
+            assert!(error_fields(&option(kind, autocomplete(None))).is_empty());
+        }
+
+        for kind in [
+            CommandOptionType::Boolean,
+            CommandOptionType::User,
+            CommandOptionType::Channel,
+            CommandOptionType::Role,
+            CommandOptionType::Mentionable,
+        ] {
+            assert_eq!(
+                error_fields(&option(kind, autocomplete(None))),
+                vec!["autocomplete".to_string()]
+            );
+        }
+    }
+
+    #[test]
+    fn test_autocomplete_with_choices() {
+        let choices = vec![CommandOptionChoice {
+            name: "chess".to_string(),
+            value: CommandOptionChoiceValue::String("chess".to_string()),
+            name_localizations: None,
+        }];
+
+        assert_eq!(
+            error_fields(&option(
+                CommandOptionType::String,
+                autocomplete(Some(choices))
+            )),
+            vec!["autocomplete".to_string()]
+        );
+
+        assert!(error_fields(&option(
+            CommandOptionType::String,
+            autocomplete(Some(vec![]))
+        ))
+        .is_empty());
+    }
+}
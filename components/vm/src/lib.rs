@@ -1,6 +1,6 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, rc::Rc, time::Instant};
 
-use deno_core::{v8_set_flags, JsRuntime, SourceMapGetter};
+use deno_core::{v8_set_flags, CancelHandle, JsRuntime, SourceMapGetter};
 use stores::config::Script;
 use tscompiler::CompiledItem;
 use url::Url;
@@ -206,6 +206,18 @@ fn script_url(script: &Script, suffix: &str) -> Url {
     Url::parse(&format!("file:///guild_scripts/{}.{suffix}", script.name)).unwrap()
 }
 
+/// Cancelled right before the vm is shut down or restarted.
+///
+/// Long running ops (such as timers) should race against this so they don't hold up the shutdown.
+#[derive(Clone, Default)]
+pub struct VmShutdownCancelHandle(pub Rc<CancelHandle>);
+
+/// When the vm started handling the last event, or when it was created if it hasn't handled any yet.
+///
+/// Ops that keep the vm alive on their own (such as timers) are bounded relative to this.
+#[derive(Clone, Copy)]
+pub struct VmLastEventAt(pub Instant);
+
 pub struct BlCoreOptions {
     cloned_load_states: Rc<RefCell<ScriptsStateStore>>,
 }
@@ -217,6 +229,8 @@ deno_core::extension!(bl_core,
   },
   state = |state, options| {
     state.put::<Rc<RefCell<ScriptsStateStore>>>(options.options.cloned_load_states);
+    state.put(VmShutdownCancelHandle::default());
+    state.put(VmLastEventAt(Instant::now()));
   },
 );
 
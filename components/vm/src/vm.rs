@@ -440,6 +440,10 @@ impl Vm {
         };
 
         let mut rt = self.isolate_cell.enter_isolate(&mut self.runtime);
+        rt.op_state()
+            .borrow_mut()
+            .put(crate::VmLastEventAt(std::time::Instant::now()));
+
         let global_ctx = rt.main_context();
         let ctx = global_ctx.open(rt.v8_isolate());
 
@@ -509,8 +513,21 @@ impl Vm {
         // iso.low_memory_notification();
     }
 
+    // cancels long running ops such as timers so they don't keep the vm alive while shutting down
+    fn cancel_long_running_ops(&mut self) {
+        let mut rt = self.isolate_cell.enter_isolate(&mut self.runtime);
+        let op_state = rt.op_state();
+        let handle = op_state
+            .borrow()
+            .borrow::<crate::VmShutdownCancelHandle>()
+            .clone();
+        handle.0.cancel();
+    }
+
     #[instrument(skip(self))]
     async fn stop_vm(&mut self) {
+        self.cancel_long_running_ops();
+
         // complete the event loop and extract our core data (script event receiver)
         // TODO: we could potentially have some long running futures
         // so maybe call a function that cancels all long running futures or something?
@@ -1,4 +1,4 @@
-import { GuildMetaConfig } from ".";
+import { GuildMetaConfig, UpdateGuildMetaConfig } from ".";
 import { CreateScript, CurrentGuildsResponse, EmptyResponse, LoginResponse, Plugin, Script, ScriptPlugin, ScriptsWithPlugins, SessionMeta, UpdateScript, User } from "./api_models";
 
 export type Body = {
@@ -164,6 +164,13 @@ export class ApiClient {
         return await this.get(`/api/guilds/${guildId}/settings`);
     }
 
+    async updateGuildMetaConfig(guildId: string, data: UpdateGuildMetaConfig): Promise<ApiResult<GuildMetaConfig>> {
+        return await this.patch(`/api/guilds/${guildId}/settings`, {
+            kind: "json",
+            body: data
+        });
+    }
+
     async getNews(): Promise<ApiResult<NewsItem[]>> {
         return await this.get(`/api/news`);
     }
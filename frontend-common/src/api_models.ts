@@ -74,6 +74,11 @@ export interface EmptyResponse { }
 export interface GuildMetaConfig {
     guild_id: string,
     error_channel_id: string | null,
+    allow_direct_messages: boolean,
+}
+
+export interface UpdateGuildMetaConfig {
+    allow_direct_messages?: boolean,
 }
 
 export interface Plugin<Variant = ScriptPluginData> {
@@ -118,8 +118,33 @@ function GuildSettings(props: { guild: BotGuild }) {
 }
 
 function InnerGuildSettings(props: { guild: BotGuild, settings: GuildMetaConfig }) {
+    const [settings, setSettings] = useState(props.settings);
+    const [isSaving, setSaving] = useState(false);
+    const session = useSession();
+    const notifications = UseNotifications();
+
+    async function toggleDirectMessages(on: boolean) {
+        setSaving(true);
+        try {
+            const resp = await session.apiClient.updateGuildMetaConfig(props.guild.guild.id, { allow_direct_messages: on });
+            if (isErrorResponse(resp)) {
+                notifications.push({ class: "error", message: "failed updating settings: " + resp.response?.description });
+            } else {
+                setSettings(resp);
+            }
+        } finally {
+            setSaving(false);
+        }
+    }
+
     return <Panel>
-        <p>Error channel: <code>{props.settings.error_channel_id || "not set"}</code></p>
+        <p>Error channel: <code>{settings.error_channel_id || "not set"}</code></p>
+        <Stack direction={"row"} alignItems={"center"}>
+            <Switch checked={settings.allow_direct_messages} disabled={isSaving} onChange={(evt) => {
+                toggleDirectMessages(evt.target.checked)
+            }} />
+            <Typography>Allow scripts to send direct messages to members</Typography>
+        </Stack>
     </Panel>
 }
 
@@ -0,0 +1,27 @@
+import { Discord, encodeText } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+const channelId = "531120790318350338";
+
+runOnce("attachments.ts", async () => {
+    const data = encodeText("id,name\n1,botloader\n")
+    const message = await Discord.createMessage(channelId, {
+        content: "attachments test",
+        attachments: [{ filename: "test.csv", description: "test file", data }],
+    })
+
+    assertExpected(1, message.attachments.length)
+    assertExpected("test.csv", message.attachments[0].filename)
+    assertExpected(data.length, message.attachments[0].size)
+
+    const edited = await Discord.editMessage(channelId, message.id, {
+        attachments: [
+            { filename: "a.txt", data: encodeText("a") },
+            { filename: "b.txt", data: encodeText("b") },
+        ],
+    })
+    assertExpected(2, edited.attachments.length)
+
+    await Discord.deleteMessage(channelId, message.id)
+    sendScriptCompletion()
+})
@@ -0,0 +1,27 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+script.on("GUILD_AUDIT_LOG_ENTRY_CREATE", async (evt) => {
+    if (evt.actionType !== "RoleCreate") {
+        return
+    }
+
+    const name = evt.changes.find(v => v.key === "name")
+    if (name?.newValue !== "bl-audit-log-test") {
+        return
+    }
+
+    assertExpected("audit_log.ts", evt.reason)
+
+    const log = await Discord.getAuditLog({ actionType: "RoleCreate", limit: 10 })
+    const entry = log.entries.find(v => v.id === evt.id)
+    assertExpected(evt.targetId, entry?.targetId)
+    assertExpected(true, log.users.some(v => v.id === entry?.userId))
+
+    await Discord.deleteRole(evt.targetId!)
+    sendScriptCompletion()
+})
+
+runOnce("audit_log.ts", async () => {
+    await Discord.createRole({ name: "bl-audit-log-test", auditLogReason: "audit_log.ts" })
+})
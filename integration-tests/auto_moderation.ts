@@ -0,0 +1,38 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+runOnce("auto_moderation.ts", async () => {
+    const rule = await Discord.createAutoModerationRule({
+        name: "bl-automod-test",
+        eventType: "MessageSend",
+        triggerType: "Keyword",
+        triggerMetadata: {
+            keywordFilter: ["bl-automod-test-word"],
+        },
+        actions: [{ kind: "BlockMessage" }],
+        enabled: false,
+    })
+
+    assertExpected("bl-automod-test", rule.name)
+    assertExpected("Keyword", rule.triggerType)
+    assertExpected(false, rule.enabled)
+
+    const fetched = await Discord.getAutoModerationRule(rule.id)
+    assertExpected(rule.id, fetched.id)
+
+    const edited = await Discord.editAutoModerationRule(rule.id, {
+        name: "bl-automod-test-2",
+        triggerMetadata: {
+            keywordFilter: ["bl-automod-test-word", "bl-automod-test-word-2"],
+        },
+    })
+    assertExpected("bl-automod-test-2", edited.name)
+    assertExpected(2, edited.triggerMetadata.keywordFilter?.length)
+
+    await Discord.deleteAutoModerationRule(rule.id)
+
+    const rules = await Discord.getAutoModerationRules()
+    assertExpected(false, rules.some(v => v.id === rule.id))
+
+    sendScriptCompletion()
+})
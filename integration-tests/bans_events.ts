@@ -0,0 +1,20 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+// a user that is not a member of the test server
+const testUserId = "643945264868098049"
+
+runOnce("bans_events.ts", async () => {
+    await Discord.createBan(testUserId, { auditLogReason: "ban events test", deleteMessageDays: 0 })
+})
+
+script.on("BAN_ADD", async (evt) => {
+    if (evt.user.id === testUserId) {
+        await Discord.deleteBan(testUserId)
+    }
+})
+
+script.on("BAN_REMOVE", async (evt) => {
+    assertExpected(testUserId, evt.user.id)
+    sendScriptCompletion()
+})
@@ -1,10 +1,12 @@
-import { Commands } from 'botloader';
+import { Commands, Discord } from 'botloader';
 import { runOnce, sendScriptCompletion } from 'lib';
 
 script.createCommand(
     Commands.slashCommand("gaming", "this is a gaming command")
-        .addOptionNumber("amount", "amount of gaming")
-        .addOptionString("what", "what to game", { required: false })
+        .setNameLocalizations({ "de": "spielen" })
+        .setDescriptionLocalizations({ "de": "das ist ein spiel befehl" })
+        .addOptionNumber("amount", "amount of gaming", { nameLocalizations: { "de": "menge" } })
+        .addOptionString("what", "what to game", { required: false, autocomplete: gamingAutocomplete })
         .build((ctx, args) => {
             // stuff here
             let a = args.amount;
@@ -13,16 +15,37 @@ script.createCommand(
 );
 
 
-function gamingAutocomplete(data: {}) {
+function gamingAutocomplete(ctx: Commands.AutocompleteContext) {
     return [{
         name: "lol",
         value: "lol",
     }, {
         name: "lost ark",
         value: "loast_ark",
-    }]
+    }].filter(v => v.name.startsWith(ctx.value))
 }
 
+const adminGroup = new Commands.Group("admin", "admin commands", {
+    defaultMemberPermissions: Discord.Permissions.ManageGuild,
+})
+
+script.createCommand(
+    Commands.slashCommand("reset", "resets the gaming")
+        .setGroup(adminGroup)
+        .build((ctx) => {
+            ctx.sendResponse("gaming has been reset");
+        })
+);
+
+script.createCommand(
+    Commands.slashCommand("spoilers", "not for everyone")
+        .setDefaultMemberPermissions(Discord.Permissions.ManageMessages)
+        .setNsfw()
+        .build((ctx) => {
+            ctx.sendResponse("spoilers");
+        })
+);
+
 script.createCommand(
     Commands.userCommand("throw")
         .build((ctx, target) => {
@@ -0,0 +1,12 @@
+import { Discord } from "botloader";
+import { assertExpectError, runOnce, sendScriptCompletion } from "lib";
+
+runOnce("direct_messages.ts", async () => {
+    // not a member of the test server, should be refused regardless of the server setting
+    await assertExpectError(() => Discord.sendDirectMessage("1", { content: "hello" }))
+
+    // invalid user ids are rejected before anything is sent
+    await assertExpectError(() => Discord.sendDirectMessage("not an id", { content: "hello" }))
+
+    sendScriptCompletion()
+})
@@ -0,0 +1,41 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+// 1x1 transparent png
+const PNG_DATA = new Uint8Array([
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4,
+    0x89, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0x00, 0x01, 0x00, 0x00,
+    0x05, 0x00, 0x01, 0x0d, 0x0a, 0x2d, 0xb4, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae,
+    0x42, 0x60, 0x82,
+]);
+
+script.on("GUILD_EMOJIS_UPDATE", async (evt) => {
+    const created = evt.emojis.find(v => v.name === "bl_emojitest")
+    if (created) {
+        const edited = await Discord.editEmoji(created.id, { name: "bl_emojitest_2" })
+        assertExpected("bl_emojitest_2", edited.name)
+        return
+    }
+
+    const edited = evt.emojis.find(v => v.name === "bl_emojitest_2")
+    if (edited) {
+        await Discord.deleteEmoji(edited.id)
+        return
+    }
+
+    const emojis = await Discord.getEmojis()
+    assertExpected(false, emojis.some(v => v.name.startsWith("bl_emojitest")))
+    sendScriptCompletion()
+})
+
+runOnce("emojis.ts", async () => {
+    const emoji = await Discord.createEmoji({
+        name: "bl_emojitest",
+        image: PNG_DATA,
+        contentType: "image/png",
+    })
+
+    const fetched = await Discord.getEmoji(emoji.id)
+    assertExpected("bl_emojitest", fetched.name)
+})
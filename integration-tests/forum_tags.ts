@@ -0,0 +1,60 @@
+import { Discord } from "botloader";
+import { assertExpectError, assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+const testForumId = "1206877897529364541"
+
+runOnce("forum_tags.ts", async () => {
+    let forum = await Discord.getChannel(testForumId) as Discord.TextChannel
+    assertExpected("Forum", forum.kind)
+
+    // clean up leftovers from previous runs
+    const leftover = forum.findTag("int-test-solved")
+    if (leftover) {
+        forum = await forum.deleteTag(leftover.id)
+    }
+
+    forum = await forum.createTag({ name: "int-test-solved", emojiName: "✅" })
+    const tag = forum.findTag("int-test-solved")
+    assertExpected(true, Boolean(tag))
+    assertExpected("✅", tag!.emojiName)
+
+    forum = await forum.editTag(tag!.id, { moderated: true })
+    assertExpected(true, forum.findTag("int-test-solved")!.moderated)
+
+    forum = await Discord.editChannel(testForumId, {
+        defaultSortOrder: "CreationDate",
+        defaultReactionEmoji: { emojiName: "👍" },
+    }) as Discord.TextChannel
+    assertExpected("CreationDate", forum.defaultSortOrder)
+    assertExpected("👍", forum.defaultReactionEmoji?.emojiName)
+
+    forum = await Discord.editChannel(testForumId, {
+        defaultSortOrder: "LatestActivity",
+        defaultReactionEmoji: null,
+    }) as Discord.TextChannel
+    assertExpected(null, forum.defaultReactionEmoji)
+
+    let { thread } = await Discord.createForumThread({
+        channelId: testForumId,
+        message: { content: "test thread" },
+        name: "int-test-forum-tags-thread",
+        autoArchiveDurationMinutes: 60,
+    })
+    assertExpected(0, thread.appliedTags.length)
+
+    thread = await thread.addTag(tag!.id) as Discord.PublicThread
+    assertExpected(true, thread.appliedTags.includes(tag!.id))
+
+    // unknown tags are rejected instead of silently dropped
+    await assertExpectError(() => thread.setTags(["123"]))
+
+    thread = await thread.removeTag(tag!.id) as Discord.PublicThread
+    assertExpected(0, thread.appliedTags.length)
+
+    await thread.archive()
+
+    forum = await forum.deleteTag(tag!.id)
+    assertExpected(undefined, forum.findTag("int-test-solved"))
+
+    sendScriptCompletion()
+})
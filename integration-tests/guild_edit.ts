@@ -0,0 +1,30 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+runOnce("guild_edit.ts", async () => {
+    const guild = await Discord.getGuild()
+
+    await Discord.editGuild({
+        name: guild.name + " (edited)",
+        afkTimeoutSeconds: 300,
+        auditLogReason: "botloader integration test",
+    })
+
+    // restore the original settings
+    await Discord.editGuild({
+        name: guild.name,
+        afkTimeoutSeconds: guild.afkTimeout as 60 | 300 | 900 | 1800 | 3600,
+    })
+
+    let failed = false
+    try {
+        await Discord.editGuild({
+            afkTimeoutSeconds: 123 as any,
+        })
+    } catch {
+        failed = true
+    }
+    assertExpected(true, failed)
+
+    sendScriptCompletion()
+})
@@ -0,0 +1,22 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+const channelId = "531120790318350338"
+
+runOnce("message_cache.ts", async () => {
+    const message = await Discord.createMessage(channelId, { content: "message cache test" })
+    await Discord.editMessage(channelId, message.id, { content: "message cache test edited" })
+})
+
+script.on("MESSAGE_UPDATE", async (evt) => {
+    if (evt.content === "message cache test edited") {
+        assertExpected("message cache test", evt.previous?.content)
+        await Discord.deleteMessage(channelId, evt.id)
+    }
+})
+
+script.on("MESSAGE_DELETE", async (evt) => {
+    if (evt.previous?.content === "message cache test edited") {
+        sendScriptCompletion()
+    }
+})
@@ -0,0 +1,24 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+const channelId = "531120790318350338"
+const messageIds = script.createStorageVarJson<string[]>("message_delete_bulk_ids")
+
+runOnce("message_delete_bulk.ts", async () => {
+    const first = await Discord.createMessage(channelId, { content: "bulk delete test 1" })
+    const second = await Discord.createMessage(channelId, { content: "bulk delete test 2" })
+
+    await messageIds.set([first.id, second.id])
+    await Discord.bulkDeleteMessages(channelId, first.id, second.id)
+})
+
+script.on("MESSAGE_DELETE_BULK", async (evt) => {
+    const expected = (await messageIds.get())?.value
+    if (!expected) {
+        return
+    }
+
+    assertExpected(channelId, evt.channelId)
+    assertExpected(true, expected.every(id => evt.ids.includes(id)))
+    sendScriptCompletion()
+})
@@ -0,0 +1,39 @@
+import { Discord } from "botloader";
+import { assertExpectError, assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+const testChannelId = "531120790318350338"
+
+runOnce("polls.ts", async () => {
+    const created = await Discord.createMessage(testChannelId, {
+        poll: {
+            question: "int test poll",
+            answers: [
+                { text: "yes", emoji: { unicode: "👍" } },
+                { text: "no" },
+            ],
+            durationHours: 1,
+            allowMultiselect: true,
+        }
+    })
+
+    const fetched = await Discord.getMessage(testChannelId, created.id)
+    assertExpected("int test poll", fetched.poll?.question.text)
+    assertExpected(2, fetched.poll?.answers.length)
+    assertExpected("yes", fetched.poll?.answers[0].media.text)
+    assertExpected(true, fetched.poll?.allowMultiselect)
+
+    // regular messages have no poll
+    const plain = await Discord.createMessage(testChannelId, { content: "no poll here" })
+    assertExpected(null, (await Discord.getMessage(testChannelId, plain.id)).poll)
+
+    // invalid polls are rejected before reaching discord
+    await assertExpectError(() => Discord.createMessage(testChannelId, { poll: { question: "empty", answers: [] } }))
+    await assertExpectError(() => Discord.createMessage(testChannelId, {
+        poll: { question: "too long", answers: [{ text: "a" }], durationHours: 1000 }
+    }))
+
+    await Discord.deleteMessage(testChannelId, created.id)
+    await Discord.deleteMessage(testChannelId, plain.id)
+
+    sendScriptCompletion()
+})
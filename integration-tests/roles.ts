@@ -0,0 +1,34 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+runOnce("roles.ts", async () => {
+    const created = await Discord.createRole({
+        name: "bl-roletest",
+        color: 0xff0000,
+        mentionable: true,
+        permissions: Discord.Permissions.SendMessages,
+    })
+    assertExpected("bl-roletest", created.name)
+    assertExpected(0xff0000, created.color)
+    assertExpected(true, created.mentionable)
+
+    const edited = await Discord.editRole(created.id, {
+        name: "bl-roletest-2",
+        color: null,
+        hoist: true,
+    })
+    assertExpected("bl-roletest-2", edited.name)
+    assertExpected(0, edited.color)
+    assertExpected(true, edited.hoist)
+    assertExpected(true, edited.mentionable)
+
+    const roles = await Discord.updateRolePositions([{ roleId: created.id, position: 1 }])
+    assertExpected(1, roles.find(v => v.id === created.id)?.position)
+
+    await Discord.deleteRole(created.id)
+
+    const remaining = await Discord.getRoles()
+    assertExpected(false, remaining.some(v => v.id === created.id))
+
+    sendScriptCompletion()
+})
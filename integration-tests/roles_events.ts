@@ -0,0 +1,27 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+runOnce("roles_events.ts", async () => {
+    await Discord.createRole({
+        name: "bl-roleevents",
+    })
+})
+
+script.on("ROLE_CREATE", async (evt) => {
+    if (evt.name === "bl-roleevents") {
+        await Discord.editRole(evt.id, { name: "bl-roleevents-2" })
+    }
+})
+
+script.on("ROLE_UPDATE", async (evt) => {
+    if (evt.role.name === "bl-roleevents-2") {
+        assertExpected("bl-roleevents", evt.oldRole?.name)
+        await Discord.deleteRole(evt.role.id)
+    }
+})
+
+script.on("ROLE_DELETE", async (evt) => {
+    if (evt.oldRole?.name === "bl-roleevents-2") {
+        sendScriptCompletion()
+    }
+})
@@ -0,0 +1,55 @@
+import { Discord } from "botloader";
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+script.on("GUILD_SCHEDULED_EVENT_CREATE", async (evt) => {
+    if (evt.name !== "bl-scheduled-event-test") {
+        return
+    }
+
+    const edited = await Discord.editScheduledEvent(evt.id, { name: "bl-scheduled-event-test-2" })
+    assertExpected("bl-scheduled-event-test-2", edited.name)
+})
+
+script.on("GUILD_SCHEDULED_EVENT_UPDATE", async (evt) => {
+    if (evt.name !== "bl-scheduled-event-test-2") {
+        return
+    }
+
+    if (evt.status === "Scheduled") {
+        const users = await Discord.getScheduledEventUsers(evt.id)
+        assertExpected(0, users.length)
+
+        const cancelled = await Discord.cancelScheduledEvent(evt.id)
+        assertExpected("Cancelled", cancelled.status)
+    } else if (evt.status === "Cancelled") {
+        await Discord.deleteScheduledEvent(evt.id)
+    }
+})
+
+script.on("GUILD_SCHEDULED_EVENT_DELETE", async (evt) => {
+    if (evt.name !== "bl-scheduled-event-test-2") {
+        return
+    }
+
+    const events = await Discord.getScheduledEvents()
+    assertExpected(false, events.some(v => v.id === evt.id))
+    sendScriptCompletion()
+})
+
+runOnce("scheduled_events.ts", async () => {
+    const start = new Date(Date.now() + 24 * 60 * 60 * 1000)
+    const event = await Discord.createScheduledEvent({
+        name: "bl-scheduled-event-test",
+        entityType: "External",
+        location: "somewhere",
+        scheduledStartTime: start,
+        scheduledEndTime: start.getTime() + 60 * 60 * 1000,
+    })
+
+    assertExpected("External", event.entityType)
+    assertExpected("somewhere", event.location)
+    assertExpected(start.getTime(), event.scheduledStartTime)
+
+    const fetched = await Discord.getScheduledEvent(event.id)
+    assertExpected(event.id, fetched.id)
+})
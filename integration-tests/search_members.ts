@@ -0,0 +1,14 @@
+import { Discord } from 'botloader';
+import { assertExpected, runOnce, sendScriptCompletion } from 'lib';
+
+runOnce("search_members.ts", async () => {
+    const bot = Discord.getBotUser()
+
+    const byPrefix = await Discord.searchMembers(bot.username.slice(0, 3))
+    assertExpected(true, byPrefix.some(v => v.user.id === bot.id))
+
+    const limited = await Discord.searchMembers(bot.username, { limit: 1 })
+    assertExpected(1, limited.length)
+
+    sendScriptCompletion();
+})
@@ -0,0 +1,28 @@
+import { assertExpected, assetJsonEquals, runOnce, sendScriptCompletion } from "lib";
+
+const blobs = script.createStorageBytes("bytes_blobs")
+const counter = script.createStorageVarBytes("bytes_var")
+
+runOnce("storage_bytes.ts", async () => {
+    const data = new Uint8Array([0, 1, 2, 127, 128, 254, 255])
+    await blobs.set("blob", data)
+
+    const entry = await blobs.get("blob")
+    assetJsonEquals(Array.from(data), Array.from(entry!.value))
+
+    // lengths that need padding
+    for (let len = 0; len < 5; len++) {
+        const buf = new Uint8Array(len).map((_, i) => i * 50)
+        await blobs.set(`len:${len}`, buf)
+        const res = await blobs.get(`len:${len}`)
+        assetJsonEquals(Array.from(buf), Array.from(res!.value))
+    }
+
+    const listed = await blobs.list({ keyPrefix: "len:" })
+    assertExpected(5, listed.length)
+
+    await counter.set(new Uint8Array([42]))
+    assertExpected(42, (await counter.get())!.value[0])
+
+    sendScriptCompletion()
+})
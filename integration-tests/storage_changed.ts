@@ -0,0 +1,45 @@
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+const scores = script.createStorageNumber("changed_scores")
+
+let seen = 0
+script.onStorageChanged(scores, async (evt) => {
+    // writes outside the prefix should not be dispatched
+    assertExpected(true, evt.key.startsWith("user:"))
+
+    seen++
+    if (seen === 1) {
+        assertExpected(null, evt.old)
+        assertExpected("Double", evt.new?.kind)
+        assertExpected(5, evt.new?.number)
+
+        await scores.incr("user:1", 2)
+    } else if (seen === 2) {
+        assertExpected(5, evt.old?.number)
+        assertExpected(7, evt.new?.number)
+
+        await scores.delete("user:1")
+    } else if (seen === 3) {
+        assertExpected(7, evt.old?.number)
+        assertExpected(null, evt.new)
+
+        await scores.set("user:2", 1)
+    } else if (seen === 4) {
+        assertExpected(null, evt.old)
+        assertExpected(1, evt.new?.number)
+
+        // also removes role:1, which is outside the prefix
+        await scores.deleteAll()
+    } else {
+        assertExpected("user:2", evt.key)
+        assertExpected(1, evt.old?.number)
+        assertExpected(null, evt.new)
+
+        sendScriptCompletion()
+    }
+}, { keyPrefix: "user:" })
+
+runOnce("storage_changed.ts", async () => {
+    await scores.set("role:1", 1)
+    await scores.set("user:1", 5)
+})
@@ -0,0 +1,23 @@
+import { assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+const counter = script.createStorageNumber("changed_loop")
+
+let seen = 0
+script.onStorageChanged(counter, async (evt) => {
+    assertExpected(seen, evt.depth)
+    seen++
+
+    // this would loop forever if the events weren't dropped at some point
+    await counter.incr("key", 1)
+
+    if (evt.depth === 10) {
+        setTimeout(() => {
+            assertExpected(11, seen)
+            sendScriptCompletion()
+        }, 1000)
+    }
+})
+
+runOnce("storage_changed_loop.ts", async () => {
+    await counter.set("key", 0)
+})
@@ -0,0 +1,43 @@
+import { assertExpected, assetJsonEquals, runOnce, sendScriptCompletion } from "lib";
+
+const bucket = script.createStorageJson<number>("cursors_json")
+const scores = script.createStorageNumber("cursors_scores")
+
+runOnce("storage_cursors.ts", async () => {
+    for (let i = 0; i < 5; i++) {
+        await bucket.set(`user:${i}`, i)
+        await bucket.set(`role:${i}`, i)
+        await scores.set(`user:${i}`, i % 2)
+    }
+
+    // paginate through a prefix with cursors
+    let page = await bucket.list({ keyPrefix: "user:", limit: 2 })
+    assetJsonEquals(["user:0", "user:1"], page.map(v => v.key))
+
+    page = await bucket.list({ keyPrefix: "user:", limit: 2, cursor: page[1].cursor })
+    assetJsonEquals(["user:2", "user:3"], page.map(v => v.key))
+
+    page = await bucket.list({ keyPrefix: "user:", limit: 2, cursor: page[1].cursor })
+    assetJsonEquals(["user:4"], page.map(v => v.key))
+
+    // reverse iteration
+    page = await bucket.list({ keyPrefix: "role:", limit: 2, reverse: true })
+    assetJsonEquals(["role:4", "role:3"], page.map(v => v.key))
+
+    // explicit range bounds, end is exclusive
+    page = await bucket.list({ start: "role:1", end: "role:3" })
+    assetJsonEquals(["role:1", "role:2"], page.map(v => v.key))
+
+    // sorted list with cursors, ties are broken by update time and then key
+    let sorted = await scores.sortedList("Descending", { limit: 3 })
+    assertExpected(3, sorted.length)
+    const rest = await scores.sortedList("Descending", { limit: 3, cursor: sorted[2].cursor })
+    assertExpected(2, rest.length)
+
+    const allKeys = [...sorted, ...rest].map(v => v.key).sort()
+    assetJsonEquals(["user:0", "user:1", "user:2", "user:3", "user:4"], allKeys)
+    assertExpected(1, sorted[0].value)
+    assertExpected(0, rest[1].value)
+
+    sendScriptCompletion()
+})
@@ -0,0 +1,32 @@
+import { assertExpected, assetJsonEquals, runOnce, sendScriptCompletion } from "lib";
+
+const scores = script.createStorageNumber("leaderboard_scores")
+const names = script.createStorageJson<string>("leaderboard_names")
+
+runOnce("storage_leaderboard.ts", async () => {
+    for (let i = 1; i <= 10; i++) {
+        await scores.set(`user:${i}`, i * 10)
+    }
+    await names.set("user:1", "not a number bucket")
+
+    assertExpected(1, await scores.rank("user:10", "Descending"))
+    assertExpected(10, await scores.rank("user:1", "Descending"))
+    assertExpected(3, await scores.rank("user:3", "Ascending"))
+    assertExpected(undefined, await scores.rank("user:404", "Descending"))
+
+    const neighbors = await scores.neighbors("user:5", "Descending", { before: 2, after: 3 })
+    assertExpected(6, neighbors?.rank)
+    assertExpected(50, neighbors?.entry.value)
+    assetJsonEquals(["user:7", "user:6"], neighbors?.before.map(v => v.key))
+    assetJsonEquals(["user:4", "user:3", "user:2"], neighbors?.after.map(v => v.key))
+
+    // fewer entries than requested at the top of the leaderboard
+    const top = await scores.neighbors("user:10", "Descending")
+    assertExpected(1, top?.rank)
+    assertExpected(0, top?.before.length)
+    assertExpected(5, top?.after.length)
+
+    assertExpected(undefined, await scores.neighbors("user:404", "Descending"))
+
+    sendScriptCompletion()
+})
@@ -0,0 +1,65 @@
+import { Storage } from "botloader";
+import { assertExpected, assetJsonEquals, runOnce, sendScriptCompletion } from "lib";
+
+const balances = script.createStorageNumber("tx_balances")
+const transfers = script.createStorageJson<{ amount: number }>("tx_transfers")
+const blobs = script.createStorageBytes("tx_blobs")
+
+runOnce("storage_transactions.ts", async () => {
+    await balances.set("a", 100)
+    await balances.set("b", 0)
+
+    const res = await new Storage.Transaction()
+        .incr(balances, "a", -30)
+        .incr(balances, "b", 30)
+        .setIf(transfers, "1", { amount: 30 }, "IfNotExists")
+        .commit()
+
+    assertExpected(true, res.committed)
+    if (res.committed) {
+        assertExpected(70, res.entries[0]?.value)
+        assertExpected(30, res.entries[1]?.value)
+    }
+
+    // same transfer again, the condition fails so nothing should be applied
+    const dup = await new Storage.Transaction()
+        .incr(balances, "a", -30)
+        .incr(balances, "b", 30)
+        .setIf(transfers, "1", { amount: 30 }, "IfNotExists")
+        .commit()
+
+    assertExpected(false, dup.committed)
+    if (!dup.committed) {
+        assertExpected(2, dup.failedIndex)
+    }
+
+    assertExpected(70, (await balances.get("a"))?.value)
+    assertExpected(30, (await balances.get("b"))?.value)
+
+    const del = await new Storage.Transaction()
+        .delete(transfers, "1")
+        .delete(transfers, "does_not_exist")
+        .commit()
+
+    assertExpected(true, del.committed)
+    if (del.committed) {
+        assertExpected(30, del.entries[0]?.value.amount)
+        assertExpected(undefined, del.entries[1])
+    }
+
+    const data = new Uint8Array([0, 1, 2, 254, 255])
+    const bytes = await new Storage.Transaction()
+        .set(blobs, "a", data)
+        .setIf(blobs, "b", data.slice(1), "IfNotExists")
+        .commit()
+
+    assertExpected(true, bytes.committed)
+    if (bytes.committed) {
+        assetJsonEquals(Array.from(data), Array.from(bytes.entries[0]!.value))
+        assetJsonEquals(Array.from(data.slice(1)), Array.from(bytes.entries[1]!.value))
+    }
+
+    assetJsonEquals(Array.from(data), Array.from((await blobs.get("a"))!.value))
+
+    sendScriptCompletion()
+})
@@ -0,0 +1,49 @@
+import { assertElapsed, assertExpectError, assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+runOnce("timers.ts", async () => {
+    let started = Date.now()
+    await sleep(1000)
+    assertElapsed(started, 1000)
+
+    // cleared timers should never fire
+    let clearedFired = false
+    const cleared = setTimeout(() => { clearedFired = true }, 500)
+    clearTimeout(cleared)
+
+    // ids that don't belong to a timer are ignored
+    clearTimeout(-1)
+
+    let ticks = 0
+    const interval = setInterval(() => {
+        ticks++
+        if (ticks >= 3) {
+            clearInterval(interval)
+        }
+    }, 200)
+
+    started = Date.now()
+    await new Promise<void>((resolve) => setTimeout(resolve, 1500))
+    assertElapsed(started, 1500)
+    assertExpected(false, clearedFired)
+    assertExpected(3, ticks)
+
+    // errors from callbacks are reported and don't stop the interval
+    setTimeout(() => { throw new Error("timeout callback error") }, 100)
+
+    let failingTicks = 0
+    const failing = setInterval(async () => {
+        failingTicks++
+        if (failingTicks >= 3) {
+            clearInterval(failing)
+        }
+        throw new Error("interval callback error")
+    }, 200)
+
+    await sleep(1000)
+    assertExpected(3, failingTicks)
+
+    // longer than the max timer duration without premium
+    await assertExpectError(() => setTimeout(() => { }, 10 * 60 * 1000))
+
+    sendScriptCompletion()
+})
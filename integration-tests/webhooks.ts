@@ -0,0 +1,46 @@
+import { Discord } from "botloader";
+import { assertExpectError, assertExpected, runOnce, sendScriptCompletion } from "lib";
+
+const channelId = "1206878101473329183"
+
+runOnce("webhooks.ts", async () => {
+    const webhook = await Discord.createWebhook(channelId, { name: "bl-webhook-test" })
+    assertExpected("bl-webhook-test", webhook.name)
+    assertExpected(undefined, (webhook as any).token)
+
+    const webhooks = await Discord.getChannelWebhooks(channelId)
+    assertExpected(true, webhooks.some(v => v.id === webhook.id))
+
+    const noWait = await Discord.executeWebhook(webhook.id, { content: "webhooks.ts no wait" })
+    assertExpected(undefined, noWait)
+
+    const message = await Discord.executeWebhook(webhook.id, {
+        content: "webhooks.ts",
+        username: "bl-webhook-test-override",
+        wait: true,
+    })
+    assertExpected("webhooks.ts", message?.content)
+    assertExpected(webhook.id, message?.webhookId)
+
+    const thread = await Discord.createStandaloneThread({
+        channelId,
+        kind: "PublicThread",
+        name: "int-test-webhook-thread",
+        autoArchiveDurationMinutes: 60,
+    })
+
+    const threadMessage = await Discord.executeWebhook(webhook.id, {
+        content: "webhooks.ts thread",
+        threadId: thread.id,
+        wait: true,
+    })
+    assertExpected(thread.id, threadMessage?.channelId)
+
+    await Discord.deleteWebhook(webhook.id)
+    await Discord.deleteChannel(thread.id)
+
+    // deleted webhooks are forgotten, and webhooks not created by scripts can't be used
+    await assertExpectError(() => Discord.executeWebhook(webhook.id, { content: "webhooks.ts deleted" }))
+
+    sendScriptCompletion()
+})